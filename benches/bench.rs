@@ -0,0 +1,137 @@
+use std::str::from_utf8;
+
+use bencher::{benchmark_group, benchmark_main, Bencher};
+use scanner_rust::{Scanner, ScannerAscii};
+
+const COUNT: u64 = 100_000;
+
+fn integers() -> Vec<u8> {
+    let mut data = String::new();
+
+    for i in 0..COUNT {
+        let n = i.wrapping_mul(0x9E37_79B9_7F4A_7C15) >> (i % 64);
+
+        data.push_str(&n.to_string());
+        data.push(if i % 16 == 15 { '\n' } else { ' ' });
+    }
+
+    data.into_bytes()
+}
+
+fn scanner_next_u64(bencher: &mut Bencher) {
+    let data = integers();
+
+    bencher.iter(|| {
+        let mut sc = Scanner::new(data.as_slice());
+
+        let mut sum = 0u64;
+
+        while let Some(n) = sc.next_u64().unwrap() {
+            sum = sum.wrapping_add(n);
+        }
+
+        sum
+    });
+
+    bencher.bytes = data.len() as u64;
+}
+
+fn scanner_next_raw_and_parse(bencher: &mut Bencher) {
+    let data = integers();
+
+    bencher.iter(|| {
+        let mut sc = Scanner::new(data.as_slice());
+
+        let mut sum = 0u64;
+
+        while let Some(token) = sc.next_raw().unwrap() {
+            sum = sum.wrapping_add(from_utf8(&token).unwrap().parse::<u64>().unwrap());
+        }
+
+        sum
+    });
+
+    bencher.bytes = data.len() as u64;
+}
+
+fn scanner_ascii_next_u64(bencher: &mut Bencher) {
+    let data = integers();
+
+    bencher.iter(|| {
+        let mut sc = ScannerAscii::new(data.as_slice());
+
+        let mut sum = 0u64;
+
+        while let Some(n) = sc.next_u64().unwrap() {
+            sum = sum.wrapping_add(n);
+        }
+
+        sum
+    });
+
+    bencher.bytes = data.len() as u64;
+}
+
+fn scanner_ascii_next_raw_and_parse(bencher: &mut Bencher) {
+    let data = integers();
+
+    bencher.iter(|| {
+        let mut sc = ScannerAscii::new(data.as_slice());
+
+        let mut sum = 0u64;
+
+        while let Some(token) = sc.next_raw().unwrap() {
+            sum = sum.wrapping_add(from_utf8(&token).unwrap().parse::<u64>().unwrap());
+        }
+
+        sum
+    });
+
+    bencher.bytes = data.len() as u64;
+}
+
+fn scanner_next(bencher: &mut Bencher) {
+    let data = integers();
+
+    bencher.iter(|| {
+        let mut sc = Scanner::new(data.as_slice());
+
+        let mut length = 0;
+
+        while let Some(token) = sc.next().unwrap() {
+            length += token.len();
+        }
+
+        length
+    });
+
+    bencher.bytes = data.len() as u64;
+}
+
+fn scanner_next_ref(bencher: &mut Bencher) {
+    let data = integers();
+
+    bencher.iter(|| {
+        let mut sc = Scanner::new(data.as_slice());
+
+        let mut length = 0;
+
+        while let Some(token) = sc.next_ref().unwrap() {
+            length += token.len();
+        }
+
+        length
+    });
+
+    bencher.bytes = data.len() as u64;
+}
+
+benchmark_group!(
+    integers_from_stream,
+    scanner_next_u64,
+    scanner_next_raw_and_parse,
+    scanner_ascii_next_u64,
+    scanner_ascii_next_raw_and_parse
+);
+benchmark_group!(tokens_from_stream, scanner_next, scanner_next_ref);
+benchmark_main!(integers_from_stream, tokens_from_stream);
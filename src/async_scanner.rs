@@ -0,0 +1,377 @@
+/// Call a method of `ScanBuffer` until it is done, reading more data whenever it needs.
+macro_rules! poll_buffer {
+    ($sc:ident, $call:expr) => {
+        loop {
+            if let Some(result) = $call {
+                return Ok(result);
+            }
+
+            $sc.fill().await?;
+        }
+    };
+}
+
+macro_rules! impl_async_next_integers {
+    ($($t:ty, $next:ident, $next_until:ident, $next_radix:ident, $next_radix_until:ident);* $(;)?) => {
+        $(
+            #[doc = concat!("Read the next token separated by the delimiter and parse it to a `", stringify!($t), "` value. If there is nothing to read, it will return `Ok(None)`.")]
+            #[inline]
+            pub async fn $next(&mut self) -> Result<Option<$t>, ScannerError> {
+                self.next_integer(10).await
+            }
+
+            #[doc = concat!("Read the next text until it reaches a specific boundary and parse it to a `", stringify!($t), "` value. If there is nothing to read, it will return `Ok(None)`.")]
+            #[inline]
+            pub async fn $next_until<D: ?Sized + AsRef<[u8]>>(
+                &mut self,
+                boundary: &D,
+            ) -> Result<Option<$t>, ScannerError> {
+                self.next_integer_until(boundary.as_ref(), 10).await
+            }
+
+            #[doc = concat!("Read the next token separated by the delimiter and parse it to a `", stringify!($t), "` value in the given radix, like the method of `Scanner`. If there is nothing to read, it will return `Ok(None)`.")]
+            ///
+            /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
+            #[inline]
+            pub async fn $next_radix(&mut self, radix: u32) -> Result<Option<$t>, ScannerError> {
+                self.next_integer(radix).await
+            }
+
+            #[doc = concat!("Read the next text until it reaches a specific boundary and parse it to a `", stringify!($t), "` value in the given radix, like the method of `Scanner`. If there is nothing to read, it will return `Ok(None)`.")]
+            ///
+            /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
+            #[inline]
+            pub async fn $next_radix_until<D: ?Sized + AsRef<[u8]>>(
+                &mut self,
+                boundary: &D,
+                radix: u32,
+            ) -> Result<Option<$t>, ScannerError> {
+                self.next_integer_until(boundary.as_ref(), radix).await
+            }
+        )*
+    };
+}
+
+macro_rules! impl_async_next_floats {
+    ($($t:ty, $next:ident, $next_until:ident);* $(;)?) => {
+        $(
+            #[doc = concat!("Read the next token separated by the delimiter and parse it to a `", stringify!($t), "` value. If there is nothing to read, it will return `Ok(None)`.")]
+            #[inline]
+            pub async fn $next(&mut self) -> Result<Option<$t>, ScannerError> {
+                self.next_number().await
+            }
+
+            #[doc = concat!("Read the next text until it reaches a specific boundary and parse it to a `", stringify!($t), "` value. If there is nothing to read, it will return `Ok(None)`.")]
+            #[inline]
+            pub async fn $next_until<D: ?Sized + AsRef<[u8]>>(
+                &mut self,
+                boundary: &D,
+            ) -> Result<Option<$t>, ScannerError> {
+                self.next_number_until(boundary.as_ref()).await
+            }
+        )*
+    };
+}
+
+/// Define `AsyncScanner` and `AsyncScannerAscii` in a module where `AsyncRead` is the trait of async readers and `read` is an async function which reads data from them into a slice.
+macro_rules! async_scanners {
+    ($module:literal, $read_trait:literal) => {
+        async_scanners!(
+            @scanner AsyncScanner, false, "UTF-8", $module, $read_trait,
+            /// Set whether invalid UTF-8 data should cause an `InvalidUtf8` error instead of being replaced with `REPLACEMENT_CHARACTER`. It is `false` by default.
+            #[inline]
+            pub fn set_strict_utf8(&mut self, strict: bool) {
+                self.buffer.strict_utf8 = strict;
+            }
+
+            /// Check whether the strict UTF-8 mode is enabled.
+            #[inline]
+            pub fn is_strict_utf8(&self) -> bool {
+                self.buffer.strict_utf8
+            }
+        );
+
+        async_scanners!(@scanner AsyncScannerAscii, true, "ASCII", $module, $read_trait,);
+    };
+    (@scanner $name:ident, $ascii:expr, $encoding:literal, $module:literal, $read_trait:literal, $($extra:tt)*) => {
+        #[doc = concat!("A simple text scanner which can parse primitive types and strings using ", $encoding, " from a reader implementing `", $read_trait, "`. The data is buffered until a whole line or token can be returned, so the buffer grows to fit the longest one. The methods are cancel-safe: if a future is dropped before it completes, the data read by it is kept for the next call.")]
+        ///
+        /// Tokens, numbers and boolean values are parsed in the same way as `Scanner` does, with the delimiter, the number format and the boolean vocabulary of the scanner. However, there are no `has_next_*` and `peek_*` methods, no `mark` and `unread` methods, and the `Scan` trait is not implemented.
+        ///
+        /// ```rust
+        #[doc = concat!("use scanner_rust::{", $module, "::", stringify!($name), ", ScannerError};")]
+        #[doc = concat!("use ", $read_trait, ";")]
+        ///
+        /// async fn sum<R: AsyncRead + Unpin>(reader: R) -> Result<i64, ScannerError> {
+        #[doc = concat!("    let mut sc = ", stringify!($name), "::new(reader);")]
+        ///
+        ///     let mut sum = 0;
+        ///
+        ///     while let Some(i) = sc.next_i64().await? {
+        ///         sum += i;
+        ///     }
+        ///
+        ///     Ok(sum)
+        /// }
+        /// ```
+        #[derive(Educe)]
+        #[educe(Debug)]
+        pub struct $name<R> {
+            #[educe(Debug(ignore))]
+            reader: R,
+            buffer: ScanBuffer,
+        }
+
+        impl<R: AsyncRead + Unpin> $name<R> {
+            /// Create a scanner from a reader. It reads at most 256 bytes at a time.
+            #[inline]
+            pub fn new(reader: R) -> $name<R> {
+                Self::with_capacity(reader, 256)
+            }
+
+            /// Create a scanner from a reader and set how many bytes it reads at a time (at least 4).
+            #[inline]
+            pub fn with_capacity(reader: R, capacity: usize) -> $name<R> {
+                $name {
+                    reader,
+                    buffer: ScanBuffer::new(capacity, $ascii),
+                }
+            }
+
+            /// Set the delimiter used by `next`, `skip_whitespaces` and the methods which parse the next token to a primitive type.
+            #[inline]
+            pub fn set_delimiter<D: Into<Delimiter>>(&mut self, delimiter: D) {
+                self.buffer.set_delimiter(delimiter.into());
+            }
+
+            /// Get the delimiter used by `next`, `skip_whitespaces` and the methods which parse the next token to a primitive type.
+            #[inline]
+            pub fn delimiter(&self) -> &Delimiter {
+                self.buffer.delimiter()
+            }
+
+            /// Set the number format used by the methods which parse the next token to an integer or a floating-point number.
+            #[inline]
+            pub fn set_number_format(&mut self, number_format: NumberFormat) {
+                self.buffer.number_format = number_format;
+            }
+
+            /// Get the number format used by the methods which parse the next token to an integer or a floating-point number.
+            #[inline]
+            pub fn number_format(&self) -> &NumberFormat {
+                &self.buffer.number_format
+            }
+
+            /// Set the words read as `true` or `false` by the `next_bool` and `next_bool_until` methods.
+            #[inline]
+            pub fn set_bool_vocabulary(&mut self, bool_vocabulary: BoolVocabulary) {
+                self.buffer.bool_vocabulary = bool_vocabulary;
+            }
+
+            /// Get the words read as `true` or `false` by the `next_bool` and `next_bool_until` methods.
+            #[inline]
+            pub fn bool_vocabulary(&self) -> &BoolVocabulary {
+                &self.buffer.bool_vocabulary
+            }
+
+            /// Get the position of the data which will be read next.
+            ///
+            /// A line break is counted when it is consumed, except that the second byte of `\r\n` or `\n\r` is counted in the offset when it is read, if the line before it is returned without waiting for it.
+            #[inline]
+            pub fn position(&self) -> Position {
+                self.buffer.position()
+            }
+
+            $($extra)*
+
+            /// Read more data into the buffer.
+            async fn fill(&mut self) -> Result<(), ScannerError> {
+                loop {
+                    let result = read(&mut self.reader, self.buffer.prepare_read()).await;
+
+                    self.buffer.finish_read(&result);
+
+                    match result {
+                        Ok(_) => return Ok(()),
+                        Err(ref err) if err.kind() == ErrorKind::Interrupted => (),
+                        Err(err) => return Err(err.into()),
+                    }
+                }
+            }
+
+            /// Read the next char. If there is nothing to read, it will return `Ok(None)`.
+            pub async fn next_char(&mut self) -> Result<Option<char>, ScannerError> {
+                poll_buffer!(self, self.buffer.next_char()?)
+            }
+
+            /// Read the next line but not include the tailing line character (or line chracters like `CrLf`(`\r\n`)). If there is nothing to read, it will return `Ok(None)`.
+            pub async fn next_line(&mut self) -> Result<Option<String>, ScannerError> {
+                poll_buffer!(self, self.buffer.next_line()?)
+            }
+
+            /// Read the next line but not include the tailing line character (or line chracters like `CrLf`(`\r\n`)) without validating UTF-8. If there is nothing to read, it will return `Ok(None)`.
+            pub async fn next_line_raw(&mut self) -> Result<Option<Vec<u8>>, ScannerError> {
+                poll_buffer!(self, self.buffer.next_line_raw())
+            }
+
+            /// Skip the next delimiters, which are whitespaces by default. If there is nothing to read, it will return `Ok(false)`.
+            pub async fn skip_whitespaces(&mut self) -> Result<bool, ScannerError> {
+                let readable = loop {
+                    if let Some(readable) = self.buffer.ready() {
+                        break readable;
+                    }
+
+                    self.fill().await?;
+                };
+
+                while !self.buffer.skip_delimiters() {
+                    self.fill().await?;
+                }
+
+                Ok(readable)
+            }
+
+            /// Read the next token separated by the delimiter. If there is nothing to read, it will return `Ok(None)`.
+            #[allow(clippy::should_implement_trait)]
+            pub async fn next(&mut self) -> Result<Option<String>, ScannerError> {
+                poll_buffer!(self, self.buffer.next()?)
+            }
+
+            /// Read the next token separated by the delimiter without validating UTF-8. If there is nothing to read, it will return `Ok(None)`.
+            pub async fn next_raw(&mut self) -> Result<Option<Vec<u8>>, ScannerError> {
+                poll_buffer!(self, self.buffer.next_raw())
+            }
+
+            /// Read the next bytes. It waits until `max_number_of_bytes` bytes are available or the reader reaches EOF. If there is nothing to read, it will return `Ok(None)`.
+            pub async fn next_bytes(
+                &mut self,
+                max_number_of_bytes: usize,
+            ) -> Result<Option<Vec<u8>>, ScannerError> {
+                poll_buffer!(self, self.buffer.next_bytes(max_number_of_bytes))
+            }
+
+            /// Read the next text until it reaches a specific boundary. If there is nothing to read, it will return `Ok(None)`.
+            pub async fn next_until<S: AsRef<str>>(
+                &mut self,
+                boundary: S,
+            ) -> Result<Option<String>, ScannerError> {
+                let boundary = boundary.as_ref().as_bytes();
+
+                poll_buffer!(self, self.buffer.next_until(boundary)?)
+            }
+
+            /// Read the next data until it reaches a specific boundary without validating UTF-8. If there is nothing to read, it will return `Ok(None)`.
+            pub async fn next_until_raw<D: ?Sized + AsRef<[u8]>>(
+                &mut self,
+                boundary: &D,
+            ) -> Result<Option<Vec<u8>>, ScannerError> {
+                let boundary = boundary.as_ref();
+
+                poll_buffer!(self, self.buffer.next_until_raw(boundary))
+            }
+
+            /// Read the next token separated by the delimiter and parse it to a value of any type which implements `FromStr`. If there is nothing to read, it will return `Ok(None)`.
+            pub async fn next_parse<T: FromStr>(&mut self) -> Result<Option<T>, ScannerError>
+            where
+                <T as FromStr>::Err: Error + Send + Sync + 'static, {
+                poll_buffer!(self, self.buffer.next_parse(|_, token, position| parse_token(token, position))?)
+            }
+
+            /// Read the next text until it reaches a specific boundary and parse it to a value of any type which implements `FromStr`. If there is nothing to read, it will return `Ok(None)`.
+            pub async fn next_parse_until<T: FromStr, D: ?Sized + AsRef<[u8]>>(
+                &mut self,
+                boundary: &D,
+            ) -> Result<Option<T>, ScannerError>
+            where
+                <T as FromStr>::Err: Error + Send + Sync + 'static, {
+                let boundary = boundary.as_ref();
+
+                poll_buffer!(
+                    self,
+                    self.buffer.next_parse_until(boundary, |_, token, position| parse_token(token, position))?
+                )
+            }
+
+            /// Read the next token separated by the delimiter and parse it to a `bool` value with the boolean vocabulary, which only has `true` and `false` by default. If the token is not in the vocabulary, it will return a `ParseBoolError` error. If there is nothing to read, it will return `Ok(None)`.
+            pub async fn next_bool(&mut self) -> Result<Option<bool>, ScannerError> {
+                poll_buffer!(
+                    self,
+                    self.buffer.next_parse(|buffer, token, position| parse_bool(token, &buffer.bool_vocabulary, position))?
+                )
+            }
+
+            /// Read the next text until it reaches a specific boundary and parse it to a `bool` value with the boolean vocabulary, which only has `true` and `false` by default. If the text is not in the vocabulary, it will return a `ParseBoolError` error. If there is nothing to read, it will return `Ok(None)`.
+            pub async fn next_bool_until<D: ?Sized + AsRef<[u8]>>(
+                &mut self,
+                boundary: &D,
+            ) -> Result<Option<bool>, ScannerError> {
+                let boundary = boundary.as_ref();
+
+                poll_buffer!(
+                    self,
+                    self.buffer.next_parse_until(boundary, |buffer, token, position| parse_bool(token, &buffer.bool_vocabulary, position))?
+                )
+            }
+
+            async fn next_integer<T: Integer>(&mut self, radix: u32) -> Result<Option<T>, ScannerError> {
+                check_radix(radix)?;
+
+                poll_buffer!(
+                    self,
+                    self.buffer.next_parse(|buffer, token, position| parse_integer(token, radix, &buffer.number_format, position))?
+                )
+            }
+
+            async fn next_integer_until<T: Integer>(
+                &mut self,
+                boundary: &[u8],
+                radix: u32,
+            ) -> Result<Option<T>, ScannerError> {
+                check_radix(radix)?;
+
+                poll_buffer!(
+                    self,
+                    self.buffer.next_parse_until(boundary, |buffer, token, position| parse_integer(token, radix, &buffer.number_format, position))?
+                )
+            }
+
+            async fn next_number<T: FromStr>(&mut self) -> Result<Option<T>, ScannerError>
+            where
+                <T as FromStr>::Err: Error + Send + Sync + 'static, {
+                poll_buffer!(
+                    self,
+                    self.buffer.next_parse(|buffer, token, position| parse_number(token, &buffer.number_format, position))?
+                )
+            }
+
+            async fn next_number_until<T: FromStr>(&mut self, boundary: &[u8]) -> Result<Option<T>, ScannerError>
+            where
+                <T as FromStr>::Err: Error + Send + Sync + 'static, {
+                poll_buffer!(
+                    self,
+                    self.buffer.next_parse_until(boundary, |buffer, token, position| parse_number(token, &buffer.number_format, position))?
+                )
+            }
+
+            impl_async_next_integers!(
+                u8, next_u8, next_u8_until, next_u8_radix, next_u8_radix_until;
+                u16, next_u16, next_u16_until, next_u16_radix, next_u16_radix_until;
+                u32, next_u32, next_u32_until, next_u32_radix, next_u32_radix_until;
+                u64, next_u64, next_u64_until, next_u64_radix, next_u64_radix_until;
+                u128, next_u128, next_u128_until, next_u128_radix, next_u128_radix_until;
+                usize, next_usize, next_usize_until, next_usize_radix, next_usize_radix_until;
+                i8, next_i8, next_i8_until, next_i8_radix, next_i8_radix_until;
+                i16, next_i16, next_i16_until, next_i16_radix, next_i16_radix_until;
+                i32, next_i32, next_i32_until, next_i32_radix, next_i32_radix_until;
+                i64, next_i64, next_i64_until, next_i64_radix, next_i64_radix_until;
+                i128, next_i128, next_i128_until, next_i128_radix, next_i128_radix_until;
+                isize, next_isize, next_isize_until, next_isize_radix, next_isize_radix_until;
+            );
+
+            impl_async_next_floats!(
+                f32, next_f32, next_f32_until;
+                f64, next_f64, next_f64_until;
+            );
+        }
+    };
+}
@@ -0,0 +1,95 @@
+use crate::{Position, ScannerError};
+
+/// The words which the `next_bool` and `next_bool_until` methods read as `true` or `false`.
+///
+/// The default vocabulary only has `true` and `false`, like `bool::from_str`. The `lenient` vocabulary is more suitable for answering questions.
+///
+/// ```rust
+/// use scanner_rust::{BoolVocabulary, ScannerStr};
+///
+/// let mut sc = ScannerStr::new("Y no ON 0 maybe");
+///
+/// sc.set_bool_vocabulary(BoolVocabulary::lenient());
+///
+/// assert_eq!(Some(true), sc.next_bool().unwrap());
+/// assert_eq!(Some(false), sc.next_bool().unwrap());
+/// assert_eq!(Some(true), sc.next_bool().unwrap());
+/// assert_eq!(Some(false), sc.next_bool().unwrap());
+/// assert!(sc.next_bool().is_err());
+///
+/// sc.set_bool_vocabulary(BoolVocabulary {
+///     true_words:       vec!["ja".into()],
+///     false_words:      vec!["nein".into()],
+///     case_insensitive: false,
+/// });
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoolVocabulary {
+    /// The words which mean `true`.
+    pub true_words:       Vec<String>,
+    /// The words which mean `false`.
+    pub false_words:      Vec<String>,
+    /// Whether the letters of a token can be in any case, such as `Yes` or `YES` for `yes`.
+    pub case_insensitive: bool,
+}
+
+impl Default for BoolVocabulary {
+    #[inline]
+    fn default() -> Self {
+        BoolVocabulary {
+            true_words:       vec!["true".into()],
+            false_words:      vec!["false".into()],
+            case_insensitive: false,
+        }
+    }
+}
+
+impl BoolVocabulary {
+    /// Create a case-insensitive vocabulary with `true`, `yes`, `y`, `on` and `1` for `true`, and `false`, `no`, `n`, `off` and `0` for `false`.
+    #[inline]
+    pub fn lenient() -> Self {
+        let words = |words: &[&str]| words.iter().map(|&word| word.to_string()).collect();
+
+        BoolVocabulary {
+            true_words:       words(&["true", "yes", "y", "on", "1"]),
+            false_words:      words(&["false", "no", "n", "off", "0"]),
+            case_insensitive: true,
+        }
+    }
+
+    #[inline]
+    fn matches(&self, token: &str, word: &str) -> bool {
+        if self.case_insensitive {
+            token.chars().flat_map(char::to_lowercase).eq(word.chars().flat_map(char::to_lowercase))
+        } else {
+            token == word
+        }
+    }
+
+    /// Look up a token. It returns `None` if the token is not in this vocabulary.
+    #[inline]
+    pub(crate) fn parse(&self, token: &str) -> Option<bool> {
+        if self.true_words.iter().any(|word| self.matches(token, word)) {
+            Some(true)
+        } else if self.false_words.iter().any(|word| self.matches(token, word)) {
+            Some(false)
+        } else {
+            None
+        }
+    }
+}
+
+/// Parse a token which began at `position` to a `bool` value with `vocabulary`.
+#[inline]
+pub(crate) fn parse_bool<S: AsRef<str>>(
+    token: S,
+    vocabulary: &BoolVocabulary,
+    position: Position,
+) -> Result<bool, ScannerError> {
+    let token = token.as_ref();
+
+    vocabulary.parse(token).ok_or_else(|| ScannerError::ParseBoolError {
+        token: token.to_string(),
+        position,
+    })
+}
@@ -0,0 +1,42 @@
+use std::{
+    fmt::Debug,
+    ops::{Deref, DerefMut},
+};
+
+use generic_array::{
+    typenum::{IsGreaterOrEqual, True, U4},
+    ArrayLength, GenericArray,
+};
+
+/// The size of the buffer of `Scanner` and `ScannerAscii`. It is implemented for the `typenum` unsigned integers which are not less than `U4`, whose buffers are arrays with a size known at compile time, and for `HeapBuffer`, whose buffers are allocated with a size decided at runtime.
+pub trait BufferSize {
+    /// The type of the buffer.
+    type Buffer: Debug + Deref<Target = [u8]> + DerefMut;
+}
+
+impl<N: ArrayLength + IsGreaterOrEqual<U4, Output = True>> BufferSize for N {
+    type Buffer = GenericArray<u8, N>;
+}
+
+/// A buffer size decided at runtime. The buffer is allocated on the heap by the `with_capacity` constructors of `Scanner` and `ScannerAscii`.
+///
+/// ```rust
+/// use scanner_rust::{HeapBuffer, Scanner};
+///
+/// let mut sc: Scanner<_, HeapBuffer> =
+///     Scanner::with_capacity("123 456".as_bytes(), 65536);
+///
+/// assert_eq!(Some(123), sc.next_u16().unwrap());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct HeapBuffer;
+
+impl BufferSize for HeapBuffer {
+    type Buffer = Box<[u8]>;
+}
+
+/// Allocate a buffer whose size is `capacity`, but at least 4 bytes so that a whole UTF-8 char can always fit in it.
+#[inline]
+pub(crate) fn heap_buffer(capacity: usize) -> Box<[u8]> {
+    vec![0; capacity.max(4)].into_boxed_slice()
+}
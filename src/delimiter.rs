@@ -0,0 +1,166 @@
+use crate::whitespaces::*;
+
+/// What the scanners treat as separators between tokens. It affects `next`, `skip_whitespaces` and the methods which parse the next token to a primitive type. Consecutive delimiters are treated as one separator, just like whitespaces.
+///
+/// ```rust
+/// use scanner_rust::{Delimiter, ScannerStr};
+///
+/// let mut sc = ScannerStr::new("1,2,,3");
+///
+/// sc.set_delimiter(',');
+///
+/// assert_eq!(&Delimiter::Chars(vec![',']), sc.delimiter());
+/// assert_eq!(Some(1), sc.next_u8().unwrap());
+/// assert_eq!(Some(2), sc.next_u8().unwrap());
+/// assert_eq!(Some(3), sc.next_u8().unwrap());
+/// assert_eq!(None, sc.next_u8().unwrap());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum Delimiter {
+    /// Whitespaces (`javaWhitespace`). The ASCII scanners only treat ASCII whitespaces as delimiters.
+    #[default]
+    Whitespaces,
+    /// A single byte.
+    Byte(u8),
+    /// Any of the chars.
+    Chars(Vec<char>),
+    /// A whole string. An empty string never matches. For `Scanner` and `ScannerAscii`, a string longer than the buffer never matches either.
+    Str(String),
+}
+
+impl Delimiter {
+    /// Get how many bytes starting with `first_byte` are needed to decide whether they are a delimiter. `0` means they are definitely not.
+    #[inline]
+    pub(crate) fn required_length(&self, first_byte: u8, ascii: bool) -> usize {
+        match self {
+            Delimiter::Whitespaces => {
+                if is_whitespace_1(first_byte) {
+                    1
+                } else if !ascii && (225..=227).contains(&first_byte) {
+                    3
+                } else {
+                    0
+                }
+            },
+            Delimiter::Byte(b) => (*b == first_byte) as usize,
+            Delimiter::Chars(chars) => {
+                let mut buffer = [0; 4];
+
+                chars
+                    .iter()
+                    .filter(|c| c.encode_utf8(&mut buffer).as_bytes()[0] == first_byte)
+                    .map(|c| c.len_utf8())
+                    .max()
+                    .unwrap_or(0)
+            },
+            Delimiter::Str(s) => match s.as_bytes().first() {
+                Some(b) if *b == first_byte => s.len(),
+                _ => 0,
+            },
+        }
+    }
+
+    /// Check whether no delimiter can start with an ASCII digit or a sign, so that the digits of an integer never need to be looked up.
+    #[inline]
+    pub(crate) fn excludes_numbers(&self) -> bool {
+        let is_numeric = |b: u8| b.is_ascii_digit() || b == b'+' || b == b'-';
+
+        match self {
+            Delimiter::Whitespaces => true,
+            Delimiter::Byte(b) => !is_numeric(*b),
+            Delimiter::Chars(chars) => chars.iter().all(|c| !c.is_ascii() || !is_numeric(*c as u8)),
+            Delimiter::Str(s) => s.as_bytes().first().map_or(true, |b| !is_numeric(*b)),
+        }
+    }
+
+    /// Get the length of the delimiter at the start of `data`. `0` means there is no delimiter.
+    pub(crate) fn match_length(&self, data: &[u8], ascii: bool) -> usize {
+        if data.is_empty() {
+            return 0;
+        }
+
+        match self {
+            Delimiter::Whitespaces => {
+                let e = data[0];
+
+                if is_whitespace_1(e) {
+                    1
+                } else if !ascii && data.len() >= 3 && is_whitespace_3(e, data[1], data[2]) {
+                    3
+                } else {
+                    0
+                }
+            },
+            Delimiter::Byte(b) => (*b == data[0]) as usize,
+            Delimiter::Chars(chars) => {
+                let mut buffer = [0; 4];
+
+                for c in chars {
+                    let bytes = c.encode_utf8(&mut buffer).as_bytes();
+
+                    if data.starts_with(bytes) {
+                        return bytes.len();
+                    }
+                }
+
+                0
+            },
+            Delimiter::Str(s) => {
+                if !s.is_empty() && data.starts_with(s.as_bytes()) {
+                    s.len()
+                } else {
+                    0
+                }
+            },
+        }
+    }
+}
+
+impl From<u8> for Delimiter {
+    #[inline]
+    fn from(b: u8) -> Delimiter {
+        Delimiter::Byte(b)
+    }
+}
+
+impl From<char> for Delimiter {
+    #[inline]
+    fn from(c: char) -> Delimiter {
+        Delimiter::Chars(vec![c])
+    }
+}
+
+impl From<&[char]> for Delimiter {
+    #[inline]
+    fn from(chars: &[char]) -> Delimiter {
+        Delimiter::Chars(chars.to_vec())
+    }
+}
+
+impl<const N: usize> From<[char; N]> for Delimiter {
+    #[inline]
+    fn from(chars: [char; N]) -> Delimiter {
+        Delimiter::Chars(chars.to_vec())
+    }
+}
+
+impl From<Vec<char>> for Delimiter {
+    #[inline]
+    fn from(chars: Vec<char>) -> Delimiter {
+        Delimiter::Chars(chars)
+    }
+}
+
+impl From<&str> for Delimiter {
+    #[inline]
+    fn from(s: &str) -> Delimiter {
+        Delimiter::Str(s.to_string())
+    }
+}
+
+impl From<String> for Delimiter {
+    #[inline]
+    fn from(s: String) -> Delimiter {
+        Delimiter::Str(s)
+    }
+}
@@ -0,0 +1,27 @@
+//! Async scanners reading data from `futures_io::AsyncRead`, which is also re-exported as `futures::io::AsyncRead`. This module is available with the `futures-io` feature.
+
+use std::{
+    error::Error,
+    future::poll_fn,
+    io::{self, ErrorKind},
+    pin::Pin,
+    str::FromStr,
+};
+
+use ::futures_io::AsyncRead;
+
+use crate::{
+    bool_vocabulary::parse_bool,
+    integer::{check_radix, parse_integer, Integer},
+    number_format::parse_number,
+    scan_buffer::ScanBuffer,
+    scanner_error::parse_token,
+    BoolVocabulary, Delimiter, NumberFormat, Position, ScannerError,
+};
+
+#[inline]
+async fn read<R: AsyncRead + Unpin>(reader: &mut R, buf: &mut [u8]) -> io::Result<usize> {
+    poll_fn(|cx| Pin::new(&mut *reader).poll_read(cx, buf)).await
+}
+
+async_scanners!("futures_io", "futures_io::AsyncRead");
@@ -0,0 +1,212 @@
+use std::{
+    borrow::Cow,
+    num::{IntErrorKind, ParseIntError},
+    str::FromStr,
+};
+
+use crate::{IntegerOverflow, NumberFormat, Position, ScannerError};
+
+/// Integer types which can be parsed from ASCII digits without going through `str::parse`.
+pub(crate) trait Integer: FromStr<Err = ParseIntError> {
+    const MIN: Self;
+    const MAX: Self;
+    const SIGNED: bool;
+
+    /// Parse decimal digits with an optional sign. It returns `None` if the bytes are not accepted or the number does not fit in the type, and then `str::parse` should be used to get the error.
+    fn from_ascii_digits(bytes: &[u8]) -> Option<Self>;
+
+    /// The same as the inherent `from_str_radix` method of the type.
+    fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseIntError>;
+
+    /// Like `from_str_radix`, but the value wraps around at the boundary of the type instead of overflowing, even below zero for an unsigned type. It returns `None` if the text is not accepted.
+    fn from_str_radix_wrapping(src: &str, radix: u32) -> Option<Self>;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),* $(,)*) => {
+        $(
+            impl Integer for $t {
+                const MIN: Self = <$t>::MIN;
+                const MAX: Self = <$t>::MAX;
+                const SIGNED: bool = <$t>::MIN != 0;
+
+                #[inline]
+                fn from_ascii_digits(bytes: &[u8]) -> Option<Self> {
+                    let (negative, digits) = match bytes {
+                        [b'+', digits @ ..] => (false, digits),
+                        [b'-', digits @ ..] if <$t>::MIN != 0 => (true, digits),
+                        _ => (false, bytes),
+                    };
+
+                    if digits.is_empty() {
+                        return None;
+                    }
+
+                    let mut value: $t = 0;
+
+                    for e in digits {
+                        let digit = e.wrapping_sub(b'0');
+
+                        if digit > 9 {
+                            return None;
+                        }
+
+                        value = value.checked_mul(10)?;
+
+                        value = if negative {
+                            value.checked_sub(digit as $t)?
+                        } else {
+                            value.checked_add(digit as $t)?
+                        };
+                    }
+
+                    Some(value)
+                }
+
+                #[inline]
+                fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseIntError> {
+                    <$t>::from_str_radix(src, radix)
+                }
+
+                fn from_str_radix_wrapping(src: &str, radix: u32) -> Option<Self> {
+                    let (negative, digits) = match src.as_bytes() {
+                        [b'+', digits @ ..] => (false, digits),
+                        [b'-', digits @ ..] => (true, digits),
+                        digits => (false, digits),
+                    };
+
+                    if digits.is_empty() {
+                        return None;
+                    }
+
+                    let mut value: $t = 0;
+
+                    for e in digits {
+                        let digit = (*e as char).to_digit(radix)? as $t;
+
+                        value = value.wrapping_mul(radix as $t);
+
+                        value = if negative {
+                            value.wrapping_sub(digit)
+                        } else {
+                            value.wrapping_add(digit)
+                        };
+                    }
+
+                    Some(value)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Split the `0x`, `0o` or `0b` prefix from the digits. The prefix is only recognized if `radix` is `0` or matches it, so the `0b` of the hexadecimal number `0b1` is not a prefix.
+fn split_radix_prefix(digits: &str, radix: u32) -> (u32, &str) {
+    let prefix_radix = match digits.as_bytes() {
+        [b'0', b'x' | b'X', ..] => 16,
+        [b'0', b'o' | b'O', ..] => 8,
+        [b'0', b'b' | b'B', ..] => 2,
+        _ => 0,
+    };
+
+    if prefix_radix == 0 {
+        (if radix == 0 { 10 } else { radix }, digits)
+    } else if radix == 0 || radix == prefix_radix {
+        (prefix_radix, &digits[2..])
+    } else {
+        (radix, digits)
+    }
+}
+
+/// Get an error whose kind is `IntErrorKind::NegOverflow`, because `ParseIntError` cannot be constructed directly.
+#[inline]
+fn neg_overflow_error() -> ParseIntError {
+    "-129".parse::<i8>().unwrap_err()
+}
+
+/// Get an error whose kind is `IntErrorKind::InvalidDigit`, because `ParseIntError` cannot be constructed directly.
+#[inline]
+fn invalid_digit_error() -> ParseIntError {
+    "x".parse::<u8>().unwrap_err()
+}
+
+/// Check whether `radix` is `0` or in the range from 2 to 36.
+#[inline]
+pub(crate) fn check_radix(radix: u32) -> Result<(), ScannerError> {
+    if radix == 0 || (2..=36).contains(&radix) {
+        Ok(())
+    } else {
+        Err(ScannerError::InvalidRadix {
+            radix,
+        })
+    }
+}
+
+/// Parse a token in `format` which began at `position` to an integer in `radix`, with an optional sign and an optional radix prefix. If `radix` is `0`, it is detected from the prefix, or `10` without one. An overflow is handled as `format.integer_overflow` says, and so is a negative number for an unsigned type.
+pub(crate) fn parse_integer<T: Integer, S: AsRef<str>>(
+    token: S,
+    radix: u32,
+    format: &NumberFormat,
+    position: Position,
+) -> Result<T, ScannerError> {
+    check_radix(radix)?;
+
+    let token = token.as_ref();
+    let normalized = format.normalize(token);
+
+    let (negative, unsigned) = match normalized.as_bytes().first() {
+        Some(b'-') => (true, &normalized[1..]),
+        Some(b'+') => (false, &normalized[1..]),
+        _ => (false, normalized.as_ref()),
+    };
+
+    let (radix, digits) = split_radix_prefix(unsigned, radix);
+
+    // if a prefix is split, put the sign back in front of the digits, so that `from_str_radix` still rejects a second sign and a negative unsigned number
+    let src = if digits.len() == unsigned.len() {
+        Cow::Borrowed(normalized.as_ref())
+    } else {
+        Cow::Owned(format!("{}{digits}", if negative { '-' } else { '+' }))
+    };
+
+    match T::from_str_radix(&src, radix) {
+        Ok(value) => Ok(value),
+        Err(source) => {
+            let source = if digits.chars().any(|c| !c.is_digit(radix)) {
+                // `str::parse` reports an overflow before it gets to an invalid digit after too many digits
+                invalid_digit_error()
+            } else if negative && !T::SIGNED && !digits.is_empty() {
+                // `str::parse` rejects any negative number for an unsigned type as an invalid digit, but `-0` is zero and the others are below the minimum
+                if digits.chars().all(|c| c == '0') {
+                    return Ok(T::MIN);
+                }
+
+                neg_overflow_error()
+            } else {
+                source
+            };
+
+            let value = match (source.kind(), format.integer_overflow) {
+                (IntErrorKind::PosOverflow, IntegerOverflow::Saturating) => {
+                    T::from_str_radix_wrapping(&src, radix).map(|_| T::MAX)
+                },
+                (IntErrorKind::NegOverflow, IntegerOverflow::Saturating) => {
+                    T::from_str_radix_wrapping(&src, radix).map(|_| T::MIN)
+                },
+                (
+                    IntErrorKind::PosOverflow | IntErrorKind::NegOverflow,
+                    IntegerOverflow::Wrapping,
+                ) => T::from_str_radix_wrapping(&src, radix),
+                _ => None,
+            };
+
+            value.ok_or_else(|| ScannerError::ParseIntError {
+                source,
+                token: token.to_string(),
+                position,
+            })
+        },
+    }
+}
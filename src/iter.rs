@@ -0,0 +1,192 @@
+use std::{error::Error, marker::PhantomData, str::FromStr};
+
+use crate::{Scan, ScannerError};
+
+/// Check whether an error only belongs to the item which caused it, so that the scanner can go on with the next item.
+///
+/// A parse error is returned after its token has been consumed, but an I/O error or invalid UTF-8 data may be encountered again and again.
+#[inline]
+fn is_recoverable(err: &ScannerError) -> bool {
+    matches!(
+        err,
+        ScannerError::ParseIntError { .. }
+            | ScannerError::ParseFloatError { .. }
+            | ScannerError::ParseError { .. }
+            | ScannerError::ParseBoolError { .. }
+    )
+}
+
+/// Turn the result of a scanner method into an item of an iterator. After an unrecoverable error is yielded, the iterator ends.
+#[inline]
+fn next_item<T>(
+    finished: &mut bool,
+    f: impl FnOnce() -> Result<Option<T>, ScannerError>,
+) -> Option<Result<T, ScannerError>> {
+    if *finished {
+        return None;
+    }
+
+    match f() {
+        Ok(item) => item.map(Ok),
+        Err(err) => {
+            *finished = !is_recoverable(&err);
+
+            Some(Err(err))
+        },
+    }
+}
+
+/// An iterator over the tokens of a scanner, created by the `tokens` method.
+///
+/// Each item is the result of `next`. Only a parse error lets the iteration go on, because its token has been consumed, so the iterator ends after an I/O error or an `InvalidUtf8` error.
+#[derive(Debug)]
+pub struct Tokens<'s, S: Scan> {
+    scanner:  &'s mut S,
+    finished: bool,
+}
+
+impl<'s, S: Scan> Tokens<'s, S> {
+    #[inline]
+    pub(crate) fn new(scanner: &'s mut S) -> Self {
+        Tokens {
+            scanner,
+            finished: false,
+        }
+    }
+}
+
+impl<'s, S: Scan> Iterator for Tokens<'s, S> {
+    type Item = Result<S::Token, ScannerError>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let scanner = &mut *self.scanner;
+
+        next_item(&mut self.finished, || scanner.next())
+    }
+}
+
+/// An iterator over the tokens or quoted strings of a scanner, created by the `quoted_tokens` method.
+///
+/// Each item is the result of `next_quoted`. The iterator ends after an `UnterminatedQuote` or `InvalidEscape` error, since the rest of a broken quoted string cannot be told apart from the next tokens, as well as after an I/O error or an `InvalidUtf8` error.
+#[derive(Debug)]
+pub struct QuotedTokens<'s, S: Scan> {
+    scanner:  &'s mut S,
+    finished: bool,
+}
+
+impl<'s, S: Scan> QuotedTokens<'s, S> {
+    #[inline]
+    pub(crate) fn new(scanner: &'s mut S) -> Self {
+        QuotedTokens {
+            scanner,
+            finished: false,
+        }
+    }
+}
+
+impl<'s, S: Scan> Iterator for QuotedTokens<'s, S> {
+    type Item = Result<String, ScannerError>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let scanner = &mut *self.scanner;
+
+        next_item(&mut self.finished, || scanner.next_quoted())
+    }
+}
+
+/// An iterator over the lines of a scanner, created by the `lines` method.
+///
+/// Each item is the result of `next_line`. Like `Tokens`, the iterator ends after an I/O error or an `InvalidUtf8` error, and goes on only after a parse error.
+#[derive(Debug)]
+pub struct Lines<'s, S: Scan> {
+    scanner:  &'s mut S,
+    finished: bool,
+}
+
+impl<'s, S: Scan> Lines<'s, S> {
+    #[inline]
+    pub(crate) fn new(scanner: &'s mut S) -> Self {
+        Lines {
+            scanner,
+            finished: false,
+        }
+    }
+}
+
+impl<'s, S: Scan> Iterator for Lines<'s, S> {
+    type Item = Result<S::Line, ScannerError>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let scanner = &mut *self.scanner;
+
+        next_item(&mut self.finished, || scanner.next_line())
+    }
+}
+
+/// An iterator over the chars of a scanner, created by the `chars` method.
+///
+/// Each item is the result of `next_char`. Invalid UTF-8 data is left unconsumed in the strict UTF-8 mode, so the iterator ends after an `InvalidUtf8` error, as well as after an I/O error.
+#[derive(Debug)]
+pub struct Chars<'s, S: Scan> {
+    scanner:  &'s mut S,
+    finished: bool,
+}
+
+impl<'s, S: Scan> Chars<'s, S> {
+    #[inline]
+    pub(crate) fn new(scanner: &'s mut S) -> Self {
+        Chars {
+            scanner,
+            finished: false,
+        }
+    }
+}
+
+impl<'s, S: Scan> Iterator for Chars<'s, S> {
+    type Item = Result<char, ScannerError>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let scanner = &mut *self.scanner;
+
+        next_item(&mut self.finished, || scanner.next_char())
+    }
+}
+
+/// An iterator which parses the tokens of a scanner to values of `T`, created by the `parse_iter` method.
+///
+/// Each item is the result of `next_parse`. A token which cannot be parsed is yielded as an error and then skipped, but the iterator ends after any other error, such as an I/O error.
+#[derive(Debug)]
+pub struct ParseIter<'s, S: Scan, T> {
+    scanner:  &'s mut S,
+    finished: bool,
+    phantom:  PhantomData<fn() -> T>,
+}
+
+impl<'s, S: Scan, T> ParseIter<'s, S, T> {
+    #[inline]
+    pub(crate) fn new(scanner: &'s mut S) -> Self {
+        ParseIter {
+            scanner,
+            finished: false,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<'s, S: Scan, T: FromStr> Iterator for ParseIter<'s, S, T>
+where
+    <T as FromStr>::Err: Error + Send + Sync + 'static,
+{
+    type Item = Result<T, ScannerError>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let scanner = &mut *self.scanner;
+
+        next_item(&mut self.finished, || scanner.next_parse())
+    }
+}
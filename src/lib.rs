@@ -46,6 +46,36 @@ println!("{} + {} = {}", a, b, a + b);
 
 Besides, the `drop_next` and `drop_next_line` methods are useful when you want to skip some data.
 
+Like `mark` and `reset` of `BufferedReader` in Java, after `mark(limit)` is called, `Scanner` and `ScannerAscii` keep up to `limit` consumed bytes, so that `reset` can go back to the mark. For example, you can try to parse the next token to a number, and read it again as a string if it fails.
+
+When you have read too far, for example, a section header while reading key-value lines, the `unread`, `unread_char` and `unread_line` methods can put the data back, so that it will be read again by the next call.
+
+In hot loops, the `next_ref`, `next_raw_ref`, `next_line_ref` and `next_line_raw_ref` methods of `Scanner` can borrow the data from the buffer instead of allocating a new `String` or `Vec<u8>` every time. Alternatively, the `*_into` methods of `Scanner` and `ScannerAscii`, such as `next_into` and `next_line_into`, write the data into a `String` or `Vec<u8>` provided by the caller, so that its allocation can be reused.
+
+All of the scanners can be used as iterators over their tokens, which end at the first error. To handle errors instead, the `tokens`, `lines`, `chars` and `parse_iter` methods return iterators yielding `Result`s, such as `sc.parse_iter::<u32>().take(n).collect::<Result<Vec<_>, _>>()`.
+
+The `has_next`, `has_next_line`, `has_next_parse` and `has_next_*` methods (such as `has_next_i32`) can check what the next data is without consuming it. The `peek_char`, `peek_token`, `peek_line` and `peek_bytes` methods go further and return the next data without consuming it, decoded in the same way as their `next_*` counterparts.
+
+Tokens are separated by whitespaces by default. The `set_delimiter` method can make a scanner separate them by a byte, any of some chars, or a string instead, like `useDelimiter` in Java.
+
+To read a value which may contain delimiters, such as a name with spaces, put it in double or single quotes and use the `next_quoted` method (or the `quoted_tokens` iterator). A doubled quote and the escape sequences `\n`, `\t`, `\r`, `\0`, `\\`, `\'`, `\"` and `\u{...}` can be used inside the quotes, and a token without quotes is returned as it is.
+
+Integers in other radixes, such as hexadecimal numbers in logs, can be read by the `next_*_radix` and `next_*_radix_until` methods (such as `next_u32_radix(16)`). They accept a sign and the `0x`, `0o` or `0b` prefix, and with the radix `0`, the radix is detected from the prefix like `strtol` in C.
+
+Numbers edited by humans, like `1_000_000`, `1,234,567` or `3,14` in European locales, can be read after setting a `NumberFormat` with the `set_number_format` method. It defines the digit separators, the grouping char and the decimal point used by the methods which parse numbers, while `next_parse` and the methods which return strings are not affected.
+
+To tell an integer out of range from a token which is not a number at all, check the `kind` method of `ScannerError`, which returns a `ScannerErrorKind` such as `Overflow`, `Underflow`, `Empty`, `InvalidDigit` or `InvalidFloat`. For bulk data where clamping is acceptable, set the `integer_overflow` field of `NumberFormat` to `IntegerOverflow::Saturating` (or `IntegerOverflow::Wrapping`), and then `next_u8` reads `300` as `255` (or `44`) instead of returning an error.
+
+The `next_bool` and `next_bool_until` methods read `true` and `false` by default. To ask yes/no questions, set `BoolVocabulary::lenient()` with the `set_bool_vocabulary` method, so that answers like `y`, `No`, `on` and `0` are accepted as well, or set a `BoolVocabulary` with your own words.
+
+With the `regex` feature enabled, `Scanner`, `ScannerStr` and `ScannerU8Slice` also have the `next_matching`, `skip_pattern`, `find_in_line` and `find_within_horizon` methods for scanning text by regular expressions.
+
+For numbers which do not fit in `u128` or `f64`, such as monetary amounts or 256-bit hashes, enable the `num-bigint` feature for the `next_bigint` and `next_biguint` methods, or the `rust_decimal` feature for the `next_decimal` method (plus their `_until` variants). The tokens are parsed straight to `BigInt`, `BigUint` or `Decimal`, so no precision is lost.
+
+With the `tokio` or `futures-io` feature enabled, the `scanner_rust::tokio` or `scanner_rust::futures_io` module provides `AsyncScanner` and `AsyncScannerAscii`, which read data from a `tokio::io::AsyncRead` or `futures_io::AsyncRead` reader. Their methods, such as `next_line`, `next` and `next_i64`, are `async` versions of the ones of `Scanner` and `ScannerAscii`.
+
+Invalid UTF-8 data is replaced with `REPLACEMENT_CHARACTER` (�) by default. After `set_strict_utf8(true)` is called, `Scanner` and `ScannerU8Slice` return a `ScannerError::InvalidUtf8` error with the offset and the invalid bytes instead.
+
 The default buffer size is 256 bytes. If you want to change that, you can use the `new2` associated function or the `scan_path2` associated function and define a length explicitly to create an instance of the above structs.
 
 For example, to change the buffer size to 64 bytes,
@@ -57,6 +87,16 @@ use scanner_rust::Scanner;
 let mut sc: Scanner<_, U64> = Scanner::scan_path2("Cargo.toml").unwrap();
 ```
 
+If the buffer size is only known at runtime, for example, it comes from a configuration file, use the `with_capacity` associated function or the `scan_path_with_capacity` associated function instead. The buffer will be allocated on the heap.
+
+```rust
+use scanner_rust::Scanner;
+
+let buffer_size = 65536;
+
+let mut sc = Scanner::scan_path_with_capacity("Cargo.toml", buffer_size).unwrap();
+```
+
 ### Scan a string slice (`&str`)
 
 `ScannerStr` can be used for reading strings from a string slice.
@@ -101,6 +141,29 @@ assert_eq!(Some("\tHello world!".as_bytes()), sc.next_line().unwrap());
 assert_eq!(None, sc.next_line().unwrap());
 ```
 
+Since the whole data is in memory, `ScannerStr`, `ScannerU8Slice` and `ScannerU8SliceAscii` can also go back to where they were, which is handy for writing parsers that need backtracking. The `checkpoint` method saves the current state, and the `restore` method goes back to it. The `try_scan` method does both automatically, going back if the given closure returns an error. Besides, the `set_position` and `remaining` methods can jump to a byte offset and get the unread data.
+
+### Scan generically
+
+All of the above scanners implement the `Scan` trait, so code which only needs the common methods can be written once and used for both streams and in-memory data.
+
+```rust
+use scanner_rust::{Scan, ScannerAscii, ScannerStr};
+
+fn sum<S: Scan>(sc: &mut S) -> i64 {
+    let mut sum = 0;
+
+    while let Some(i) = sc.next_i64().unwrap() {
+        sum += i;
+    }
+
+    sum
+}
+
+assert_eq!(6, sum(&mut ScannerAscii::new("1 2 3".as_bytes())));
+assert_eq!(6, sum(&mut ScannerStr::new("1 2 3")));
+```
+
 */
 
 pub extern crate generic_array;
@@ -108,6 +171,20 @@ pub extern crate generic_array;
 #[macro_use]
 extern crate educe;
 
+#[macro_use]
+mod scan;
+#[cfg(any(feature = "tokio", feature = "futures-io"))]
+#[macro_use]
+mod async_scanner;
+mod bool_vocabulary;
+mod buffer_size;
+mod delimiter;
+mod integer;
+mod iter;
+mod number_format;
+mod position;
+mod quoted;
+mod replay;
 mod scanner;
 mod scanner_ascii;
 mod scanner_error;
@@ -116,6 +193,21 @@ mod scanner_u8_slice;
 mod scanner_u8_slice_ascii;
 mod whitespaces;
 
+#[cfg(any(feature = "tokio", feature = "futures-io"))]
+mod scan_buffer;
+
+#[cfg(feature = "futures-io")]
+pub mod futures_io;
+#[cfg(feature = "tokio")]
+pub mod tokio;
+
+pub use bool_vocabulary::*;
+pub use buffer_size::*;
+pub use delimiter::*;
+pub use iter::*;
+pub use number_format::*;
+pub use position::*;
+pub use scan::*;
 pub use scanner::*;
 pub use scanner_ascii::*;
 pub use scanner_error::*;
@@ -0,0 +1,220 @@
+use std::{borrow::Cow, error::Error, str::FromStr};
+
+#[cfg(any(feature = "num-bigint", feature = "rust_decimal"))]
+use crate::Scan;
+use crate::{Position, ScannerError};
+
+/// How the scanners read numbers written for humans. It affects the methods which parse the next token to an integer or a floating-point number, such as `next_i64`, `next_f64_until`, `next_u32_radix` and `has_next_i32`, but not `next_parse` or the methods which return tokens as strings. A leading `+` is always accepted.
+///
+/// The default format only accepts what `str::parse` accepts. If one char has more than one role, the decimal point takes precedence over the grouping char, which takes precedence over the digit separators.
+///
+/// ```rust
+/// use scanner_rust::{NumberFormat, ScannerStr};
+///
+/// let mut sc = ScannerStr::new("1_000_000 +1.234.567,89 1.5");
+///
+/// sc.set_number_format(NumberFormat {
+///     digit_separators: vec!['_'],
+///     grouping: Some('.'),
+///     decimal_point: ',',
+///     ..NumberFormat::default()
+/// });
+///
+/// assert_eq!(Some(1000000), sc.next_u32().unwrap());
+/// assert_eq!(Some(1234567.89), sc.next_f64().unwrap());
+/// assert!(sc.next_f64().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NumberFormat {
+    /// Chars which can be put between two digits and are ignored, such as `_` in `1_000_000`. The decimal point and the grouping char are not treated as digit separators even if they are in it.
+    pub digit_separators: Vec<char>,
+    /// The char which separates the integer part into groups of three digits, such as `,` in `1,234,567`. A number whose groups are not of three digits is rejected. It is ignored if it is the same as the decimal point.
+    pub grouping:         Option<char>,
+    /// The char between the integer part and the fractional part of a floating-point number, such as `,` in `3,14`. If it is not `.`, `.` is no longer accepted as a decimal point.
+    pub decimal_point:    char,
+    /// What to do if an integer is out of the range of its type.
+    pub integer_overflow: IntegerOverflow,
+}
+
+/// What the scanners do with an integer which is out of the range of its type, such as `300` or `-1` for `u8`.
+///
+/// There are no separate saturating or wrapping methods such as `next_u8_saturating`. The mode is set in the `NumberFormat` of a scanner, and it applies to all of its integer methods, such as `next_u8`, `next_i64_until` and `next_u32_radix`.
+///
+/// ```rust
+/// use scanner_rust::{IntegerOverflow, NumberFormat, ScannerStr};
+///
+/// let mut sc = ScannerStr::new("300 -300 300 12x");
+///
+/// sc.set_number_format(NumberFormat {
+///     integer_overflow: IntegerOverflow::Saturating,
+///     ..NumberFormat::default()
+/// });
+///
+/// assert_eq!(Some(255), sc.next_u8().unwrap());
+/// assert_eq!(Some(-128), sc.next_i8().unwrap());
+///
+/// sc.set_number_format(NumberFormat {
+///     integer_overflow: IntegerOverflow::Wrapping,
+///     ..NumberFormat::default()
+/// });
+///
+/// assert_eq!(Some(44), sc.next_u8().unwrap());
+/// assert!(sc.next_u8().is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum IntegerOverflow {
+    /// Return an error whose kind is `ScannerErrorKind::Overflow` or `ScannerErrorKind::Underflow`.
+    #[default]
+    Checked,
+    /// Clamp the value to the maximum or the minimum of the type.
+    Saturating,
+    /// Wrap the value around at the boundary of the type, like the `wrapping_*` methods of integers.
+    Wrapping,
+}
+
+impl Default for NumberFormat {
+    #[inline]
+    fn default() -> Self {
+        NumberFormat {
+            digit_separators: Vec::new(),
+            grouping:         None,
+            decimal_point:    '.',
+            integer_overflow: IntegerOverflow::Checked,
+        }
+    }
+}
+
+impl NumberFormat {
+    /// Rewrite a token in this format to the format accepted by `str::parse`. A token which does not follow this format is rewritten to something `str::parse` rejects, or left as it is.
+    pub(crate) fn normalize<'a>(&self, token: &'a str) -> Cow<'a, str> {
+        if self.digit_separators.is_empty() && self.grouping.is_none() && self.decimal_point == '.'
+        {
+            return Cow::Borrowed(token);
+        }
+
+        let chars: Vec<char> = token.chars().collect();
+
+        // the decimal point takes precedence over the grouping char, which takes precedence over the digit separators
+        let grouping = self.grouping.filter(|&c| c != self.decimal_point);
+        let is_separator = |c: char| {
+            c != self.decimal_point && Some(c) != grouping && self.digit_separators.contains(&c)
+        };
+
+        // the integer part, in which the grouping char can appear
+        let start = matches!(chars.first(), Some('+' | '-')) as usize;
+        let end = chars[start..]
+            .iter()
+            .position(|&c| !c.is_ascii_digit() && Some(c) != grouping)
+            .map_or(chars.len(), |i| start + i);
+
+        let grouped = match grouping {
+            Some(grouping) => is_grouped(&chars[start..end], grouping),
+            None => false,
+        };
+
+        let is_digit = |i: usize| chars.get(i).map_or(false, char::is_ascii_alphanumeric);
+
+        let mut s = String::with_capacity(token.len());
+
+        for (i, &c) in chars.iter().enumerate() {
+            if grouped && i < end && Some(c) == grouping {
+                continue;
+            }
+
+            if i > 0 && is_separator(c) && is_digit(i - 1) && is_digit(i + 1) {
+                continue;
+            }
+
+            if c == self.decimal_point {
+                s.push('.');
+            } else if c == '.' {
+                // swap them, so that `.` cannot be parsed as a decimal point
+                s.push(self.decimal_point);
+            } else {
+                s.push(c);
+            }
+        }
+
+        Cow::Owned(s)
+    }
+}
+
+/// Check whether the digits are grouped by `grouping` in threes, like `1,234,567`.
+fn is_grouped(digits: &[char], grouping: char) -> bool {
+    let mut groups = digits.split(|&c| c == grouping);
+
+    match groups.next() {
+        Some(first) if (1..=3).contains(&first.len()) => (),
+        _ => return false,
+    }
+
+    let mut count = 0;
+
+    for group in groups {
+        if group.len() != 3 {
+            return false;
+        }
+
+        count += 1;
+    }
+
+    count > 0
+}
+
+/// Parse a token in `format` which began at `position`. The error keeps the original token.
+#[inline]
+pub(crate) fn parse_number<T: FromStr, S: AsRef<str>>(
+    token: S,
+    format: &NumberFormat,
+    position: Position,
+) -> Result<T, ScannerError>
+where
+    <T as FromStr>::Err: Error + Send + Sync + 'static, {
+    let token = token.as_ref();
+
+    format
+        .normalize(token)
+        .parse()
+        .map_err(|err| ScannerError::from_parse_error(err, token, position))
+}
+
+/// Read the next token separated by delimiters and parse it in the number format of the scanner. It is the default of the `Scan` methods which read the numbers of the optional crates.
+#[cfg(any(feature = "num-bigint", feature = "rust_decimal"))]
+pub(crate) fn next_number<T: FromStr, S: Scan + ?Sized>(
+    sc: &mut S,
+) -> Result<Option<T>, ScannerError>
+where
+    <T as FromStr>::Err: Error + Send + Sync + 'static, {
+    sc.skip_whitespaces()?;
+
+    let position = sc.position();
+
+    match sc.next()? {
+        Some(token) => Ok(Some(parse_number(
+            String::from_utf8_lossy(token.as_ref()),
+            sc.number_format(),
+            position,
+        )?)),
+        None => Ok(None),
+    }
+}
+
+/// Read the next text until it reaches a specific boundary and parse it in the number format of the scanner. It is the default of the `Scan` methods which read the numbers of the optional crates.
+#[cfg(any(feature = "num-bigint", feature = "rust_decimal"))]
+pub(crate) fn next_number_until<T: FromStr, S: Scan + ?Sized, B: AsRef<str>>(
+    sc: &mut S,
+    boundary: B,
+) -> Result<Option<T>, ScannerError>
+where
+    <T as FromStr>::Err: Error + Send + Sync + 'static, {
+    let position = sc.position();
+
+    match sc.next_until(boundary)? {
+        Some(token) => Ok(Some(parse_number(
+            String::from_utf8_lossy(token.as_ref()),
+            sc.number_format(),
+            position,
+        )?)),
+        None => Ok(None),
+    }
+}
@@ -0,0 +1,155 @@
+use std::fmt::{self, Display, Formatter};
+
+/// A position in the data read by a scanner.
+///
+/// ```rust
+/// use scanner_rust::{Position, ScannerStr};
+///
+/// let mut sc = ScannerStr::new("123\r\n中文 456");
+///
+/// assert_eq!(Position::default(), sc.position());
+///
+/// sc.next_line().unwrap();
+/// sc.next().unwrap();
+///
+/// assert_eq!(
+///     Position {
+///         offset: 11, line: 2, column: 3
+///     },
+///     sc.position()
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Position {
+    /// The number of bytes which have been consumed.
+    pub offset: usize,
+    /// The line number, starting from `1`. `\n`, `\r`, `\r\n` and `\n\r` are all treated as one line break.
+    pub line:   usize,
+    /// The column number in chars (or bytes for the ASCII scanners), starting from `1`.
+    pub column: usize,
+}
+
+impl Default for Position {
+    #[inline]
+    fn default() -> Self {
+        Position {
+            offset: 0, line: 1, column: 1
+        }
+    }
+}
+
+impl Display for Position {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        f.write_fmt(format_args!("{}:{}", self.line, self.column))
+    }
+}
+
+/// A saved state of an in-memory scanner, which is created by its `checkpoint` method and can be passed to its `restore` method to go back there.
+///
+/// ```rust
+/// use scanner_rust::ScannerStr;
+///
+/// let mut sc = ScannerStr::new("123 456");
+///
+/// let checkpoint = sc.checkpoint();
+///
+/// assert_eq!(Some(123), sc.next_u16().unwrap());
+///
+/// sc.restore(checkpoint);
+///
+/// assert_eq!(0, checkpoint.position().offset);
+/// assert_eq!(Some("123"), sc.next().unwrap());
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Checkpoint {
+    tracker: PositionTracker,
+}
+
+impl Checkpoint {
+    #[inline]
+    pub(crate) fn new(tracker: PositionTracker) -> Checkpoint {
+        Checkpoint {
+            tracker,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn tracker(&self) -> PositionTracker {
+        self.tracker
+    }
+
+    /// Get the position where the checkpoint was created.
+    #[inline]
+    pub fn position(&self) -> Position {
+        self.tracker.position()
+    }
+}
+
+/// Keep the position up to date with the consumed bytes.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct PositionTracker {
+    position:        Position,
+    /// The last consumed byte if it is `\r` or `\n` and has not been paired with another one.
+    last_line_break: Option<u8>,
+}
+
+impl PositionTracker {
+    /// Create a tracker which has consumed `data`.
+    #[inline]
+    pub(crate) fn from_data(data: &[u8], ascii: bool) -> PositionTracker {
+        let mut tracker = PositionTracker::default();
+
+        tracker.advance(data, ascii);
+
+        tracker
+    }
+
+    #[inline]
+    pub(crate) fn position(&self) -> Position {
+        self.position
+    }
+
+    /// Check whether `e` is the second byte of `\r\n` or `\n\r` if it is consumed next, which means that the last consumed byte is the first one.
+    #[inline]
+    pub(crate) fn completes_line_break(&self, e: u8) -> bool {
+        matches!((self.last_line_break, e), (Some(b'\r'), b'\n') | (Some(b'\n'), b'\r'))
+    }
+
+    pub(crate) fn advance(&mut self, data: &[u8], ascii: bool) {
+        for &e in data {
+            match e {
+                b'\n' | b'\r' => {
+                    match self.last_line_break.take() {
+                        // the second byte of `\r\n` or `\n\r`
+                        Some(last) if last != e => (),
+                        _ => {
+                            self.position.line += 1;
+                            self.position.column = 1;
+                            self.last_line_break = Some(e);
+                        },
+                    }
+                },
+                _ => {
+                    self.last_line_break = None;
+
+                    // do not count UTF-8 continuation bytes
+                    if ascii || e & 0xC0 != 0x80 {
+                        self.position.column += 1;
+                    }
+                },
+            }
+        }
+
+        self.position.offset += data.len();
+    }
+}
+
+/// Get the length of the line break at the start of `data`. `\r\n` and `\n\r` are both one line break.
+#[inline]
+pub(crate) fn line_break_length(data: &[u8]) -> usize {
+    match data {
+        [b'\r', b'\n', ..] | [b'\n', b'\r', ..] => 2,
+        _ => 1,
+    }
+}
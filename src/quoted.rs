@@ -0,0 +1,111 @@
+use crate::{Position, Scan, ScannerError};
+
+/// Read the next token, which may be a quoted string. It is shared by the `next_quoted` methods of all scanners, so they follow exactly the same rules.
+///
+/// A quoted string starts with `"` or `'` and ends with the same quote. Inside it, a doubled quote stands for the quote itself, and a backslash starts one of the escape sequences `\n`, `\t`, `\r`, `\0`, `\\`, `\'`, `\"` and `\u{...}`.
+pub(crate) fn next_quoted<S: Scan>(sc: &mut S) -> Result<Option<String>, ScannerError> {
+    if !sc.skip_whitespaces()? {
+        return Ok(None);
+    }
+
+    let quote = match sc.peek_char()? {
+        Some(c @ ('"' | '\'')) => c,
+        Some(_) => {
+            return Ok(sc.next()?.map(|token| String::from_utf8_lossy(token.as_ref()).into_owned()))
+        },
+        None => return Ok(None),
+    };
+
+    let position = sc.position();
+
+    sc.next_char()?;
+
+    let mut s = String::new();
+
+    loop {
+        let escape_position = sc.position();
+
+        match sc.next_char()? {
+            Some(c) if c == quote => {
+                if sc.peek_char()? != Some(quote) {
+                    return Ok(Some(s));
+                }
+
+                sc.next_char()?;
+
+                s.push(quote);
+            },
+            Some('\\') => s.push(unescape(sc, escape_position)?),
+            Some(c) => s.push(c),
+            None => {
+                return Err(ScannerError::UnterminatedQuote {
+                    position,
+                })
+            },
+        }
+    }
+}
+
+/// Read an escape sequence after its backslash, which is at `position`.
+fn unescape<S: Scan>(sc: &mut S, position: Position) -> Result<char, ScannerError> {
+    let mut sequence = String::from('\\');
+
+    let c = match sc.next_char()? {
+        Some(c) => c,
+        None => {
+            return Err(ScannerError::InvalidEscape {
+                sequence,
+                position,
+            })
+        },
+    };
+
+    sequence.push(c);
+
+    match c {
+        'n' => return Ok('\n'),
+        't' => return Ok('\t'),
+        'r' => return Ok('\r'),
+        '0' => return Ok('\0'),
+        '\\' | '\'' | '"' => return Ok(c),
+        'u' if sc.peek_char()? == Some('{') => (),
+        _ => {
+            return Err(ScannerError::InvalidEscape {
+                sequence,
+                position,
+            })
+        },
+    }
+
+    sequence.push('{');
+    sc.next_char()?;
+
+    let mut code = 0u32;
+
+    // at most 6 hex digits, and then `}`
+    for i in 0..=6 {
+        let c = sc.next_char()?;
+
+        if let Some(c) = c {
+            sequence.push(c);
+        }
+
+        match c {
+            Some('}') if i > 0 => {
+                return char::from_u32(code).ok_or(ScannerError::InvalidEscape {
+                    sequence,
+                    position,
+                })
+            },
+            Some(c) if i < 6 && c.is_ascii_hexdigit() => {
+                code = code * 16 + c.to_digit(16).unwrap();
+            },
+            _ => break,
+        }
+    }
+
+    Err(ScannerError::InvalidEscape {
+        sequence,
+        position,
+    })
+}
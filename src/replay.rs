@@ -0,0 +1,237 @@
+use std::io::{self, Read};
+
+use crate::position::PositionTracker;
+
+/// A reader which returns the data pushed back into it before reading from the inner reader.
+pub(crate) struct Replay<R> {
+    inner:  R,
+    data:   Vec<u8>,
+    offset: usize,
+    /// The byte which should be dropped if it is the first one read from the inner reader.
+    skip:   Option<u8>,
+}
+
+impl<R> Replay<R> {
+    #[inline]
+    pub(crate) fn new(inner: R) -> Replay<R> {
+        Replay {
+            inner,
+            data: Vec::new(),
+            offset: 0,
+            skip: None,
+        }
+    }
+
+    /// Drop the next byte if it is `byte`. If the next byte has not been read from the inner reader yet, it will be checked after it is.
+    pub(crate) fn skip_if_next(&mut self, byte: u8) {
+        if self.offset == self.data.len() {
+            self.skip = Some(byte);
+        } else if self.data[self.offset] == byte {
+            self.offset += 1;
+        }
+    }
+
+    /// Put `data` in front of the data which has not been read yet.
+    pub(crate) fn push_front(&mut self, data: &[u8]) {
+        if data.is_empty() {
+            return;
+        }
+
+        self.data.drain(..self.offset);
+        self.offset = 0;
+
+        self.data.splice(0..0, data.iter().copied());
+    }
+}
+
+impl<R: Read> Read for Replay<R> {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.offset == self.data.len() {
+            let size = self.inner.read(buf)?;
+
+            return match self.skip.take() {
+                Some(byte) if size > 0 && buf[0] == byte => {
+                    buf.copy_within(1..size, 0);
+
+                    if size == 1 {
+                        self.inner.read(buf)
+                    } else {
+                        Ok(size - 1)
+                    }
+                },
+                _ => Ok(size),
+            };
+        }
+
+        let length = buf.len().min(self.data.len() - self.offset);
+
+        buf[..length].copy_from_slice(&self.data[self.offset..(self.offset + length)]);
+
+        self.offset += length;
+
+        if self.offset == self.data.len() {
+            self.data.clear();
+            self.offset = 0;
+        }
+
+        Ok(length)
+    }
+}
+
+/// The state of a streaming scanner saved by `mark`, plus the bytes consumed after it.
+pub(crate) struct Mark {
+    limit:                   usize,
+    pub(crate) tracker:      PositionTracker,
+    pub(crate) passing_byte: Option<u8>,
+    pub(crate) data:         Vec<u8>,
+}
+
+impl Mark {
+    #[inline]
+    pub(crate) fn new(limit: usize, tracker: PositionTracker, passing_byte: Option<u8>) -> Mark {
+        Mark {
+            limit,
+            tracker,
+            passing_byte,
+            data: Vec::new(),
+        }
+    }
+
+    /// Keep the consumed bytes. If there are more than `limit` bytes, it will return `false` and the mark becomes invalid.
+    #[inline]
+    pub(crate) fn record(&mut self, data: &[u8]) -> bool {
+        if self.data.len() + data.len() > self.limit {
+            return false;
+        }
+
+        self.data.extend_from_slice(data);
+
+        true
+    }
+
+    /// Forget the consumed bytes which are put back. If they are not the last bytes consumed after the mark, it will return `false` and the mark becomes invalid.
+    #[inline]
+    pub(crate) fn unrecord(&mut self, data: &[u8]) -> bool {
+        if !self.data.ends_with(data) {
+            return false;
+        }
+
+        self.data.truncate(self.data.len() - data.len());
+
+        true
+    }
+
+    /// Move the mark past the pending byte of a line break if it has been dropped after the mark, so that going back to the mark does not leave the byte pending again, and return the byte.
+    #[inline]
+    pub(crate) fn skip_passing_byte(&mut self) -> Option<u8> {
+        let passing_byte = self.passing_byte?;
+
+        // the pending byte is always dropped before anything else is consumed
+        if self.data.first() != Some(&passing_byte) {
+            return None;
+        }
+
+        self.data.remove(0);
+        self.tracker.advance(&[passing_byte], true);
+        self.passing_byte = None;
+
+        Some(passing_byte)
+    }
+}
+
+/// The bytes consumed by the latest read of a streaming scanner and its state before them, so that `unread` can move the position back if the same bytes are put back.
+///
+/// The consumed bytes are left in the buffer of the scanner, right before its offset, and only copied when the buffer is about to be overwritten, so a read which does not cross the end of the buffer copies nothing.
+#[derive(Default)]
+pub(crate) struct History {
+    tracker:      PositionTracker,
+    passing_byte: Option<u8>,
+    /// The bytes of the latest read which have been copied out of the buffer.
+    data:         Vec<u8>,
+    /// The number of the bytes of the latest read which follow `data` and are still in the buffer.
+    pending:      usize,
+}
+
+impl History {
+    /// Forget everything and start from the given state.
+    #[inline]
+    pub(crate) fn clear(&mut self, tracker: PositionTracker, passing_byte: Option<u8>) {
+        self.tracker = tracker;
+        self.passing_byte = passing_byte;
+        self.data.clear();
+        self.pending = 0;
+    }
+
+    /// Start a new read in the given state.
+    #[inline]
+    pub(crate) fn begin(&mut self, tracker: PositionTracker, passing_byte: Option<u8>) {
+        // the latest read has not consumed anything, so it is still the one to go back to
+        if self.data.is_empty() && self.pending == 0 {
+            return;
+        }
+
+        self.clear(tracker, passing_byte);
+    }
+
+    /// Count the `distance` bytes which have just been consumed from the buffer.
+    #[inline]
+    pub(crate) fn record(&mut self, distance: usize) {
+        self.pending += distance;
+    }
+
+    /// Keep a byte which has been consumed without being in the buffer. The bytes in the buffer should have been copied by `keep` before.
+    #[inline]
+    pub(crate) fn push(&mut self, e: u8) {
+        debug_assert_eq!(0, self.pending);
+
+        self.data.push(e);
+    }
+
+    /// Copy the bytes of the latest read out of the buffer. `consumed` is the part of the buffer before its offset. It should be called before the buffer is overwritten or its offset is moved back.
+    #[inline]
+    pub(crate) fn keep(&mut self, consumed: &[u8]) {
+        if self.pending > 0 {
+            self.data.extend_from_slice(&consumed[(consumed.len() - self.pending)..]);
+            self.pending = 0;
+        }
+    }
+
+    /// Find the line break which was consumed right after `line`, if the line is what has just been consumed. An empty line break means that the line ended at EOF. The bytes in the buffer should have been copied by `keep` before.
+    pub(crate) fn line_break_after(&self, line: &[u8]) -> Option<&'static [u8]> {
+        debug_assert_eq!(0, self.pending);
+
+        let candidates: [&'static [u8]; 5] = [b"\r\n", b"\n\r", b"\r", b"\n", b""];
+
+        candidates.into_iter().find(|line_break| {
+            self.data.ends_with(line_break)
+                && self.data[..(self.data.len() - line_break.len())].ends_with(line)
+        })
+    }
+
+    /// Forget the consumed bytes which are put back, and get the state before them. If they are not the last bytes consumed by the latest read, it will return `None`. The bytes in the buffer should have been copied by `keep` before.
+    pub(crate) fn unrecord(
+        &mut self,
+        data: &[u8],
+        ascii: bool,
+    ) -> Option<(PositionTracker, Option<u8>)> {
+        debug_assert_eq!(0, self.pending);
+
+        if data.is_empty() || !self.data.ends_with(data) {
+            return None;
+        }
+
+        let length = self.data.len() - data.len();
+
+        let mut tracker = self.tracker;
+
+        tracker.advance(&self.data[..length], ascii);
+
+        // the other byte of a line break is not pending in the middle of a read
+        let passing_byte = if length == 0 { self.passing_byte } else { None };
+
+        self.data.truncate(length);
+
+        Some((tracker, passing_byte))
+    }
+}
@@ -0,0 +1,687 @@
+use std::{error::Error, str::FromStr};
+
+#[cfg(feature = "num-bigint")]
+use num_bigint::{BigInt, BigUint};
+#[cfg(feature = "rust_decimal")]
+use rust_decimal::Decimal;
+
+#[cfg(any(feature = "num-bigint", feature = "rust_decimal"))]
+use crate::number_format;
+use crate::{
+    BoolVocabulary, Chars, Delimiter, Lines, NumberFormat, ParseIter, Position, QuotedTokens,
+    ScannerError, Tokens,
+};
+
+/// The common interface of `Scanner`, `ScannerAscii`, `ScannerStr`, `ScannerU8Slice` and `ScannerU8SliceAscii`, which can be used to write code that is generic over any scanner.
+///
+/// The methods which depend on optional features, such as `next_bigint` and `next_decimal`, have default implementations built on `next` and `next_until`, so enabling a feature never breaks another implementation of this trait.
+///
+/// ```rust
+/// use scanner_rust::{Scan, Scanner, ScannerStr};
+///
+/// fn sum<S: Scan>(sc: &mut S) -> i64 {
+///     let mut sum = 0;
+///
+///     while let Some(i) = sc.next_i64().unwrap() {
+///         sum += i;
+///     }
+///
+///     sum
+/// }
+///
+/// assert_eq!(6, sum(&mut Scanner::new("1 2 3".as_bytes())));
+/// assert_eq!(6, sum(&mut ScannerStr::new("1 2 3")));
+/// ```
+pub trait Scan {
+    /// The type of tokens returned by `next` and `next_until`.
+    type Token: AsRef<[u8]>;
+    /// The type of lines returned by `next_line`.
+    type Line: AsRef<[u8]>;
+
+    /// Set the delimiter used by `next`, `skip_whitespaces` and the methods which parse the next token to a primitive type.
+    fn set_delimiter<D: Into<Delimiter>>(&mut self, delimiter: D);
+
+    /// Get the delimiter used by `next`, `skip_whitespaces` and the methods which parse the next token to a primitive type.
+    fn delimiter(&self) -> &Delimiter;
+
+    /// Set the number format used by the methods which parse the next token to an integer or a floating-point number.
+    fn set_number_format(&mut self, number_format: NumberFormat);
+
+    /// Get the number format used by the methods which parse the next token to an integer or a floating-point number.
+    fn number_format(&self) -> &NumberFormat;
+
+    /// Set the words read as `true` or `false` by the `next_bool` and `next_bool_until` methods.
+    fn set_bool_vocabulary(&mut self, bool_vocabulary: BoolVocabulary);
+
+    /// Get the words read as `true` or `false` by the `next_bool` and `next_bool_until` methods.
+    fn bool_vocabulary(&self) -> &BoolVocabulary;
+
+    /// Get the position of the data which will be read next.
+    fn position(&self) -> Position;
+
+    /// Read the next char. If there is nothing to read, it will return `Ok(None)`.
+    fn next_char(&mut self) -> Result<Option<char>, ScannerError>;
+
+    /// Read the next line but not include the tailing line character (or line chracters like `CrLf`(`\r\n`)). If there is nothing to read, it will return `Ok(None)`.
+    fn next_line(&mut self) -> Result<Option<Self::Line>, ScannerError>;
+
+    /// Skip the next delimiters, which are whitespaces (`javaWhitespace`) by default. If there is nothing to read, it will return `Ok(false)`.
+    fn skip_whitespaces(&mut self) -> Result<bool, ScannerError>;
+
+    /// Read the next token separated by delimiters. If there is nothing to read, it will return `Ok(None)`.
+    fn next(&mut self) -> Result<Option<Self::Token>, ScannerError>;
+
+    /// Read the next token separated by delimiters, or a quoted string which can contain delimiters, and unescape it. If there is nothing to read, it will return `Ok(None)`.
+    fn next_quoted(&mut self) -> Result<Option<String>, ScannerError>;
+
+    /// Read the next text until it reaches a specific boundary. If there is nothing to read, it will return `Ok(None)`.
+    fn next_until<S: AsRef<str>>(
+        &mut self,
+        boundary: S,
+    ) -> Result<Option<Self::Token>, ScannerError>;
+
+    /// Read the next token separated by the delimiter and parse it to a value of any type which implements `FromStr`. If there is nothing to read, it will return `Ok(None)`.
+    fn next_parse<T: FromStr>(&mut self) -> Result<Option<T>, ScannerError>
+    where
+        <T as FromStr>::Err: Error + Send + Sync + 'static;
+
+    /// Read the next text until it reaches a specific boundary and parse it to a value of any type which implements `FromStr`. If there is nothing to read, it will return `Ok(None)`.
+    fn next_parse_until<T: FromStr, S: AsRef<str>>(
+        &mut self,
+        boundary: S,
+    ) -> Result<Option<T>, ScannerError>
+    where
+        <T as FromStr>::Err: Error + Send + Sync + 'static;
+
+    /// Read the next token separated by the delimiter and parse it to a `u8` value. If there is nothing to read, it will return `Ok(None)`.
+    fn next_u8(&mut self) -> Result<Option<u8>, ScannerError>;
+
+    /// Read the next token separated by the delimiter and parse it to a `u16` value. If there is nothing to read, it will return `Ok(None)`.
+    fn next_u16(&mut self) -> Result<Option<u16>, ScannerError>;
+
+    /// Read the next token separated by the delimiter and parse it to a `u32` value. If there is nothing to read, it will return `Ok(None)`.
+    fn next_u32(&mut self) -> Result<Option<u32>, ScannerError>;
+
+    /// Read the next token separated by the delimiter and parse it to a `u64` value. If there is nothing to read, it will return `Ok(None)`.
+    fn next_u64(&mut self) -> Result<Option<u64>, ScannerError>;
+
+    /// Read the next token separated by the delimiter and parse it to a `u128` value. If there is nothing to read, it will return `Ok(None)`.
+    fn next_u128(&mut self) -> Result<Option<u128>, ScannerError>;
+
+    /// Read the next token separated by the delimiter and parse it to a `usize` value. If there is nothing to read, it will return `Ok(None)`.
+    fn next_usize(&mut self) -> Result<Option<usize>, ScannerError>;
+
+    /// Read the next token separated by the delimiter and parse it to a `i8` value. If there is nothing to read, it will return `Ok(None)`.
+    fn next_i8(&mut self) -> Result<Option<i8>, ScannerError>;
+
+    /// Read the next token separated by the delimiter and parse it to a `i16` value. If there is nothing to read, it will return `Ok(None)`.
+    fn next_i16(&mut self) -> Result<Option<i16>, ScannerError>;
+
+    /// Read the next token separated by the delimiter and parse it to a `i32` value. If there is nothing to read, it will return `Ok(None)`.
+    fn next_i32(&mut self) -> Result<Option<i32>, ScannerError>;
+
+    /// Read the next token separated by the delimiter and parse it to a `i64` value. If there is nothing to read, it will return `Ok(None)`.
+    fn next_i64(&mut self) -> Result<Option<i64>, ScannerError>;
+
+    /// Read the next token separated by the delimiter and parse it to a `i128` value. If there is nothing to read, it will return `Ok(None)`.
+    fn next_i128(&mut self) -> Result<Option<i128>, ScannerError>;
+
+    /// Read the next token separated by the delimiter and parse it to a `isize` value. If there is nothing to read, it will return `Ok(None)`.
+    fn next_isize(&mut self) -> Result<Option<isize>, ScannerError>;
+
+    /// Read the next token separated by the delimiter and parse it to a `f32` value. If there is nothing to read, it will return `Ok(None)`.
+    fn next_f32(&mut self) -> Result<Option<f32>, ScannerError>;
+
+    /// Read the next token separated by the delimiter and parse it to a `f64` value. If there is nothing to read, it will return `Ok(None)`.
+    fn next_f64(&mut self) -> Result<Option<f64>, ScannerError>;
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `u8` value. If there is nothing to read, it will return `Ok(None)`.
+    fn next_u8_until<S: AsRef<str>>(&mut self, boundary: S) -> Result<Option<u8>, ScannerError>;
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `u16` value. If there is nothing to read, it will return `Ok(None)`.
+    fn next_u16_until<S: AsRef<str>>(&mut self, boundary: S) -> Result<Option<u16>, ScannerError>;
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `u32` value. If there is nothing to read, it will return `Ok(None)`.
+    fn next_u32_until<S: AsRef<str>>(&mut self, boundary: S) -> Result<Option<u32>, ScannerError>;
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `u64` value. If there is nothing to read, it will return `Ok(None)`.
+    fn next_u64_until<S: AsRef<str>>(&mut self, boundary: S) -> Result<Option<u64>, ScannerError>;
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `u128` value. If there is nothing to read, it will return `Ok(None)`.
+    fn next_u128_until<S: AsRef<str>>(&mut self, boundary: S)
+        -> Result<Option<u128>, ScannerError>;
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `usize` value. If there is nothing to read, it will return `Ok(None)`.
+    fn next_usize_until<S: AsRef<str>>(
+        &mut self,
+        boundary: S,
+    ) -> Result<Option<usize>, ScannerError>;
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `i8` value. If there is nothing to read, it will return `Ok(None)`.
+    fn next_i8_until<S: AsRef<str>>(&mut self, boundary: S) -> Result<Option<i8>, ScannerError>;
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `i16` value. If there is nothing to read, it will return `Ok(None)`.
+    fn next_i16_until<S: AsRef<str>>(&mut self, boundary: S) -> Result<Option<i16>, ScannerError>;
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `i32` value. If there is nothing to read, it will return `Ok(None)`.
+    fn next_i32_until<S: AsRef<str>>(&mut self, boundary: S) -> Result<Option<i32>, ScannerError>;
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `i64` value. If there is nothing to read, it will return `Ok(None)`.
+    fn next_i64_until<S: AsRef<str>>(&mut self, boundary: S) -> Result<Option<i64>, ScannerError>;
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `i128` value. If there is nothing to read, it will return `Ok(None)`.
+    fn next_i128_until<S: AsRef<str>>(&mut self, boundary: S)
+        -> Result<Option<i128>, ScannerError>;
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `isize` value. If there is nothing to read, it will return `Ok(None)`.
+    fn next_isize_until<S: AsRef<str>>(
+        &mut self,
+        boundary: S,
+    ) -> Result<Option<isize>, ScannerError>;
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `f32` value. If there is nothing to read, it will return `Ok(None)`.
+    fn next_f32_until<S: AsRef<str>>(&mut self, boundary: S) -> Result<Option<f32>, ScannerError>;
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `f64` value. If there is nothing to read, it will return `Ok(None)`.
+    fn next_f64_until<S: AsRef<str>>(&mut self, boundary: S) -> Result<Option<f64>, ScannerError>;
+
+    /// Read the next token separated by delimiters and parse it to a `u8` value in the given radix, which can also be detected from the `0x`, `0o` or `0b` prefix if it is `0`. If there is nothing to read, it will return `Ok(None)`.
+    fn next_u8_radix(&mut self, radix: u32) -> Result<Option<u8>, ScannerError>;
+
+    /// Read the next token separated by delimiters and parse it to a `u16` value in the given radix, which can also be detected from the `0x`, `0o` or `0b` prefix if it is `0`. If there is nothing to read, it will return `Ok(None)`.
+    fn next_u16_radix(&mut self, radix: u32) -> Result<Option<u16>, ScannerError>;
+
+    /// Read the next token separated by delimiters and parse it to a `u32` value in the given radix, which can also be detected from the `0x`, `0o` or `0b` prefix if it is `0`. If there is nothing to read, it will return `Ok(None)`.
+    fn next_u32_radix(&mut self, radix: u32) -> Result<Option<u32>, ScannerError>;
+
+    /// Read the next token separated by delimiters and parse it to a `u64` value in the given radix, which can also be detected from the `0x`, `0o` or `0b` prefix if it is `0`. If there is nothing to read, it will return `Ok(None)`.
+    fn next_u64_radix(&mut self, radix: u32) -> Result<Option<u64>, ScannerError>;
+
+    /// Read the next token separated by delimiters and parse it to a `u128` value in the given radix, which can also be detected from the `0x`, `0o` or `0b` prefix if it is `0`. If there is nothing to read, it will return `Ok(None)`.
+    fn next_u128_radix(&mut self, radix: u32) -> Result<Option<u128>, ScannerError>;
+
+    /// Read the next token separated by delimiters and parse it to a `usize` value in the given radix, which can also be detected from the `0x`, `0o` or `0b` prefix if it is `0`. If there is nothing to read, it will return `Ok(None)`.
+    fn next_usize_radix(&mut self, radix: u32) -> Result<Option<usize>, ScannerError>;
+
+    /// Read the next token separated by delimiters and parse it to a `i8` value in the given radix, which can also be detected from the `0x`, `0o` or `0b` prefix if it is `0`. If there is nothing to read, it will return `Ok(None)`.
+    fn next_i8_radix(&mut self, radix: u32) -> Result<Option<i8>, ScannerError>;
+
+    /// Read the next token separated by delimiters and parse it to a `i16` value in the given radix, which can also be detected from the `0x`, `0o` or `0b` prefix if it is `0`. If there is nothing to read, it will return `Ok(None)`.
+    fn next_i16_radix(&mut self, radix: u32) -> Result<Option<i16>, ScannerError>;
+
+    /// Read the next token separated by delimiters and parse it to a `i32` value in the given radix, which can also be detected from the `0x`, `0o` or `0b` prefix if it is `0`. If there is nothing to read, it will return `Ok(None)`.
+    fn next_i32_radix(&mut self, radix: u32) -> Result<Option<i32>, ScannerError>;
+
+    /// Read the next token separated by delimiters and parse it to a `i64` value in the given radix, which can also be detected from the `0x`, `0o` or `0b` prefix if it is `0`. If there is nothing to read, it will return `Ok(None)`.
+    fn next_i64_radix(&mut self, radix: u32) -> Result<Option<i64>, ScannerError>;
+
+    /// Read the next token separated by delimiters and parse it to a `i128` value in the given radix, which can also be detected from the `0x`, `0o` or `0b` prefix if it is `0`. If there is nothing to read, it will return `Ok(None)`.
+    fn next_i128_radix(&mut self, radix: u32) -> Result<Option<i128>, ScannerError>;
+
+    /// Read the next token separated by delimiters and parse it to a `isize` value in the given radix, which can also be detected from the `0x`, `0o` or `0b` prefix if it is `0`. If there is nothing to read, it will return `Ok(None)`.
+    fn next_isize_radix(&mut self, radix: u32) -> Result<Option<isize>, ScannerError>;
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `u8` value in the given radix, which can also be detected from the `0x`, `0o` or `0b` prefix if it is `0`. If there is nothing to read, it will return `Ok(None)`.
+    fn next_u8_radix_until<S: AsRef<str>>(
+        &mut self,
+        boundary: S,
+        radix: u32,
+    ) -> Result<Option<u8>, ScannerError>;
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `u16` value in the given radix, which can also be detected from the `0x`, `0o` or `0b` prefix if it is `0`. If there is nothing to read, it will return `Ok(None)`.
+    fn next_u16_radix_until<S: AsRef<str>>(
+        &mut self,
+        boundary: S,
+        radix: u32,
+    ) -> Result<Option<u16>, ScannerError>;
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `u32` value in the given radix, which can also be detected from the `0x`, `0o` or `0b` prefix if it is `0`. If there is nothing to read, it will return `Ok(None)`.
+    fn next_u32_radix_until<S: AsRef<str>>(
+        &mut self,
+        boundary: S,
+        radix: u32,
+    ) -> Result<Option<u32>, ScannerError>;
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `u64` value in the given radix, which can also be detected from the `0x`, `0o` or `0b` prefix if it is `0`. If there is nothing to read, it will return `Ok(None)`.
+    fn next_u64_radix_until<S: AsRef<str>>(
+        &mut self,
+        boundary: S,
+        radix: u32,
+    ) -> Result<Option<u64>, ScannerError>;
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `u128` value in the given radix, which can also be detected from the `0x`, `0o` or `0b` prefix if it is `0`. If there is nothing to read, it will return `Ok(None)`.
+    fn next_u128_radix_until<S: AsRef<str>>(
+        &mut self,
+        boundary: S,
+        radix: u32,
+    ) -> Result<Option<u128>, ScannerError>;
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `usize` value in the given radix, which can also be detected from the `0x`, `0o` or `0b` prefix if it is `0`. If there is nothing to read, it will return `Ok(None)`.
+    fn next_usize_radix_until<S: AsRef<str>>(
+        &mut self,
+        boundary: S,
+        radix: u32,
+    ) -> Result<Option<usize>, ScannerError>;
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `i8` value in the given radix, which can also be detected from the `0x`, `0o` or `0b` prefix if it is `0`. If there is nothing to read, it will return `Ok(None)`.
+    fn next_i8_radix_until<S: AsRef<str>>(
+        &mut self,
+        boundary: S,
+        radix: u32,
+    ) -> Result<Option<i8>, ScannerError>;
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `i16` value in the given radix, which can also be detected from the `0x`, `0o` or `0b` prefix if it is `0`. If there is nothing to read, it will return `Ok(None)`.
+    fn next_i16_radix_until<S: AsRef<str>>(
+        &mut self,
+        boundary: S,
+        radix: u32,
+    ) -> Result<Option<i16>, ScannerError>;
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `i32` value in the given radix, which can also be detected from the `0x`, `0o` or `0b` prefix if it is `0`. If there is nothing to read, it will return `Ok(None)`.
+    fn next_i32_radix_until<S: AsRef<str>>(
+        &mut self,
+        boundary: S,
+        radix: u32,
+    ) -> Result<Option<i32>, ScannerError>;
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `i64` value in the given radix, which can also be detected from the `0x`, `0o` or `0b` prefix if it is `0`. If there is nothing to read, it will return `Ok(None)`.
+    fn next_i64_radix_until<S: AsRef<str>>(
+        &mut self,
+        boundary: S,
+        radix: u32,
+    ) -> Result<Option<i64>, ScannerError>;
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `i128` value in the given radix, which can also be detected from the `0x`, `0o` or `0b` prefix if it is `0`. If there is nothing to read, it will return `Ok(None)`.
+    fn next_i128_radix_until<S: AsRef<str>>(
+        &mut self,
+        boundary: S,
+        radix: u32,
+    ) -> Result<Option<i128>, ScannerError>;
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `isize` value in the given radix, which can also be detected from the `0x`, `0o` or `0b` prefix if it is `0`. If there is nothing to read, it will return `Ok(None)`.
+    fn next_isize_radix_until<S: AsRef<str>>(
+        &mut self,
+        boundary: S,
+        radix: u32,
+    ) -> Result<Option<isize>, ScannerError>;
+
+    /// Read the next token separated by delimiters and parse it to a `bool` value with the boolean vocabulary. If there is nothing to read, it will return `Ok(None)`.
+    fn next_bool(&mut self) -> Result<Option<bool>, ScannerError>;
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `bool` value with the boolean vocabulary. If there is nothing to read, it will return `Ok(None)`.
+    fn next_bool_until<S: AsRef<str>>(&mut self, boundary: S)
+        -> Result<Option<bool>, ScannerError>;
+
+    /// Read the next token separated by delimiters and parse it to a `BigInt` value without losing precision. If there is nothing to read, it will return `Ok(None)`.
+    #[cfg(feature = "num-bigint")]
+    #[inline]
+    fn next_bigint(&mut self) -> Result<Option<BigInt>, ScannerError> {
+        number_format::next_number(self)
+    }
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `BigInt` value without losing precision. If there is nothing to read, it will return `Ok(None)`.
+    #[cfg(feature = "num-bigint")]
+    #[inline]
+    fn next_bigint_until<S: AsRef<str>>(
+        &mut self,
+        boundary: S,
+    ) -> Result<Option<BigInt>, ScannerError> {
+        number_format::next_number_until(self, boundary)
+    }
+
+    /// Read the next token separated by delimiters and parse it to a `BigUint` value without losing precision. If there is nothing to read, it will return `Ok(None)`.
+    #[cfg(feature = "num-bigint")]
+    #[inline]
+    fn next_biguint(&mut self) -> Result<Option<BigUint>, ScannerError> {
+        number_format::next_number(self)
+    }
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `BigUint` value without losing precision. If there is nothing to read, it will return `Ok(None)`.
+    #[cfg(feature = "num-bigint")]
+    #[inline]
+    fn next_biguint_until<S: AsRef<str>>(
+        &mut self,
+        boundary: S,
+    ) -> Result<Option<BigUint>, ScannerError> {
+        number_format::next_number_until(self, boundary)
+    }
+
+    /// Read the next token separated by delimiters and parse it to a `Decimal` value without losing precision. If there is nothing to read, it will return `Ok(None)`.
+    #[cfg(feature = "rust_decimal")]
+    #[inline]
+    fn next_decimal(&mut self) -> Result<Option<Decimal>, ScannerError> {
+        number_format::next_number(self)
+    }
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `Decimal` value without losing precision. If there is nothing to read, it will return `Ok(None)`.
+    #[cfg(feature = "rust_decimal")]
+    #[inline]
+    fn next_decimal_until<S: AsRef<str>>(
+        &mut self,
+        boundary: S,
+    ) -> Result<Option<Decimal>, ScannerError> {
+        number_format::next_number_until(self, boundary)
+    }
+
+    /// Read the next char without consuming it. If there is nothing to read, it will return `Ok(None)`.
+    fn peek_char(&mut self) -> Result<Option<char>, ScannerError>;
+
+    /// Read the next token separated by delimiters without consuming it. If there is nothing to read, it will return `Ok(None)`.
+    fn peek_token(&mut self) -> Result<Option<Self::Token>, ScannerError>;
+
+    /// Read the next line without consuming it. If there is nothing to read, it will return `Ok(None)`.
+    fn peek_line(&mut self) -> Result<Option<Self::Line>, ScannerError>;
+
+    /// Check whether there is a next token separated by delimiters without consuming it.
+    fn has_next(&mut self) -> Result<bool, ScannerError>;
+
+    /// Check whether there is a next line, which may be empty, without consuming it.
+    fn has_next_line(&mut self) -> Result<bool, ScannerError>;
+
+    /// Check whether the next token separated by delimiters can be parsed to a value of any type which implements `FromStr`, without consuming it.
+    fn has_next_parse<T: FromStr>(&mut self) -> Result<bool, ScannerError>;
+
+    /// Check whether the next token separated by delimiters can be parsed to a `u8` value without consuming it.
+    fn has_next_u8(&mut self) -> Result<bool, ScannerError>;
+
+    /// Check whether the next token separated by delimiters can be parsed to a `u16` value without consuming it.
+    fn has_next_u16(&mut self) -> Result<bool, ScannerError>;
+
+    /// Check whether the next token separated by delimiters can be parsed to a `u32` value without consuming it.
+    fn has_next_u32(&mut self) -> Result<bool, ScannerError>;
+
+    /// Check whether the next token separated by delimiters can be parsed to a `u64` value without consuming it.
+    fn has_next_u64(&mut self) -> Result<bool, ScannerError>;
+
+    /// Check whether the next token separated by delimiters can be parsed to a `u128` value without consuming it.
+    fn has_next_u128(&mut self) -> Result<bool, ScannerError>;
+
+    /// Check whether the next token separated by delimiters can be parsed to a `usize` value without consuming it.
+    fn has_next_usize(&mut self) -> Result<bool, ScannerError>;
+
+    /// Check whether the next token separated by delimiters can be parsed to a `i8` value without consuming it.
+    fn has_next_i8(&mut self) -> Result<bool, ScannerError>;
+
+    /// Check whether the next token separated by delimiters can be parsed to a `i16` value without consuming it.
+    fn has_next_i16(&mut self) -> Result<bool, ScannerError>;
+
+    /// Check whether the next token separated by delimiters can be parsed to a `i32` value without consuming it.
+    fn has_next_i32(&mut self) -> Result<bool, ScannerError>;
+
+    /// Check whether the next token separated by delimiters can be parsed to a `i64` value without consuming it.
+    fn has_next_i64(&mut self) -> Result<bool, ScannerError>;
+
+    /// Check whether the next token separated by delimiters can be parsed to a `i128` value without consuming it.
+    fn has_next_i128(&mut self) -> Result<bool, ScannerError>;
+
+    /// Check whether the next token separated by delimiters can be parsed to a `isize` value without consuming it.
+    fn has_next_isize(&mut self) -> Result<bool, ScannerError>;
+
+    /// Check whether the next token separated by delimiters can be parsed to a `f32` value without consuming it.
+    fn has_next_f32(&mut self) -> Result<bool, ScannerError>;
+
+    /// Check whether the next token separated by delimiters can be parsed to a `f64` value without consuming it.
+    fn has_next_f64(&mut self) -> Result<bool, ScannerError>;
+
+    /// Get an iterator over the next tokens separated by delimiters.
+    #[inline]
+    fn tokens(&mut self) -> Tokens<'_, Self>
+    where
+        Self: Sized, {
+        Tokens::new(self)
+    }
+
+    /// Get an iterator over the next tokens or quoted strings read by `next_quoted`.
+    #[inline]
+    fn quoted_tokens(&mut self) -> QuotedTokens<'_, Self>
+    where
+        Self: Sized, {
+        QuotedTokens::new(self)
+    }
+
+    /// Get an iterator over the next lines.
+    #[inline]
+    fn lines(&mut self) -> Lines<'_, Self>
+    where
+        Self: Sized, {
+        Lines::new(self)
+    }
+
+    /// Get an iterator over the next chars.
+    #[inline]
+    fn chars(&mut self) -> Chars<'_, Self>
+    where
+        Self: Sized, {
+        Chars::new(self)
+    }
+
+    /// Get an iterator which parses the next tokens separated by delimiters to values of any type which implements `FromStr`.
+    #[inline]
+    fn parse_iter<T: FromStr>(&mut self) -> ParseIter<'_, Self, T>
+    where
+        Self: Sized,
+        <T as FromStr>::Err: Error + Send + Sync + 'static, {
+        ParseIter::new(self)
+    }
+}
+
+/// Implement most methods of `Scan`, such as the delimiter, position, peeking and parsing methods, by delegating them to the inherent methods of the same names.
+macro_rules! impl_scan_delegate {
+    () => {
+        #[inline]
+        fn set_delimiter<D: Into<$crate::Delimiter>>(&mut self, delimiter: D) {
+            Self::set_delimiter(self, delimiter)
+        }
+
+        #[inline]
+        fn delimiter(&self) -> &$crate::Delimiter {
+            Self::delimiter(self)
+        }
+
+        #[inline]
+        fn set_number_format(&mut self, number_format: $crate::NumberFormat) {
+            Self::set_number_format(self, number_format)
+        }
+
+        #[inline]
+        fn number_format(&self) -> &$crate::NumberFormat {
+            Self::number_format(self)
+        }
+
+        #[inline]
+        fn set_bool_vocabulary(&mut self, bool_vocabulary: $crate::BoolVocabulary) {
+            Self::set_bool_vocabulary(self, bool_vocabulary)
+        }
+
+        #[inline]
+        fn bool_vocabulary(&self) -> &$crate::BoolVocabulary {
+            Self::bool_vocabulary(self)
+        }
+
+        #[inline]
+        fn position(&self) -> $crate::Position {
+            Self::position(self)
+        }
+
+        #[inline]
+        fn next_parse<T: std::str::FromStr>(&mut self) -> Result<Option<T>, ScannerError>
+        where
+            <T as std::str::FromStr>::Err: std::error::Error + Send + Sync + 'static, {
+            Self::next_parse(self)
+        }
+
+        #[inline]
+        fn next_parse_until<T: std::str::FromStr, S: AsRef<str>>(
+            &mut self,
+            boundary: S,
+        ) -> Result<Option<T>, ScannerError>
+        where
+            <T as std::str::FromStr>::Err: std::error::Error + Send + Sync + 'static, {
+            Self::next_parse_until(self, boundary.as_ref())
+        }
+
+        #[inline]
+        fn next_quoted(&mut self) -> Result<Option<String>, ScannerError> {
+            Self::next_quoted(self)
+        }
+
+        #[inline]
+        fn next_bool(&mut self) -> Result<Option<bool>, ScannerError> {
+            Self::next_bool(self)
+        }
+
+        #[inline]
+        fn next_bool_until<S: AsRef<str>>(
+            &mut self,
+            boundary: S,
+        ) -> Result<Option<bool>, ScannerError> {
+            Self::next_bool_until(self, boundary.as_ref())
+        }
+
+        #[cfg(feature = "num-bigint")]
+        #[inline]
+        fn next_bigint(&mut self) -> Result<Option<num_bigint::BigInt>, ScannerError> {
+            Self::next_bigint(self)
+        }
+
+        #[cfg(feature = "num-bigint")]
+        #[inline]
+        fn next_bigint_until<S: AsRef<str>>(
+            &mut self,
+            boundary: S,
+        ) -> Result<Option<num_bigint::BigInt>, ScannerError> {
+            Self::next_bigint_until(self, boundary.as_ref())
+        }
+
+        #[cfg(feature = "num-bigint")]
+        #[inline]
+        fn next_biguint(&mut self) -> Result<Option<num_bigint::BigUint>, ScannerError> {
+            Self::next_biguint(self)
+        }
+
+        #[cfg(feature = "num-bigint")]
+        #[inline]
+        fn next_biguint_until<S: AsRef<str>>(
+            &mut self,
+            boundary: S,
+        ) -> Result<Option<num_bigint::BigUint>, ScannerError> {
+            Self::next_biguint_until(self, boundary.as_ref())
+        }
+
+        #[cfg(feature = "rust_decimal")]
+        #[inline]
+        fn next_decimal(&mut self) -> Result<Option<rust_decimal::Decimal>, ScannerError> {
+            Self::next_decimal(self)
+        }
+
+        #[cfg(feature = "rust_decimal")]
+        #[inline]
+        fn next_decimal_until<S: AsRef<str>>(
+            &mut self,
+            boundary: S,
+        ) -> Result<Option<rust_decimal::Decimal>, ScannerError> {
+            Self::next_decimal_until(self, boundary.as_ref())
+        }
+
+        #[inline]
+        fn peek_char(&mut self) -> Result<Option<char>, ScannerError> {
+            Self::peek_char(self)
+        }
+
+        #[inline]
+        fn peek_token(&mut self) -> Result<Option<Self::Token>, ScannerError> {
+            Self::peek_token(self)
+        }
+
+        #[inline]
+        fn peek_line(&mut self) -> Result<Option<Self::Line>, ScannerError> {
+            Self::peek_line(self)
+        }
+
+        #[inline]
+        fn has_next(&mut self) -> Result<bool, ScannerError> {
+            Self::has_next(self)
+        }
+
+        #[inline]
+        fn has_next_line(&mut self) -> Result<bool, ScannerError> {
+            Self::has_next_line(self)
+        }
+
+        #[inline]
+        fn has_next_parse<T: std::str::FromStr>(&mut self) -> Result<bool, ScannerError> {
+            Self::has_next_parse::<T>(self)
+        }
+
+        impl_scan_delegate!(
+            next_u8, next_u8_until, has_next_u8 => u8,
+            next_u16, next_u16_until, has_next_u16 => u16,
+            next_u32, next_u32_until, has_next_u32 => u32,
+            next_u64, next_u64_until, has_next_u64 => u64,
+            next_u128, next_u128_until, has_next_u128 => u128,
+            next_usize, next_usize_until, has_next_usize => usize,
+            next_i8, next_i8_until, has_next_i8 => i8,
+            next_i16, next_i16_until, has_next_i16 => i16,
+            next_i32, next_i32_until, has_next_i32 => i32,
+            next_i64, next_i64_until, has_next_i64 => i64,
+            next_i128, next_i128_until, has_next_i128 => i128,
+            next_isize, next_isize_until, has_next_isize => isize,
+            next_f32, next_f32_until, has_next_f32 => f32,
+            next_f64, next_f64_until, has_next_f64 => f64,
+        );
+
+        impl_scan_delegate!(
+            radix:
+            next_u8_radix, next_u8_radix_until => u8,
+            next_u16_radix, next_u16_radix_until => u16,
+            next_u32_radix, next_u32_radix_until => u32,
+            next_u64_radix, next_u64_radix_until => u64,
+            next_u128_radix, next_u128_radix_until => u128,
+            next_usize_radix, next_usize_radix_until => usize,
+            next_i8_radix, next_i8_radix_until => i8,
+            next_i16_radix, next_i16_radix_until => i16,
+            next_i32_radix, next_i32_radix_until => i32,
+            next_i64_radix, next_i64_radix_until => i64,
+            next_i128_radix, next_i128_radix_until => i128,
+            next_isize_radix, next_isize_radix_until => isize,
+        );
+    };
+    (radix: $($next_radix:ident, $next_radix_until:ident => $t:ty),* $(,)*) => {
+        $(
+            #[inline]
+            fn $next_radix(&mut self, radix: u32) -> Result<Option<$t>, ScannerError> {
+                Self::$next_radix(self, radix)
+            }
+
+            #[inline]
+            fn $next_radix_until<S: AsRef<str>>(
+                &mut self,
+                boundary: S,
+                radix: u32,
+            ) -> Result<Option<$t>, ScannerError> {
+                Self::$next_radix_until(self, boundary.as_ref(), radix)
+            }
+        )*
+    };
+    ($($next:ident, $next_until:ident, $has_next:ident => $t:ty),* $(,)*) => {
+        $(
+            #[inline]
+            fn $next(&mut self) -> Result<Option<$t>, ScannerError> {
+                Self::$next(self)
+            }
+
+            #[inline]
+            fn $next_until<S: AsRef<str>>(
+                &mut self,
+                boundary: S,
+            ) -> Result<Option<$t>, ScannerError> {
+                Self::$next_until(self, boundary.as_ref())
+            }
+
+            #[inline]
+            fn $has_next(&mut self) -> Result<bool, ScannerError> {
+                Self::$has_next(self)
+            }
+        )*
+    };
+}
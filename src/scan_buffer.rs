@@ -0,0 +1,522 @@
+use std::{char::REPLACEMENT_CHARACTER, io, str::from_utf8};
+
+use utf8_width::*;
+
+use crate::{
+    position::{line_break_length, PositionTracker},
+    scanner_error::decode_utf8,
+    BoolVocabulary, Delimiter, NumberFormat, Position, ScannerError,
+};
+
+/// What an unfinished call of `ScanBuffer` was searching for.
+#[derive(Debug, PartialEq)]
+enum Search {
+    Line,
+    Token,
+    Until(Vec<u8>),
+}
+
+/// The I/O-free part of the async scanners. Every method either finishes its job with the data in the buffer, or returns `Ok(None)` without consuming anything that matters so that it can be called again after more data is appended by `prepare_read` and `finish_read`.
+#[derive(Debug)]
+pub(crate) struct ScanBuffer {
+    buf:                        Vec<u8>,
+    buf_offset:                 usize,
+    eof:                        bool,
+    passing_byte:               Option<u8>,
+    delimiter:                  Delimiter,
+    pub(crate) number_format:   NumberFormat,
+    pub(crate) bool_vocabulary: BoolVocabulary,
+    tracker:                    PositionTracker,
+    pub(crate) strict_utf8:     bool,
+    ascii:                      bool,
+    read_size:                  usize,
+    /// What the last unfinished call was searching for, and where the same search can resume after more data is read, so that a long line or token is not searched again from the start for every read.
+    searched:                   Option<(Search, usize)>,
+    /// The space which the reader reads data into. The data is only appended to `buf` after the read is done, so that nothing is left in `buf` if the future of the read is dropped.
+    read_buf:                   Vec<u8>,
+}
+
+impl ScanBuffer {
+    #[inline]
+    pub(crate) fn new(read_size: usize, ascii: bool) -> ScanBuffer {
+        ScanBuffer {
+            buf: Vec::new(),
+            buf_offset: 0,
+            eof: false,
+            passing_byte: None,
+            delimiter: Delimiter::Whitespaces,
+            number_format: NumberFormat::default(),
+            bool_vocabulary: BoolVocabulary::default(),
+            tracker: PositionTracker::default(),
+            strict_utf8: false,
+            ascii,
+            read_size: read_size.max(4),
+            searched: None,
+            read_buf: Vec::new(),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn position(&self) -> Position {
+        self.tracker.position()
+    }
+
+    #[inline]
+    pub(crate) fn delimiter(&self) -> &Delimiter {
+        &self.delimiter
+    }
+
+    #[inline]
+    pub(crate) fn set_delimiter(&mut self, delimiter: Delimiter) {
+        self.delimiter = delimiter;
+        self.searched = None;
+    }
+
+    #[inline]
+    fn data(&self) -> &[u8] {
+        &self.buf[self.buf_offset..]
+    }
+
+    fn consume(&mut self, length: usize) {
+        let start = self.buf_offset;
+
+        self.tracker.advance(&self.buf[start..(start + length)], self.ascii);
+
+        self.buf_offset += length;
+        self.searched = None;
+
+        if self.buf_offset == self.buf.len() {
+            self.buf.clear();
+            self.buf_offset = 0;
+        }
+    }
+
+    /// Get the space to read data into, which is `read_size` bytes long.
+    pub(crate) fn prepare_read(&mut self) -> &mut [u8] {
+        self.read_buf.resize(self.read_size, 0);
+
+        &mut self.read_buf
+    }
+
+    /// Keep the data which has been read into the space from `prepare_read`.
+    pub(crate) fn finish_read(&mut self, result: &io::Result<usize>) {
+        match result {
+            Ok(0) => self.eof = true,
+            Ok(c) => {
+                self.buf.drain(..self.buf_offset);
+                self.buf_offset = 0;
+
+                self.buf.extend_from_slice(&self.read_buf[..*c]);
+            },
+            Err(_) => (),
+        }
+    }
+
+    /// Check whether there is something to read. It will return `None` if more data is needed to tell.
+    pub(crate) fn ready(&mut self) -> Option<bool> {
+        if let Some(b) = self.passing_byte {
+            match self.data().first() {
+                Some(&e) => {
+                    self.passing_byte = None;
+
+                    if e == b {
+                        self.consume(1);
+                    }
+                },
+                None if self.eof => self.passing_byte = None,
+                None => return None,
+            }
+        }
+
+        if self.data().is_empty() {
+            if self.eof {
+                Some(false)
+            } else {
+                None
+            }
+        } else {
+            Some(true)
+        }
+    }
+
+    /// Get the length of the delimiter at the index `i` of the data. It will return `None` if more data is needed to tell.
+    fn delimiter_length_at(&self, i: usize) -> Option<usize> {
+        let data = &self.data()[i..];
+
+        let required_length = self.delimiter.required_length(data[0], self.ascii);
+
+        if required_length == 0 {
+            Some(0)
+        } else if data.len() < required_length && !self.eof {
+            None
+        } else {
+            Some(self.delimiter.match_length(data, self.ascii))
+        }
+    }
+
+    /// Skip delimiters. It will return `false` if more data is needed.
+    pub(crate) fn skip_delimiters(&mut self) -> bool {
+        loop {
+            match self.ready() {
+                None => return false,
+                Some(false) => return true,
+                Some(true) => (),
+            }
+
+            match self.delimiter_length_at(0) {
+                None => return false,
+                Some(0) => return true,
+                Some(length) => self.consume(length),
+            }
+        }
+    }
+
+    /// Create an `InvalidUtf8` error with the first `length` bytes of the data.
+    #[inline]
+    fn invalid_utf8(&self, length: usize) -> ScannerError {
+        ScannerError::InvalidUtf8 {
+            offset: self.tracker.position().offset,
+            bytes:  self.data()[..length].to_vec(),
+        }
+    }
+
+    /// Decode the first `length` bytes of the data to a string and consume them, plus `skip` bytes after them. Each non-ASCII byte is replaced with `REPLACEMENT_CHARACTER` if `bytewise` is `true`. In the strict UTF-8 mode, only the valid bytes before the invalid ones are consumed if there are any.
+    fn take_str(
+        &mut self,
+        length: usize,
+        skip: usize,
+        bytewise: bool,
+    ) -> Result<String, ScannerError> {
+        let data = &self.data()[..length];
+
+        let s = if bytewise {
+            data.iter().map(|&e| if e >= 128 { REPLACEMENT_CHARACTER } else { e as char }).collect()
+        } else {
+            let offset = self.tracker.position().offset;
+
+            match decode_utf8(data, offset, self.strict_utf8) {
+                Ok(s) => s.into_owned(),
+                Err(err) => {
+                    if let ScannerError::InvalidUtf8 {
+                        offset: invalid_offset, ..
+                    } = err
+                    {
+                        self.consume(invalid_offset - offset);
+                    }
+
+                    return Err(err);
+                },
+            }
+        };
+
+        self.consume(length + skip);
+
+        Ok(s)
+    }
+
+    /// Copy the first `length` bytes of the data and consume them, plus `skip` bytes after them.
+    fn take_raw(&mut self, length: usize, skip: usize) -> Vec<u8> {
+        let data = self.data()[..length].to_vec();
+
+        self.consume(length + skip);
+
+        data
+    }
+
+    pub(crate) fn next_char(&mut self) -> Result<Option<Option<char>>, ScannerError> {
+        match self.ready() {
+            None => return Ok(None),
+            Some(false) => return Ok(Some(None)),
+            Some(true) => (),
+        }
+
+        let data = self.data();
+
+        let e = data[0];
+
+        if self.ascii || e < 128 {
+            self.consume(1);
+
+            return Ok(Some(Some(if e >= 128 { REPLACEMENT_CHARACTER } else { e as char })));
+        }
+
+        let width = get_width(e);
+
+        if width == 0 {
+            if self.strict_utf8 {
+                return Err(self.invalid_utf8(1));
+            }
+
+            self.consume(1);
+
+            return Ok(Some(Some(REPLACEMENT_CHARACTER)));
+        }
+
+        if data.len() < width && !self.eof {
+            return Ok(None);
+        }
+
+        let char_bytes = &data[..width.min(data.len())];
+
+        let c = match from_utf8(char_bytes) {
+            Ok(s) => s.chars().next(),
+            Err(err) => {
+                if self.strict_utf8 {
+                    return Err(self.invalid_utf8(err.error_len().unwrap_or(char_bytes.len())));
+                }
+
+                None
+            },
+        };
+
+        match c {
+            Some(c) => {
+                self.consume(width);
+
+                Ok(Some(Some(c)))
+            },
+            None => {
+                self.consume(1);
+
+                Ok(Some(Some(REPLACEMENT_CHARACTER)))
+            },
+        }
+    }
+
+    /// Find the next line. It returns the length of the line and the length of its line break, or `None` if more data is needed.
+    fn find_line(&mut self) -> Option<Option<(usize, usize)>> {
+        match self.ready() {
+            None => return None,
+            Some(false) => return Some(None),
+            Some(true) => (),
+        }
+
+        let start = match self.searched {
+            Some((Search::Line, start)) => start,
+            _ => 0,
+        };
+
+        let data = self.data();
+
+        match data[start..].iter().position(|&e| e == b'\n' || e == b'\r').map(|i| start + i) {
+            Some(i) => {
+                let e = data[i];
+
+                if i == 0 && self.tracker.completes_line_break(e) {
+                    // the second byte of the line break consumed right before is not paired again
+                    Some(Some((0, 1)))
+                } else if i + 1 == data.len() && !self.eof {
+                    // do not wait for the other byte of `\r\n` or `\n\r`, which will be skipped when it comes
+                    self.passing_byte = Some(if e == b'\n' { b'\r' } else { b'\n' });
+
+                    Some(Some((i, 1)))
+                } else {
+                    Some(Some((i, line_break_length(&data[i..]))))
+                }
+            },
+            None if self.eof => Some(Some((data.len(), 0))),
+            None => {
+                self.searched = Some((Search::Line, data.len()));
+
+                None
+            },
+        }
+    }
+
+    pub(crate) fn next_line(&mut self) -> Result<Option<Option<String>>, ScannerError> {
+        match self.find_line() {
+            Some(Some((length, line_break_length))) => {
+                // the other byte of the line break should not be skipped if the line is not consumed because of an error
+                let passing_byte = self.passing_byte.take();
+
+                let line = self.take_str(length, line_break_length, self.ascii)?;
+
+                self.passing_byte = passing_byte;
+
+                Ok(Some(Some(line)))
+            },
+            Some(None) => Ok(Some(None)),
+            None => Ok(None),
+        }
+    }
+
+    pub(crate) fn next_line_raw(&mut self) -> Option<Option<Vec<u8>>> {
+        self.find_line().map(|line| line.map(|(length, skip)| self.take_raw(length, skip)))
+    }
+
+    /// Find the next token after skipping delimiters. It returns the length of the token, or `None` if more data is needed.
+    fn find_token(&mut self) -> Option<Option<usize>> {
+        if !self.skip_delimiters() {
+            return None;
+        }
+
+        let data = self.data();
+
+        if data.is_empty() {
+            return Some(None);
+        }
+
+        let mut end = match self.searched {
+            Some((Search::Token, end)) => end,
+            _ => 0,
+        };
+
+        loop {
+            // a multi-byte char is not split
+            if end >= data.len() {
+                if self.eof {
+                    return Some(Some(data.len()));
+                }
+
+                break;
+            }
+
+            match self.delimiter_length_at(end) {
+                None => break,
+                Some(0) => {
+                    end += if self.ascii { 1 } else { get_width(data[end]).max(1) };
+                },
+                Some(_) => return Some(Some(end)),
+            }
+        }
+
+        self.searched = Some((Search::Token, end));
+
+        None
+    }
+
+    pub(crate) fn next(&mut self) -> Result<Option<Option<String>>, ScannerError> {
+        match self.find_token() {
+            Some(Some(length)) => Ok(Some(Some(self.take_str(length, 0, self.ascii)?))),
+            Some(None) => Ok(Some(None)),
+            None => Ok(None),
+        }
+    }
+
+    pub(crate) fn next_raw(&mut self) -> Option<Option<Vec<u8>>> {
+        self.find_token().map(|token| token.map(|length| self.take_raw(length, 0)))
+    }
+
+    pub(crate) fn next_bytes(&mut self, max_number_of_bytes: usize) -> Option<Option<Vec<u8>>> {
+        match self.ready() {
+            None => None,
+            Some(false) => Some(None),
+            Some(true) => {
+                let length = self.data().len();
+
+                if length < max_number_of_bytes && !self.eof {
+                    None
+                } else {
+                    Some(Some(self.take_raw(length.min(max_number_of_bytes), 0)))
+                }
+            },
+        }
+    }
+
+    /// Find the text before a boundary. It returns the length of the text and the length of the boundary (`0` if it is not found before the end), or `None` if more data is needed.
+    fn find_until(&mut self, boundary: &[u8]) -> Option<Option<(usize, usize)>> {
+        match self.ready() {
+            None => return None,
+            Some(false) => return Some(None),
+            Some(true) => (),
+        }
+
+        let data = self.data();
+
+        if boundary.is_empty() {
+            return Some(Some((0, 0)));
+        }
+
+        let start = match &self.searched {
+            Some((Search::Until(searched_boundary), start)) if searched_boundary == boundary => {
+                *start
+            },
+            _ => 0,
+        };
+
+        match data[start..]
+            .windows(boundary.len())
+            .position(|window| window == boundary)
+            .map(|i| start + i)
+        {
+            Some(i) => Some(Some((i, boundary.len()))),
+            None if self.eof => Some(Some((data.len(), 0))),
+            None => {
+                // the boundary may begin in the last bytes
+                let start = (data.len() + 1).saturating_sub(boundary.len()).max(start);
+
+                self.searched = Some((Search::Until(boundary.to_vec()), start));
+
+                None
+            },
+        }
+    }
+
+    pub(crate) fn next_until(
+        &mut self,
+        boundary: &[u8],
+    ) -> Result<Option<Option<String>>, ScannerError> {
+        match self.find_until(boundary) {
+            Some(Some((length, boundary_length))) => {
+                // like `ScannerAscii`, the text before a boundary is decoded as UTF-8
+                Ok(Some(Some(self.take_str(length, boundary_length, false)?)))
+            },
+            Some(None) => Ok(Some(None)),
+            None => Ok(None),
+        }
+    }
+
+    pub(crate) fn next_until_raw(&mut self, boundary: &[u8]) -> Option<Option<Vec<u8>>> {
+        self.find_until(boundary).map(|text| text.map(|(length, skip)| self.take_raw(length, skip)))
+    }
+
+    /// Parse the first `length` bytes of the data with `parse`, which gets the decoded token and where it began, and consume them, plus `skip` bytes after them.
+    fn parse<T>(
+        &mut self,
+        length: usize,
+        skip: usize,
+        parse: impl FnOnce(&ScanBuffer, &str, Position) -> Result<T, ScannerError>,
+    ) -> Result<T, ScannerError> {
+        let position = self.tracker.position();
+
+        let result = match decode_utf8(
+            &self.data()[..length],
+            position.offset,
+            self.strict_utf8 && !self.ascii,
+        ) {
+            Ok(token) => parse(self, &token, position),
+            Err(err) => Err(err),
+        };
+
+        self.consume(length + skip);
+
+        result
+    }
+
+    /// Find the next token and parse it with `parse`, which is given the same arguments as in the `parse` method.
+    pub(crate) fn next_parse<T>(
+        &mut self,
+        parse: impl FnOnce(&ScanBuffer, &str, Position) -> Result<T, ScannerError>,
+    ) -> Result<Option<Option<T>>, ScannerError> {
+        match self.find_token() {
+            Some(Some(length)) => Ok(Some(Some(self.parse(length, 0, parse)?))),
+            Some(None) => Ok(Some(None)),
+            None => Ok(None),
+        }
+    }
+
+    /// Find the text before a boundary and parse it with `parse`, which is given the same arguments as in the `parse` method.
+    pub(crate) fn next_parse_until<T>(
+        &mut self,
+        boundary: &[u8],
+        parse: impl FnOnce(&ScanBuffer, &str, Position) -> Result<T, ScannerError>,
+    ) -> Result<Option<Option<T>>, ScannerError> {
+        match self.find_until(boundary) {
+            Some(Some((length, boundary_length))) => {
+                Ok(Some(Some(self.parse(length, boundary_length, parse)?)))
+            },
+            Some(None) => Ok(Some(None)),
+            None => Ok(None),
+        }
+    }
+}
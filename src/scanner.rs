@@ -1,10 +1,12 @@
 use std::{
     char::REPLACEMENT_CHARACTER,
     cmp::Ordering,
+    error::Error,
     fs::File,
-    intrinsics::copy,
     io::{ErrorKind, Read},
+    mem,
     path::Path,
+    ptr::copy,
     str::{from_utf8, from_utf8_unchecked, FromStr},
 };
 
@@ -12,20 +14,49 @@ use generic_array::{
     typenum::{IsGreaterOrEqual, True, U256, U4},
     ArrayLength, GenericArray,
 };
+#[cfg(feature = "num-bigint")]
+use num_bigint::{BigInt, BigUint};
+#[cfg(feature = "regex")]
+use regex::bytes::Regex;
+#[cfg(feature = "rust_decimal")]
+use rust_decimal::Decimal;
 use utf8_width::*;
 
-use crate::{whitespaces::*, ScannerError};
+use crate::{
+    bool_vocabulary::parse_bool,
+    buffer_size::heap_buffer,
+    integer::{check_radix, parse_integer, Integer},
+    number_format::parse_number,
+    position::{line_break_length, PositionTracker},
+    quoted::next_quoted,
+    replay::{History, Mark, Replay},
+    scanner_error::{decode_utf8, parse_token},
+    BoolVocabulary, BufferSize, Chars, Delimiter, HeapBuffer, Lines, NumberFormat, ParseIter,
+    Position, QuotedTokens, Scan, ScannerError, Tokens,
+};
 
 /// A simple text scanner which can parse primitive types and strings using UTF-8.
 #[derive(Educe)]
 #[educe(Debug)]
-pub struct Scanner<R: Read, N: ArrayLength + IsGreaterOrEqual<U4, Output = True> = U256> {
+pub struct Scanner<R: Read, N: BufferSize = U256> {
+    #[educe(Debug(ignore))]
+    reader:          Replay<R>,
+    buf:             N::Buffer,
+    buf_length:      usize,
+    buf_offset:      usize,
+    passing_byte:    Option<u8>,
+    delimiter:       Delimiter,
+    number_format:   NumberFormat,
+    bool_vocabulary: BoolVocabulary,
+    tracker:         PositionTracker,
+    #[educe(Debug(ignore))]
+    mark:            Option<Mark>,
+    #[educe(Debug(ignore))]
+    history:         History,
+    strict_utf8:     bool,
+    /// Holds the data borrowed by the `*_ref` methods when it cannot stay in the buffer.
     #[educe(Debug(ignore))]
-    reader:       R,
-    buf:          GenericArray<u8, N>,
-    buf_length:   usize,
-    buf_offset:   usize,
-    passing_byte: Option<u8>,
+    spill:           Vec<u8>,
 }
 
 impl<R: Read> Scanner<R> {
@@ -56,607 +87,940 @@ impl<R: Read, N: ArrayLength + IsGreaterOrEqual<U4, Output = True>> Scanner<R, N
     /// ```
     #[inline]
     pub fn new2(reader: R) -> Scanner<R, N> {
+        Self::from_buffer(reader, GenericArray::default())
+    }
+}
+
+impl<R: Read> Scanner<R, HeapBuffer> {
+    /// Create a scanner from a reader and set the buffer size at runtime. The buffer is allocated on the heap, and its size is at least 4 bytes.
+    ///
+    /// ```rust
+    /// use std::io;
+    ///
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::with_capacity(io::stdin(), 64);
+    /// ```
+    #[inline]
+    pub fn with_capacity(reader: R, capacity: usize) -> Scanner<R, HeapBuffer> {
+        Self::from_buffer(reader, heap_buffer(capacity))
+    }
+}
+
+impl<R: Read, N: BufferSize> Scanner<R, N> {
+    #[inline]
+    fn from_buffer(reader: R, buf: N::Buffer) -> Scanner<R, N> {
         Scanner {
-            reader,
-            buf: GenericArray::default(),
+            reader: Replay::new(reader),
+            buf,
             buf_length: 0,
             buf_offset: 0,
             passing_byte: None,
+            delimiter: Delimiter::Whitespaces,
+            number_format: NumberFormat::default(),
+            bool_vocabulary: BoolVocabulary::default(),
+            tracker: PositionTracker::default(),
+            mark: None,
+            history: History::default(),
+            strict_utf8: false,
+            spill: Vec::new(),
         }
     }
 }
 
-impl Scanner<File> {
-    /// Create a scanner to read data from a file by its path.
+impl<R: Read, N: BufferSize> Scanner<R, N> {
+    /// Set the delimiter used by `next`, `skip_whitespaces` and the methods which parse the next token to a primitive type.
     ///
     /// ```rust
     /// use scanner_rust::Scanner;
     ///
-    /// let mut sc = Scanner::scan_path("Cargo.toml").unwrap();
+    /// let mut sc = Scanner::new("1, 2;3".as_bytes());
+    ///
+    /// sc.set_delimiter([',', ';', ' ']);
+    ///
+    /// assert_eq!(Some(1), sc.next_u8().unwrap());
+    /// assert_eq!(Some(2), sc.next_u8().unwrap());
+    /// assert_eq!(Some(3), sc.next_u8().unwrap());
+    /// assert_eq!(None, sc.next_u8().unwrap());
     /// ```
     #[inline]
-    pub fn scan_path<P: AsRef<Path>>(path: P) -> Result<Scanner<File>, ScannerError> {
-        Self::scan_path2(path)
+    pub fn set_delimiter<D: Into<Delimiter>>(&mut self, delimiter: D) {
+        self.delimiter = delimiter.into();
     }
-}
 
-impl<N: ArrayLength + IsGreaterOrEqual<U4, Output = True>> Scanner<File, N> {
-    /// Create a scanner to read data from a file by its path and set the buffer size via generics.
+    /// Get the delimiter used by `next`, `skip_whitespaces` and the methods which parse the next token to a primitive type.
     ///
     /// ```rust
-    /// use scanner_rust::{generic_array::typenum::U1024, Scanner};
+    /// use scanner_rust::{Delimiter, Scanner};
     ///
-    /// let mut sc: Scanner<_, U1024> = Scanner::scan_path2("Cargo.toml").unwrap();
+    /// let sc = Scanner::new("1 2 3".as_bytes());
+    ///
+    /// assert_eq!(&Delimiter::Whitespaces, sc.delimiter());
     /// ```
     #[inline]
-    pub fn scan_path2<P: AsRef<Path>>(path: P) -> Result<Scanner<File, N>, ScannerError> {
-        let reader = File::open(path)?;
-
-        Ok(Scanner::new2(reader))
+    pub fn delimiter(&self) -> &Delimiter {
+        &self.delimiter
     }
-}
 
-impl<R: Read, N: ArrayLength + IsGreaterOrEqual<U4, Output = True>> Scanner<R, N> {
+    /// Set the number format used by the methods which parse the next token to an integer or a floating-point number.
+    ///
+    /// ```rust
+    /// use scanner_rust::{NumberFormat, Scanner};
+    ///
+    /// let mut sc = Scanner::new("1_000 1,5".as_bytes());
+    ///
+    /// sc.set_number_format(NumberFormat {
+    ///     digit_separators: vec!['_'],
+    ///     decimal_point: ',',
+    ///     ..NumberFormat::default()
+    /// });
+    ///
+    /// assert_eq!(Some(1000), sc.next_u16().unwrap());
+    /// assert_eq!(Some(1.5), sc.next_f64().unwrap());
+    /// ```
     #[inline]
-    fn buf_align_to_frond_end(&mut self) {
-        unsafe {
-            copy(self.buf.as_ptr().add(self.buf_offset), self.buf.as_mut_ptr(), self.buf_length);
-        }
+    pub fn set_number_format(&mut self, number_format: NumberFormat) {
+        self.number_format = number_format;
+    }
 
-        self.buf_offset = 0;
+    /// Get the number format used by the methods which parse the next token to an integer or a floating-point number.
+    ///
+    /// ```rust
+    /// use scanner_rust::{NumberFormat, Scanner};
+    ///
+    /// let sc = Scanner::new("1_000 1,5".as_bytes());
+    ///
+    /// assert_eq!(&NumberFormat::default(), sc.number_format());
+    /// ```
+    #[inline]
+    pub fn number_format(&self) -> &NumberFormat {
+        &self.number_format
     }
 
+    /// Set the words read as `true` or `false` by the `next_bool` and `next_bool_until` methods.
+    ///
+    /// ```rust
+    /// use scanner_rust::{BoolVocabulary, Scanner};
+    ///
+    /// let mut sc = Scanner::new("yes N".as_bytes());
+    ///
+    /// sc.set_bool_vocabulary(BoolVocabulary::lenient());
+    ///
+    /// assert_eq!(Some(true), sc.next_bool().unwrap());
+    /// assert_eq!(Some(false), sc.next_bool().unwrap());
+    /// ```
     #[inline]
-    fn buf_left_shift(&mut self, distance: usize) {
-        debug_assert!(self.buf_length >= distance);
+    pub fn set_bool_vocabulary(&mut self, bool_vocabulary: BoolVocabulary) {
+        self.bool_vocabulary = bool_vocabulary;
+    }
 
-        self.buf_offset += distance;
+    /// Get the words read as `true` or `false` by the `next_bool` and `next_bool_until` methods.
+    ///
+    /// ```rust
+    /// use scanner_rust::{BoolVocabulary, Scanner};
+    ///
+    /// let sc = Scanner::new("true".as_bytes());
+    ///
+    /// assert_eq!(&BoolVocabulary::default(), sc.bool_vocabulary());
+    /// ```
+    #[inline]
+    pub fn bool_vocabulary(&self) -> &BoolVocabulary {
+        &self.bool_vocabulary
+    }
 
-        if self.buf_offset >= N::USIZE - 4 {
-            self.buf_align_to_frond_end();
-        }
+    /// Get the position of the data which will be read next.
+    ///
+    /// A line break is counted when it is consumed, so the position is the same as the one of an in-memory scanner reading the same data, no matter how the reader splits it. The only exception is a `\n\r` line break split by the reader. Its `\r` is counted in the offset when the next read gets it, so that reading a line from an interactive input does not block until the next line is entered.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("123 456\r\n789".as_bytes());
+    ///
+    /// sc.next_line().unwrap();
+    /// sc.drop_next().unwrap();
+    ///
+    /// let position = sc.position();
+    ///
+    /// assert_eq!(12, position.offset);
+    /// assert_eq!(2, position.line);
+    /// assert_eq!(4, position.column);
+    /// ```
+    #[inline]
+    pub fn position(&self) -> Position {
+        self.tracker.position()
+    }
 
-        self.buf_length -= distance;
+    /// Set whether invalid UTF-8 data is an `InvalidUtf8` error instead of being replaced with `REPLACEMENT_CHARACTER`. When the error is returned, the scanner stops right before the invalid bytes. It is `false` by default.
+    ///
+    /// ```rust
+    /// use scanner_rust::{Scanner, ScannerError};
+    ///
+    /// let mut sc = Scanner::new(b"12 3\xFF4".as_ref());
+    ///
+    /// sc.set_strict_utf8(true);
+    ///
+    /// assert_eq!(Some("12".into()), sc.next().unwrap());
+    ///
+    /// match sc.next() {
+    ///     Err(ScannerError::InvalidUtf8 {
+    ///         offset,
+    ///         bytes,
+    ///     }) => {
+    ///         assert_eq!(4, offset);
+    ///         assert_eq!(vec![0xFF], bytes);
+    ///     },
+    ///     _ => unreachable!(),
+    /// }
+    /// ```
+    #[inline]
+    pub fn set_strict_utf8(&mut self, strict: bool) {
+        self.strict_utf8 = strict;
     }
 
-    /// Left shift (if necessary) the buffer to remove bytes from the start of the buffer. Typically, you should use this after `peek`ing the buffer.
+    /// Check whether invalid UTF-8 data is an `InvalidUtf8` error instead of being replaced with `REPLACEMENT_CHARACTER`.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let sc = Scanner::new("123".as_bytes());
+    ///
+    /// assert!(!sc.is_strict_utf8());
+    /// ```
     #[inline]
-    #[allow(clippy::missing_safety_doc)]
-    pub unsafe fn remove_heading_bytes_from_buffer(&mut self, number_of_bytes: usize) {
-        self.buf_left_shift(number_of_bytes);
+    pub fn is_strict_utf8(&self) -> bool {
+        self.strict_utf8
     }
+}
 
-    fn passing_read(&mut self) -> Result<bool, ScannerError> {
-        if self.buf_length == 0 {
-            let size = self.reader.read(&mut self.buf[self.buf_offset..])?;
+impl<R: Read, N: BufferSize> Scanner<R, N> {
+    /// Mark the current position, so that `reset` can go back to it later. The bytes consumed after this are kept in memory, up to `limit` bytes, no matter how large the buffer is. Consuming more bytes than that discards the mark. Calling this method again replaces the previous mark.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("123 abc".as_bytes());
+    ///
+    /// sc.mark(16);
+    ///
+    /// assert_eq!(Some(123), sc.next_u8().unwrap());
+    /// assert!(sc.next_u8().is_err());
+    ///
+    /// assert!(sc.reset());
+    ///
+    /// assert_eq!(Some("123".into()), sc.next().unwrap());
+    /// assert_eq!(Some("abc".into()), sc.next().unwrap());
+    /// ```
+    #[inline]
+    pub fn mark(&mut self, limit: usize) {
+        self.mark = Some(Mark::new(limit, self.tracker, self.passing_byte));
+    }
 
-            if size == 0 {
-                return Ok(false);
-            }
+    /// Go back to the position of the last mark, so that the data consumed after it will be read again. The mark stays there, so it can be reset to again. If there is no mark, or the mark has been discarded because more than `limit` bytes have been consumed, it will return `false` and do nothing.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("123 456 789".as_bytes());
+    ///
+    /// assert!(!sc.reset());
+    ///
+    /// sc.mark(4);
+    ///
+    /// assert_eq!(Some(123), sc.next_u16().unwrap());
+    /// assert!(sc.reset());
+    /// assert_eq!(Some(123), sc.next_u16().unwrap());
+    /// assert_eq!(Some(456), sc.next_u16().unwrap());
+    ///
+    /// // more than 4 bytes have been consumed since the mark
+    /// assert!(!sc.reset());
+    /// assert_eq!(Some(789), sc.next_u16().unwrap());
+    /// ```
+    pub fn reset(&mut self) -> bool {
+        let mark = match self.mark.as_mut() {
+            Some(mark) => mark,
+            None => return false,
+        };
 
-            self.buf_length += size;
+        let _ = mark.skip_passing_byte();
 
-            if let Some(passing_byte) = self.passing_byte.take() {
-                if self.buf[self.buf_offset] == passing_byte {
-                    self.buf_left_shift(1);
+        self.reader.push_front(&self.buf[self.buf_offset..(self.buf_offset + self.buf_length)]);
+        self.reader.push_front(&mark.data);
 
-                    return if size == 1 {
-                        let size = self.reader.read(&mut self.buf[self.buf_offset..])?;
+        mark.data.clear();
 
-                        if size == 0 {
-                            Ok(false)
-                        } else {
-                            self.buf_length += size;
+        self.buf_offset = 0;
+        self.buf_length = 0;
+        self.passing_byte = mark.passing_byte;
+        self.tracker = mark.tracker;
 
-                            Ok(true)
-                        }
-                    } else {
-                        Ok(true)
-                    };
-                }
-            }
+        self.history.clear(self.tracker, self.passing_byte);
 
-            Ok(true)
-        } else {
-            Ok(true)
-        }
+        true
     }
 }
 
-impl<R: Read, N: ArrayLength + IsGreaterOrEqual<U4, Output = True>> Scanner<R, N> {
-    /// Read the next char. If the data is not a correct char, it will return a `Ok(Some(REPLACEMENT_CHARACTER))` which is �. If there is nothing to read, it will return `Ok(None)`.
+impl<R: Read, N: BufferSize> Scanner<R, N> {
+    /// Put data back in front of the data which has not been read yet, so that it will be read again by the next call. If the data is exactly what the latest call has just consumed, such as a token returned by `next`, the position moves back to where it began. Otherwise, the position is not moved. The mark is discarded unless the data is exactly what has just been consumed after it.
     ///
     /// ```rust
     /// use scanner_rust::Scanner;
     ///
-    /// let mut sc = Scanner::new("5 c 中文".as_bytes());
+    /// let mut sc = Scanner::new("456 789".as_bytes());
     ///
-    /// assert_eq!(Some('5'), sc.next_char().unwrap());
-    /// assert_eq!(Some(' '), sc.next_char().unwrap());
-    /// assert_eq!(Some('c'), sc.next_char().unwrap());
-    /// assert_eq!(Some(' '), sc.next_char().unwrap());
-    /// assert_eq!(Some('中'), sc.next_char().unwrap());
-    /// assert_eq!(Some('文'), sc.next_char().unwrap());
-    /// assert_eq!(None, sc.next_char().unwrap());
+    /// sc.unread(b"123 ");
+    ///
+    /// assert_eq!(Some(123), sc.next_u16().unwrap());
+    /// assert_eq!(Some(456), sc.next_u16().unwrap());
     /// ```
-    pub fn next_char(&mut self) -> Result<Option<char>, ScannerError> {
-        if !self.passing_read()? {
-            return Ok(None);
-        }
-
-        let e = self.buf[self.buf_offset];
-
-        let width = get_width(e);
+    pub fn unread<D: ?Sized + AsRef<[u8]>>(&mut self, data: &D) {
+        let data = data.as_ref();
 
-        match width {
-            0 => {
-                self.buf_left_shift(1);
+        if data.is_empty() {
+            return;
+        }
 
-                Ok(Some(REPLACEMENT_CHARACTER))
-            },
-            1 => {
-                self.buf_left_shift(1);
+        self.history.keep(&self.buf[..self.buf_offset]);
 
-                Ok(Some(e as char))
+        match self.history.unrecord(data, false) {
+            Some((tracker, passing_byte)) => {
+                // the line break at the end of the data will be paired with the byte after it again
+                self.tracker = tracker;
+                self.passing_byte = passing_byte;
             },
-            _ => {
-                while self.buf_length < width {
-                    match self.reader.read(&mut self.buf[(self.buf_offset + self.buf_length)..]) {
-                        Ok(0) => {
-                            self.buf_left_shift(1);
-
-                            return Ok(Some(REPLACEMENT_CHARACTER));
-                        },
-                        Ok(c) => self.buf_length += c,
-                        Err(ref err) if err.kind() == ErrorKind::Interrupted => (),
-                        Err(err) => return Err(err.into()),
-                    }
+            None => {
+                // the other byte of `\r\n` or `\n\r` may come right after the data
+                if let Some(passing_byte) = self.passing_byte.take() {
+                    self.reader.skip_if_next(passing_byte);
                 }
 
-                let char_str_bytes = &self.buf[self.buf_offset..(self.buf_offset + width)];
-
-                match from_utf8(char_str_bytes) {
-                    Ok(char_str) => {
-                        let c = char_str.chars().next();
+                self.history.clear(self.tracker, None);
+            },
+        }
 
-                        self.buf_left_shift(width);
+        self.reader.push_front(&self.buf[self.buf_offset..(self.buf_offset + self.buf_length)]);
+        self.reader.push_front(data);
 
-                        Ok(c)
-                    },
-                    Err(_) => {
-                        self.buf_left_shift(1);
+        self.buf_offset = 0;
+        self.buf_length = 0;
 
-                        Ok(Some(REPLACEMENT_CHARACTER))
-                    },
-                }
-            },
+        if let Some(mark) = self.mark.as_mut() {
+            if !mark.unrecord(data) {
+                self.mark = None;
+            }
         }
     }
 
-    /// Read the next line but not include the tailing line character (or line chracters like `CrLf`(`\r\n`)). If there is nothing to read, it will return `Ok(None)`.
+    /// Put a char back in front of the data which has not been read yet.
     ///
     /// ```rust
     /// use scanner_rust::Scanner;
     ///
-    /// let mut sc = Scanner::new("123 456\r\n789 \n\n 中文 ".as_bytes());
+    /// let mut sc = Scanner::new("中文".as_bytes());
     ///
-    /// assert_eq!(Some("123 456".into()), sc.next_line().unwrap());
-    /// assert_eq!(Some("789 ".into()), sc.next_line().unwrap());
-    /// assert_eq!(Some("".into()), sc.next_line().unwrap());
-    /// assert_eq!(Some(" 中文 ".into()), sc.next_line().unwrap());
+    /// let c = sc.next_char().unwrap().unwrap();
+    ///
+    /// sc.unread_char(c);
+    ///
+    /// assert_eq!(Some("中文".into()), sc.next().unwrap());
     /// ```
-    pub fn next_line(&mut self) -> Result<Option<String>, ScannerError> {
-        if !self.passing_read()? {
-            return Ok(None);
-        }
-
-        let mut temp = String::new();
-
-        loop {
-            let e = self.buf[self.buf_offset];
-
-            let width = get_width(e);
+    #[inline]
+    pub fn unread_char(&mut self, c: char) {
+        let mut buffer = [0; 4];
 
-            match width {
-                0 => {
-                    self.buf_left_shift(1);
+        self.unread(c.encode_utf8(&mut buffer));
+    }
 
-                    temp.push(REPLACEMENT_CHARACTER);
-                },
-                1 => {
-                    match e {
-                        b'\n' => {
-                            if self.buf_length == 1 {
-                                self.passing_byte = Some(b'\r');
-                                self.buf_left_shift(1);
-                            } else if self.buf[self.buf_offset + 1] == b'\r' {
-                                self.buf_left_shift(2);
-                            } else {
-                                self.buf_left_shift(1);
-                            }
+    /// Put a line back in front of the data which has not been read yet, so the next `next_line` call returns the line. If it is the line which has just been read, its original line break, if it did not end at EOF, is put back with it and the position moves back. Otherwise, `\r\n` is appended to it, which is not paired with the data after it.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("a=1\nb=2\n[section]\nc=3".as_bytes());
+    ///
+    /// let mut pairs = Vec::new();
+    ///
+    /// while let Some(line) = sc.next_line().unwrap() {
+    ///     if line.starts_with('[') {
+    ///         sc.unread_line(line);
+    ///
+    ///         break;
+    ///     }
+    ///
+    ///     pairs.push(line);
+    /// }
+    ///
+    /// assert_eq!(vec!["a=1", "b=2"], pairs);
+    /// assert_eq!(Some("[section]".into()), sc.next_line().unwrap());
+    /// assert_eq!(Some("c=3".into()), sc.next_line().unwrap());
+    /// ```
+    #[inline]
+    pub fn unread_line<S: AsRef<str>>(&mut self, line: S) {
+        let line = line.as_ref().as_bytes();
 
-                            return Ok(Some(temp));
-                        },
-                        b'\r' => {
-                            if self.buf_length == 1 {
-                                self.passing_byte = Some(b'\n');
-                                self.buf_left_shift(1);
-                            } else if self.buf[self.buf_offset + 1] == b'\n' {
-                                self.buf_left_shift(2);
-                            } else {
-                                self.buf_left_shift(1);
-                            }
+        self.history.keep(&self.buf[..self.buf_offset]);
 
-                            return Ok(Some(temp));
-                        },
-                        _ => (),
-                    }
+        let line_break = self.history.line_break_after(line).unwrap_or(b"\r\n");
 
-                    self.buf_left_shift(1);
+        let mut data = Vec::with_capacity(line.len() + line_break.len());
 
-                    temp.push(e as char);
-                },
-                _ => {
-                    while self.buf_length < width {
-                        match self.reader.read(&mut self.buf[(self.buf_offset + self.buf_length)..])
-                        {
-                            Ok(0) => {
-                                temp.push_str(
-                                    String::from_utf8_lossy(
-                                        &self.buf
-                                            [self.buf_offset..(self.buf_offset + self.buf_length)],
-                                    )
-                                    .as_ref(),
-                                );
+        data.extend_from_slice(line);
+        data.extend_from_slice(line_break);
 
-                                self.buf_left_shift(self.buf_length);
+        self.unread(&data);
+    }
+}
 
-                                return Ok(Some(temp));
-                            },
-                            Ok(c) => self.buf_length += c,
-                            Err(ref err) if err.kind() == ErrorKind::Interrupted => (),
-                            Err(err) => return Err(err.into()),
-                        }
-                    }
+impl Scanner<File> {
+    /// Create a scanner to read data from a file by its path.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::scan_path("Cargo.toml").unwrap();
+    /// ```
+    #[inline]
+    pub fn scan_path<P: AsRef<Path>>(path: P) -> Result<Scanner<File>, ScannerError> {
+        Self::scan_path2(path)
+    }
+}
 
-                    let char_str_bytes = &self.buf[self.buf_offset..(self.buf_offset + width)];
+impl<N: ArrayLength + IsGreaterOrEqual<U4, Output = True>> Scanner<File, N> {
+    /// Create a scanner to read data from a file by its path and set the buffer size via generics.
+    ///
+    /// ```rust
+    /// use scanner_rust::{generic_array::typenum::U1024, Scanner};
+    ///
+    /// let mut sc: Scanner<_, U1024> = Scanner::scan_path2("Cargo.toml").unwrap();
+    /// ```
+    #[inline]
+    pub fn scan_path2<P: AsRef<Path>>(path: P) -> Result<Scanner<File, N>, ScannerError> {
+        let reader = File::open(path)?;
 
-                    match from_utf8(char_str_bytes) {
-                        Ok(char_str) => {
-                            temp.push_str(char_str);
+        Ok(Scanner::new2(reader))
+    }
+}
 
-                            self.buf_left_shift(width);
-                        },
-                        Err(_) => {
-                            self.buf_left_shift(1);
+impl Scanner<File, HeapBuffer> {
+    /// Create a scanner to read data from a file by its path and set the buffer size at runtime.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::scan_path_with_capacity("Cargo.toml", 65536).unwrap();
+    /// ```
+    #[inline]
+    pub fn scan_path_with_capacity<P: AsRef<Path>>(
+        path: P,
+        capacity: usize,
+    ) -> Result<Scanner<File, HeapBuffer>, ScannerError> {
+        let reader = File::open(path)?;
 
-                            temp.push(REPLACEMENT_CHARACTER);
-                        },
-                    }
-                },
-            }
+        Ok(Scanner::with_capacity(reader, capacity))
+    }
+}
 
-            if self.buf_length == 0 {
-                let size = self.reader.read(&mut self.buf[self.buf_offset..])?;
+impl<R: Read, N: BufferSize> Scanner<R, N> {
+    #[inline]
+    fn buf_align_to_frond_end(&mut self) {
+        self.history.keep(&self.buf[..self.buf_offset]);
 
-                if size == 0 {
-                    return Ok(Some(temp));
-                }
+        unsafe {
+            copy(self.buf.as_ptr().add(self.buf_offset), self.buf.as_mut_ptr(), self.buf_length);
+        }
 
-                self.buf_length += size;
+        self.buf_offset = 0;
+    }
+
+    /// Update the position with the `distance` consumed bytes starting at the index `start` of the buffer, and keep them for the mark if there is one.
+    #[inline]
+    fn track(&mut self, start: usize, distance: usize) {
+        let data = &self.buf[start..(start + distance)];
+
+        self.tracker.advance(data, false);
+        self.history.record(distance);
+
+        if let Some(mark) = self.mark.as_mut() {
+            if !mark.record(data) {
+                self.mark = None;
             }
         }
     }
 
-    /// Read the next line include the tailing line character (or line chracters like `CrLf`(`\r\n`)) without fully validating UTF-8. If there is nothing to read, it will return `Ok(None)`.
-    ///
-    /// ```rust
-    /// use scanner_rust::Scanner;
-    ///
-    /// let mut sc = Scanner::new("123 456\r\n789 \n\n 中文 ".as_bytes());
-    ///
-    /// assert_eq!(Some("123 456".into()), sc.next_line_raw().unwrap());
-    /// assert_eq!(Some("789 ".into()), sc.next_line_raw().unwrap());
-    /// assert_eq!(Some("".into()), sc.next_line_raw().unwrap());
-    /// assert_eq!(Some(" 中文 ".into()), sc.next_line_raw().unwrap());
-    /// ```
-    pub fn next_line_raw(&mut self) -> Result<Option<Vec<u8>>, ScannerError> {
-        if !self.passing_read()? {
-            return Ok(None);
+    #[inline]
+    fn buf_left_shift(&mut self, distance: usize) {
+        debug_assert!(self.buf_length >= distance);
+
+        self.track(self.buf_offset, distance);
+
+        self.buf_offset += distance;
+        self.buf_length -= distance;
+
+        if self.buf_offset >= self.buf.len() - 4 {
+            self.buf_align_to_frond_end();
         }
+    }
 
-        let mut temp = Vec::new();
+    /// Create an `InvalidUtf8` error with the first `length` bytes of the buffer.
+    #[inline]
+    fn invalid_utf8(&self, length: usize) -> ScannerError {
+        ScannerError::InvalidUtf8 {
+            offset: self.tracker.position().offset,
+            bytes:  self.buf[self.buf_offset..(self.buf_offset + length)].to_vec(),
+        }
+    }
 
-        loop {
-            let e = self.buf[self.buf_offset];
+    /// Decode the first `length` bytes of the buffer and consume them. In the strict UTF-8 mode, only the valid bytes before the invalid ones are consumed.
+    fn consume_str(&mut self, length: usize) -> Result<String, ScannerError> {
+        let offset = self.tracker.position().offset;
 
-            let width = get_width(e);
+        let result = decode_utf8(
+            &self.buf[self.buf_offset..(self.buf_offset + length)],
+            offset,
+            self.strict_utf8,
+        )
+        .map(|s| s.into_owned());
 
-            match width {
-                0 => {
-                    self.buf_left_shift(1);
+        match result {
+            Ok(s) => {
+                self.buf_left_shift(length);
 
-                    temp.push(e);
-                },
-                1 => {
-                    match e {
-                        b'\n' => {
-                            if self.buf_length == 1 {
-                                self.passing_byte = Some(b'\r');
-                                self.buf_left_shift(1);
-                            } else if self.buf[self.buf_offset + 1] == b'\r' {
-                                self.buf_left_shift(2);
-                            } else {
-                                self.buf_left_shift(1);
-                            }
+                Ok(s)
+            },
+            Err(err) => {
+                if let ScannerError::InvalidUtf8 {
+                    offset: invalid_offset, ..
+                } = err
+                {
+                    self.buf_left_shift(invalid_offset - offset);
+                }
 
-                            return Ok(Some(temp));
-                        },
-                        b'\r' => {
-                            if self.buf_length == 1 {
-                                self.passing_byte = Some(b'\n');
-                                self.buf_left_shift(1);
-                            } else if self.buf[self.buf_offset + 1] == b'\n' {
-                                self.buf_left_shift(2);
-                            } else {
-                                self.buf_left_shift(1);
-                            }
+                Err(err)
+            },
+        }
+    }
 
-                            return Ok(Some(temp));
-                        },
-                        _ => (),
-                    }
+    /// Consume the first `length` bytes of the buffer, plus `skip` bytes after them, and borrow the first `length` bytes. If aligning the buffer would overwrite them, they are copied to `spill` first.
+    fn buf_consume_ref(&mut self, length: usize, skip: usize) -> &[u8] {
+        let start = self.buf_offset;
+        let distance = length + skip;
 
-                    self.buf_left_shift(1);
+        self.track(start, distance);
 
-                    temp.push(e);
-                },
-                _ => {
-                    while self.buf_length < width {
-                        match self.reader.read(&mut self.buf[(self.buf_offset + self.buf_length)..])
-                        {
-                            Ok(0) => {
-                                temp.extend_from_slice(
-                                    &self.buf[self.buf_offset..(self.buf_offset + self.buf_length)],
-                                );
+        self.buf_offset += distance;
+        self.buf_length -= distance;
 
-                                self.buf_left_shift(self.buf_length);
+        if self.buf_offset >= self.buf.len() - 4 {
+            if self.buf_length > start {
+                self.spill.clear();
+                self.spill.extend_from_slice(&self.buf[start..(start + length)]);
 
-                                return Ok(Some(temp));
-                            },
-                            Ok(c) => self.buf_length += c,
-                            Err(ref err) if err.kind() == ErrorKind::Interrupted => (),
-                            Err(err) => return Err(err.into()),
-                        }
+                self.buf_align_to_frond_end();
+
+                return &self.spill;
+            }
+
+            self.buf_align_to_frond_end();
+        }
+
+        &self.buf[start..(start + length)]
+    }
+
+    /// Find the next line in the buffer without consuming anything. It returns the length of the line and the length of its line break. If the line is longer than the buffer, it will return `Ok(None)`. The buffer should not be empty.
+    fn find_line(&mut self) -> Result<Option<(usize, usize)>, ScannerError> {
+        let mut i = 0;
+
+        loop {
+            while i < self.buf_length {
+                let e = self.buf[self.buf_offset + i];
+
+                if e == b'\n' || e == b'\r' {
+                    // the second byte of the line break consumed right before is not paired again
+                    if i == 0 && self.tracker.completes_line_break(e) {
+                        return Ok(Some((0, 1)));
                     }
 
-                    let char_str_bytes = &self.buf[self.buf_offset..(self.buf_offset + width)];
+                    // like `buf_consume_line_break`, only a `\r` waits for the other byte
+                    if i + 1 == self.buf_length && (e == b'\n' || !self.read_more()?) {
+                        return Ok(Some((i, 1)));
+                    }
 
-                    temp.extend_from_slice(char_str_bytes);
+                    return Ok(Some((
+                        i,
+                        line_break_length(
+                            &self.buf[(self.buf_offset + i)..(self.buf_offset + self.buf_length)],
+                        ),
+                    )));
+                }
 
-                    self.buf_left_shift(width);
-                },
+                i += 1;
             }
 
-            if self.buf_length == 0 {
-                let size = self.reader.read(&mut self.buf[self.buf_offset..])?;
+            if !self.read_more()? {
+                return if self.buf_length == self.buf.len() { Ok(None) } else { Ok(Some((i, 0))) };
+            }
+        }
+    }
 
-                if size == 0 {
-                    return Ok(Some(temp));
-                }
+    /// Consume a line found by `find_line` and borrow it.
+    fn consume_line_ref(&mut self, length: usize, line_break_length: usize) -> &[u8] {
+        if line_break_length == 1 && length + 1 == self.buf_length {
+            let e = self.buf[self.buf_offset + length];
 
-                self.buf_length += size;
+            if !(length == 0 && self.tracker.completes_line_break(e)) {
+                self.passing_byte = Some(if e == b'\n' { b'\r' } else { b'\n' });
             }
         }
+
+        self.buf_consume_ref(length, line_break_length)
     }
 
-    /// Drop the next line but not include the tailing line character (or line chracters like `CrLf`(`\r\n`)). If there is nothing to read, it will return `Ok(None)`. If there are something to read, it will return `Ok(Some(i))`. The `i` is the length of the dropped line.
-    ///
-    /// ```rust
-    /// use scanner_rust::Scanner;
-    ///
-    /// let mut sc = Scanner::new("123 456\r\n789 \n\n 中文 ".as_bytes());
-    ///
-    /// assert_eq!(Some(7), sc.drop_next_line().unwrap());
-    /// assert_eq!(Some("789 ".into()), sc.next_line().unwrap());
-    /// assert_eq!(Some(0), sc.drop_next_line().unwrap());
-    /// assert_eq!(Some(" 中文 ".into()), sc.next_line().unwrap());
-    /// assert_eq!(None, sc.drop_next_line().unwrap());
-    /// ```
-    pub fn drop_next_line(&mut self) -> Result<Option<usize>, ScannerError> {
-        if !self.passing_read()? {
-            return Ok(None);
+    /// Left shift (if necessary) the buffer to remove bytes from the start of the buffer. Typically, you should use this after `peek`ing the buffer.
+    #[inline]
+    #[allow(clippy::missing_safety_doc)]
+    pub unsafe fn remove_heading_bytes_from_buffer(&mut self, number_of_bytes: usize) {
+        self.buf_left_shift(number_of_bytes);
+    }
+
+    /// Consume the line break at the start of the buffer. `\r\n` and `\n\r` are one line break, unless the first byte is the second byte of the line break consumed right before it. After a `\r` at the end of the buffer, the next read decides whether it is `\r\n`. But a `\n` does not wait for the `\r` of `\n\r`, so that reading a line from an interactive input does not block until the next line is entered, and the `\r` is dropped later by `passing_read`.
+    fn buf_consume_line_break(&mut self) -> Result<(), ScannerError> {
+        let e = self.buf[self.buf_offset];
+
+        if self.tracker.completes_line_break(e) {
+            self.buf_left_shift(1);
+
+            return Ok(());
         }
 
-        let mut c = 0;
+        if self.buf_length == 1 && e == b'\r' {
+            self.read_more()?;
+        }
 
-        loop {
-            let e = self.buf[self.buf_offset];
+        if self.buf_length == 1 {
+            self.passing_byte = Some(if e == b'\n' { b'\r' } else { b'\n' });
+            self.buf_left_shift(1);
+        } else {
+            self.buf_left_shift(line_break_length(
+                &self.buf[self.buf_offset..(self.buf_offset + self.buf_length)],
+            ));
+        }
 
-            let width = get_width(e);
+        Ok(())
+    }
 
-            match width {
-                0 => {
-                    self.buf_left_shift(1);
+    fn passing_read(&mut self) -> Result<bool, ScannerError> {
+        self.history.begin(self.tracker, self.passing_byte);
 
-                    c += 1;
-                },
-                1 => {
-                    match e {
-                        b'\n' => {
-                            if self.buf_length == 1 {
-                                self.passing_byte = Some(b'\r');
-                                self.buf_left_shift(1);
-                            } else if self.buf[self.buf_offset + 1] == b'\r' {
-                                self.buf_left_shift(2);
-                            } else {
-                                self.buf_left_shift(1);
-                            }
+        if self.buf_length == 0 {
+            let size = self.reader.read(&mut self.buf[self.buf_offset..])?;
 
-                            return Ok(Some(c));
-                        },
-                        b'\r' => {
-                            if self.buf_length == 1 {
-                                self.passing_byte = Some(b'\n');
-                                self.buf_left_shift(1);
-                            } else if self.buf[self.buf_offset + 1] == b'\n' {
-                                self.buf_left_shift(2);
-                            } else {
-                                self.buf_left_shift(1);
-                            }
+            if size == 0 {
+                return Ok(false);
+            }
 
-                            return Ok(Some(c));
-                        },
-                        _ => (),
-                    }
+            self.buf_length += size;
+        }
 
-                    self.buf_left_shift(1);
+        // the other byte of `\r\n` or `\n\r` may have been read into the buffer before, such as by peeking
+        if let Some(passing_byte) = self.passing_byte.take() {
+            if self.buf[self.buf_offset] == passing_byte {
+                self.buf_left_shift(1);
 
-                    c += 1;
-                },
-                _ => {
-                    while self.buf_length < width {
-                        match self.reader.read(&mut self.buf[(self.buf_offset + self.buf_length)..])
-                        {
-                            Ok(0) => {
-                                self.buf_left_shift(self.buf_length);
-                                c += self.buf_length;
+                if self.buf_length == 0 {
+                    let size = self.reader.read(&mut self.buf[self.buf_offset..])?;
 
-                                return Ok(Some(c));
-                            },
-                            Ok(c) => self.buf_length += c,
-                            Err(ref err) if err.kind() == ErrorKind::Interrupted => (),
-                            Err(err) => return Err(err.into()),
-                        }
+                    if size == 0 {
+                        return Ok(false);
                     }
 
-                    self.buf_left_shift(width);
-                    c += width;
-                },
+                    self.buf_length += size;
+                }
             }
+        }
 
-            if self.buf_length == 0 {
-                let size = self.reader.read(&mut self.buf[self.buf_offset..])?;
+        Ok(true)
+    }
 
-                if size == 0 {
-                    return Ok(Some(c));
-                }
+    /// Get the length of the delimiter at the start of the buffer. `0` means there is no delimiter. The buffer should not be empty.
+    fn delimiter_length(&mut self) -> Result<usize, ScannerError> {
+        let required_length =
+            self.delimiter.required_length(self.buf[self.buf_offset], false).min(self.buf.len());
 
-                self.buf_length += size;
+        if self.buf_length < required_length {
+            if self.buf_offset + required_length > self.buf.len() {
+                self.buf_align_to_frond_end();
+            }
+
+            while self.buf_length < required_length {
+                match self.reader.read(&mut self.buf[(self.buf_offset + self.buf_length)..]) {
+                    Ok(0) => break,
+                    Ok(c) => self.buf_length += c,
+                    Err(ref err) if err.kind() == ErrorKind::Interrupted => (),
+                    Err(err) => return Err(err.into()),
+                }
             }
         }
+
+        Ok(self
+            .delimiter
+            .match_length(&self.buf[self.buf_offset..(self.buf_offset + self.buf_length)], false))
     }
-}
 
-impl<R: Read, N: ArrayLength + IsGreaterOrEqual<U4, Output = True>> Scanner<R, N> {
-    /// Skip the next whitespaces (`javaWhitespace`). If there is nothing to read, it will return `Ok(false)`.
-    ///
-    /// ```rust
-    /// use scanner_rust::Scanner;
-    ///
-    /// let mut sc = Scanner::new("1 2   c".as_bytes());
-    ///
-    /// assert_eq!(Some('1'), sc.next_char().unwrap());
-    /// assert_eq!(Some(' '), sc.next_char().unwrap());
-    /// assert_eq!(Some('2'), sc.next_char().unwrap());
-    /// assert_eq!(true, sc.skip_whitespaces().unwrap());
-    /// assert_eq!(Some('c'), sc.next_char().unwrap());
-    /// assert_eq!(false, sc.skip_whitespaces().unwrap());
-    /// ```
-    pub fn skip_whitespaces(&mut self) -> Result<bool, ScannerError> {
+    /// Read more data into the end of the buffer, which is aligned first if there is no room at its end. If the buffer is full or the reader reaches EOF, it will return `Ok(false)`.
+    fn read_more(&mut self) -> Result<bool, ScannerError> {
+        loop {
+            let end = self.buf_offset + self.buf_length;
+
+            if end == self.buf.len() {
+                if self.buf_offset == 0 {
+                    return Ok(false);
+                }
+
+                self.buf_align_to_frond_end();
+
+                continue;
+            }
+
+            match self.reader.read(&mut self.buf[end..]) {
+                Ok(0) => return Ok(false),
+                Ok(c) => {
+                    self.buf_length += c;
+
+                    return Ok(true);
+                },
+                Err(ref err) if err.kind() == ErrorKind::Interrupted => (),
+                Err(err) => return Err(err.into()),
+            }
+        }
+    }
+
+    /// Get the length of the delimiter at the index `i` of the buffer without consuming anything. `None` means the buffer is full before the delimiter can be decided.
+    fn delimiter_length_at(&mut self, i: usize) -> Result<Option<usize>, ScannerError> {
+        let required_length = self.delimiter.required_length(self.buf[self.buf_offset + i], false);
+
+        if required_length == 0 || required_length > self.buf.len() {
+            return Ok(Some(0));
+        }
+
+        while self.buf_length < i + required_length {
+            if !self.read_more()? {
+                if self.buf_length == self.buf.len() {
+                    return Ok(None);
+                }
+
+                break;
+            }
+        }
+
+        Ok(Some(self.delimiter.match_length(
+            &self.buf[(self.buf_offset + i)..(self.buf_offset + self.buf_length)],
+            false,
+        )))
+    }
+
+    /// Drop the first `distance` bytes of the buffer and move the rest to the front.
+    #[inline]
+    fn buf_drop_and_align(&mut self, distance: usize) {
+        self.track(self.buf_offset, distance);
+
+        self.buf_offset += distance;
+        self.buf_length -= distance;
+
+        self.buf_align_to_frond_end();
+    }
+
+    /// Find the next token in the buffer without consuming anything but the leading delimiters which do not fit in the buffer. It returns the start and end indices of the token in the buffer (relative to `buf_offset`), and whether the end of the token is found.
+    fn find_token(&mut self) -> Result<Option<(usize, usize, bool)>, ScannerError> {
         if !self.passing_read()? {
-            return Ok(false);
+            return Ok(None);
         }
 
-        loop {
-            let e = self.buf[self.buf_offset];
+        let mut start = 0;
 
-            let width = get_width(e);
+        loop {
+            if start == self.buf_length && !self.read_more()? {
+                if self.buf_length < self.buf.len() {
+                    return Ok(None);
+                }
 
-            match width {
-                0 => {
-                    break;
-                },
-                1 => {
-                    if !is_whitespace_1(e) {
-                        break;
-                    }
+                self.buf_drop_and_align(start);
+                start = 0;
 
-                    self.buf_left_shift(1);
-                },
-                3 => {
-                    while self.buf_length < width {
-                        match self.reader.read(&mut self.buf[(self.buf_offset + self.buf_length)..])
-                        {
-                            Ok(0) => {
-                                return Ok(true);
-                            },
-                            Ok(c) => self.buf_length += c,
-                            Err(ref err) if err.kind() == ErrorKind::Interrupted => (),
-                            Err(err) => return Err(err.into()),
-                        }
-                    }
+                if !self.read_more()? {
+                    return Ok(None);
+                }
+            }
 
-                    if is_whitespace_3(
-                        self.buf[self.buf_offset],
-                        self.buf[self.buf_offset + 1],
-                        self.buf[self.buf_offset + 2],
-                    ) {
-                        self.buf_left_shift(3);
-                    } else {
-                        break;
-                    }
-                },
-                _ => {
-                    break;
+            match self.delimiter_length_at(start)? {
+                Some(0) => break,
+                Some(length) => start += length,
+                None => {
+                    self.buf_drop_and_align(start);
+                    start = 0;
                 },
             }
+        }
 
-            if self.buf_length == 0 {
-                let size = self.reader.read(&mut self.buf[self.buf_offset..])?;
+        let mut end = start;
 
-                if size == 0 {
-                    return Ok(true);
+        loop {
+            // `end` can be beyond the data if the last char is not fully read, because a multi-byte char is not split, just like what `next_raw` does
+            if end >= self.buf_length {
+                if self.read_more()? {
+                    continue;
                 }
 
-                self.buf_length += size;
+                if self.buf_length < self.buf.len() {
+                    return Ok(Some((start, self.buf_length, true)));
+                }
+            } else {
+                match self.delimiter_length_at(end)? {
+                    Some(0) => {
+                        end += get_width(self.buf[self.buf_offset + end]).max(1);
+
+                        continue;
+                    },
+                    Some(_) => return Ok(Some((start, end, true))),
+                    None => (),
+                }
             }
-        }
 
-        Ok(true)
+            // the buffer is full before the end of the token is found
+            if start == 0 {
+                return Ok(Some((start, end.min(self.buf_length), false)));
+            }
+
+            self.buf_drop_and_align(start);
+            end -= start;
+            start = 0;
+        }
     }
+}
 
-    /// Read the next token separated by whitespaces. If there is nothing to read, it will return `Ok(None)`.
+impl<R: Read, N: BufferSize> Scanner<R, N> {
+    /// Read the next char. If the data is not a correct char, it will return a `Ok(Some(REPLACEMENT_CHARACTER))` which is � (or an `InvalidUtf8` error in the strict UTF-8 mode). If there is nothing to read, it will return `Ok(None)`.
     ///
     /// ```rust
     /// use scanner_rust::Scanner;
     ///
-    /// let mut sc = Scanner::new("123 456\r\n789 \n\n 中文 ".as_bytes());
+    /// let mut sc = Scanner::new("5 c 中文".as_bytes());
     ///
-    /// assert_eq!(Some("123".into()), sc.next().unwrap());
-    /// assert_eq!(Some("456".into()), sc.next().unwrap());
-    /// assert_eq!(Some("789".into()), sc.next().unwrap());
-    /// assert_eq!(Some("中文".into()), sc.next().unwrap());
-    /// assert_eq!(None, sc.next().unwrap());
+    /// assert_eq!(Some('5'), sc.next_char().unwrap());
+    /// assert_eq!(Some(' '), sc.next_char().unwrap());
+    /// assert_eq!(Some('c'), sc.next_char().unwrap());
+    /// assert_eq!(Some(' '), sc.next_char().unwrap());
+    /// assert_eq!(Some('中'), sc.next_char().unwrap());
+    /// assert_eq!(Some('文'), sc.next_char().unwrap());
+    /// assert_eq!(None, sc.next_char().unwrap());
     /// ```
-    #[allow(clippy::should_implement_trait)]
-    pub fn next(&mut self) -> Result<Option<String>, ScannerError> {
-        if !self.skip_whitespaces()? {
+    pub fn next_char(&mut self) -> Result<Option<char>, ScannerError> {
+        if !self.passing_read()? {
             return Ok(None);
         }
 
-        if self.buf_length == 0 {
-            let size = self.reader.read(&mut self.buf[self.buf_offset..])?;
+        let e = self.buf[self.buf_offset];
 
-            if size == 0 {
-                return Ok(None);
-            }
+        let width = get_width(e);
 
-            self.buf_length += size;
+        match width {
+            0 => {
+                if self.strict_utf8 {
+                    return Err(self.invalid_utf8(1));
+                }
+
+                self.buf_left_shift(1);
+
+                Ok(Some(REPLACEMENT_CHARACTER))
+            },
+            1 => {
+                self.buf_left_shift(1);
+
+                Ok(Some(e as char))
+            },
+            _ => {
+                while self.buf_length < width {
+                    match self.reader.read(&mut self.buf[(self.buf_offset + self.buf_length)..]) {
+                        Ok(0) => {
+                            if self.strict_utf8 {
+                                return Err(self.invalid_utf8(self.buf_length));
+                            }
+
+                            self.buf_left_shift(1);
+
+                            return Ok(Some(REPLACEMENT_CHARACTER));
+                        },
+                        Ok(c) => self.buf_length += c,
+                        Err(ref err) if err.kind() == ErrorKind::Interrupted => (),
+                        Err(err) => return Err(err.into()),
+                    }
+                }
+
+                let char_str_bytes = &self.buf[self.buf_offset..(self.buf_offset + width)];
+
+                match from_utf8(char_str_bytes) {
+                    Ok(char_str) => {
+                        let c = char_str.chars().next();
+
+                        self.buf_left_shift(width);
+
+                        Ok(c)
+                    },
+                    Err(err) => {
+                        if self.strict_utf8 {
+                            return Err(self.invalid_utf8(err.error_len().unwrap_or(1)));
+                        }
+
+                        self.buf_left_shift(1);
+
+                        Ok(Some(REPLACEMENT_CHARACTER))
+                    },
+                }
+            },
         }
+    }
 
+    /// Read the next line but not include the tailing line character (or line chracters like `CrLf`(`\r\n`)). If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("123 456\r\n789 \n\n 中文 ".as_bytes());
+    ///
+    /// assert_eq!(Some("123 456".into()), sc.next_line().unwrap());
+    /// assert_eq!(Some("789 ".into()), sc.next_line().unwrap());
+    /// assert_eq!(Some("".into()), sc.next_line().unwrap());
+    /// assert_eq!(Some(" 中文 ".into()), sc.next_line().unwrap());
+    /// ```
+    pub fn next_line(&mut self) -> Result<Option<String>, ScannerError> {
         let mut temp = String::new();
 
+        Ok(self.next_line_into(&mut temp)?.map(|_| temp))
+    }
+
+    /// Read the next line into `buf`, just like `next_line`, so that the allocation of `buf` can be reused. `buf` is cleared first. If there is nothing to read, it will return `Ok(None)`. Otherwise, it will return `Ok(Some(i))`. The `i` is the length of the line in bytes.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("123 456\r\n789 \n\n 中文 ".as_bytes());
+    /// let mut line = String::new();
+    ///
+    /// assert_eq!(Some(7), sc.next_line_into(&mut line).unwrap());
+    /// assert_eq!("123 456", line);
+    /// assert_eq!(Some(4), sc.next_line_into(&mut line).unwrap());
+    /// assert_eq!("789 ", line);
+    /// assert_eq!(Some(0), sc.next_line_into(&mut line).unwrap());
+    /// assert_eq!("", line);
+    /// assert_eq!(Some(8), sc.next_line_into(&mut line).unwrap());
+    /// assert_eq!(None, sc.next_line_into(&mut line).unwrap());
+    /// ```
+    pub fn next_line_into(&mut self, buf: &mut String) -> Result<Option<usize>, ScannerError> {
+        buf.clear();
+
+        if !self.passing_read()? {
+            return Ok(None);
+        }
+
         loop {
             let e = self.buf[self.buf_offset];
 
@@ -664,81 +1028,36 @@ impl<R: Read, N: ArrayLength + IsGreaterOrEqual<U4, Output = True>> Scanner<R, N
 
             match width {
                 0 => {
-                    self.buf_left_shift(1);
-
-                    temp.push(REPLACEMENT_CHARACTER);
-                },
-                1 => {
-                    if is_whitespace_1(e) {
-                        return Ok(Some(temp));
+                    if self.strict_utf8 {
+                        return Err(self.invalid_utf8(1));
                     }
 
                     self.buf_left_shift(1);
 
-                    temp.push(e as char);
+                    buf.push(REPLACEMENT_CHARACTER);
                 },
-                3 => {
-                    while self.buf_length < width {
-                        match self.reader.read(&mut self.buf[(self.buf_offset + self.buf_length)..])
-                        {
-                            Ok(0) => {
-                                temp.push_str(
-                                    String::from_utf8_lossy(
-                                        &self.buf
-                                            [self.buf_offset..(self.buf_offset + self.buf_length)],
-                                    )
-                                    .as_ref(),
-                                );
-
-                                self.buf_left_shift(self.buf_length);
+                1 => {
+                    match e {
+                        b'\n' | b'\r' => {
+                            self.buf_consume_line_break()?;
 
-                                return Ok(Some(temp));
-                            },
-                            Ok(c) => self.buf_length += c,
-                            Err(ref err) if err.kind() == ErrorKind::Interrupted => (),
-                            Err(err) => return Err(err.into()),
-                        }
+                            return Ok(Some(buf.len()));
+                        },
+                        _ => (),
                     }
 
-                    if is_whitespace_3(
-                        self.buf[self.buf_offset],
-                        self.buf[self.buf_offset + 1],
-                        self.buf[self.buf_offset + 2],
-                    ) {
-                        return Ok(Some(temp));
-                    } else {
-                        let char_str_bytes = &self.buf[self.buf_offset..(self.buf_offset + width)];
-
-                        match from_utf8(char_str_bytes) {
-                            Ok(char_str) => {
-                                temp.push_str(char_str);
-
-                                self.buf_left_shift(width);
-                            },
-                            Err(_) => {
-                                self.buf_left_shift(1);
+                    self.buf_left_shift(1);
 
-                                temp.push(REPLACEMENT_CHARACTER);
-                            },
-                        }
-                    }
+                    buf.push(e as char);
                 },
                 _ => {
                     while self.buf_length < width {
                         match self.reader.read(&mut self.buf[(self.buf_offset + self.buf_length)..])
                         {
                             Ok(0) => {
-                                temp.push_str(
-                                    String::from_utf8_lossy(
-                                        &self.buf
-                                            [self.buf_offset..(self.buf_offset + self.buf_length)],
-                                    )
-                                    .as_ref(),
-                                );
-
-                                self.buf_left_shift(self.buf_length);
+                                buf.push_str(&self.consume_str(self.buf_length)?);
 
-                                return Ok(Some(temp));
+                                return Ok(Some(buf.len()));
                             },
                             Ok(c) => self.buf_length += c,
                             Err(ref err) if err.kind() == ErrorKind::Interrupted => (),
@@ -750,14 +1069,18 @@ impl<R: Read, N: ArrayLength + IsGreaterOrEqual<U4, Output = True>> Scanner<R, N
 
                     match from_utf8(char_str_bytes) {
                         Ok(char_str) => {
-                            temp.push_str(char_str);
+                            buf.push_str(char_str);
 
                             self.buf_left_shift(width);
                         },
-                        Err(_) => {
+                        Err(err) => {
+                            if self.strict_utf8 {
+                                return Err(self.invalid_utf8(err.error_len().unwrap_or(1)));
+                            }
+
                             self.buf_left_shift(1);
 
-                            temp.push(REPLACEMENT_CHARACTER);
+                            buf.push(REPLACEMENT_CHARACTER);
                         },
                     }
                 },
@@ -767,7 +1090,7 @@ impl<R: Read, N: ArrayLength + IsGreaterOrEqual<U4, Output = True>> Scanner<R, N
                 let size = self.reader.read(&mut self.buf[self.buf_offset..])?;
 
                 if size == 0 {
-                    return Ok(Some(temp));
+                    return Ok(Some(buf.len()));
                 }
 
                 self.buf_length += size;
@@ -775,36 +1098,48 @@ impl<R: Read, N: ArrayLength + IsGreaterOrEqual<U4, Output = True>> Scanner<R, N
         }
     }
 
-    /// Read the next token separated by whitespaces without fully validating UTF-8. If there is nothing to read, it will return `Ok(None)`.
+    /// Read the next line include the tailing line character (or line chracters like `CrLf`(`\r\n`)) without fully validating UTF-8. If there is nothing to read, it will return `Ok(None)`.
     ///
     /// ```rust
     /// use scanner_rust::Scanner;
     ///
     /// let mut sc = Scanner::new("123 456\r\n789 \n\n 中文 ".as_bytes());
     ///
-    /// assert_eq!(Some("123".into()), sc.next_raw().unwrap());
-    /// assert_eq!(Some("456".into()), sc.next_raw().unwrap());
-    /// assert_eq!(Some("789".into()), sc.next_raw().unwrap());
-    /// assert_eq!(Some("中文".into()), sc.next_raw().unwrap());
-    /// assert_eq!(None, sc.next_raw().unwrap());
+    /// assert_eq!(Some("123 456".into()), sc.next_line_raw().unwrap());
+    /// assert_eq!(Some("789 ".into()), sc.next_line_raw().unwrap());
+    /// assert_eq!(Some("".into()), sc.next_line_raw().unwrap());
+    /// assert_eq!(Some(" 中文 ".into()), sc.next_line_raw().unwrap());
     /// ```
-    pub fn next_raw(&mut self) -> Result<Option<Vec<u8>>, ScannerError> {
-        if !self.skip_whitespaces()? {
-            return Ok(None);
-        }
+    pub fn next_line_raw(&mut self) -> Result<Option<Vec<u8>>, ScannerError> {
+        let mut temp = Vec::new();
 
-        if self.buf_length == 0 {
-            let size = self.reader.read(&mut self.buf[self.buf_offset..])?;
+        Ok(self.next_line_raw_into(&mut temp)?.map(|_| temp))
+    }
 
-            if size == 0 {
-                return Ok(None);
-            }
+    /// Read the next line into `buf` without fully validating UTF-8, just like `next_line_raw`, so that the allocation of `buf` can be reused. `buf` is cleared first. If there is nothing to read, it will return `Ok(None)`. Otherwise, it will return `Ok(Some(i))`. The `i` is the length of the line.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("123 456\r\n789 \n\n 中文 ".as_bytes());
+    /// let mut line = Vec::new();
+    ///
+    /// assert_eq!(Some(7), sc.next_line_raw_into(&mut line).unwrap());
+    /// assert_eq!(b"123 456", line.as_slice());
+    /// assert_eq!(Some(4), sc.next_line_raw_into(&mut line).unwrap());
+    /// assert_eq!(b"789 ", line.as_slice());
+    /// assert_eq!(Some(0), sc.next_line_raw_into(&mut line).unwrap());
+    /// assert_eq!(Some(8), sc.next_line_raw_into(&mut line).unwrap());
+    /// assert_eq!(" 中文 ".as_bytes(), line.as_slice());
+    /// assert_eq!(None, sc.next_line_raw_into(&mut line).unwrap());
+    /// ```
+    pub fn next_line_raw_into(&mut self, buf: &mut Vec<u8>) -> Result<Option<usize>, ScannerError> {
+        buf.clear();
 
-            self.buf_length += size;
+        if !self.passing_read()? {
+            return Ok(None);
         }
 
-        let mut temp = Vec::new();
-
         loop {
             let e = self.buf[self.buf_offset];
 
@@ -814,58 +1149,34 @@ impl<R: Read, N: ArrayLength + IsGreaterOrEqual<U4, Output = True>> Scanner<R, N
                 0 => {
                     self.buf_left_shift(1);
 
-                    temp.push(e);
+                    buf.push(e);
                 },
                 1 => {
-                    if is_whitespace_1(e) {
-                        return Ok(Some(temp));
-                    }
-
-                    self.buf_left_shift(1);
-
-                    temp.push(e);
-                },
-                3 => {
-                    while self.buf_length < width {
-                        match self.reader.read(&mut self.buf[(self.buf_offset + self.buf_length)..])
-                        {
-                            Ok(0) => {
-                                self.buf_left_shift(self.buf_length);
+                    match e {
+                        b'\n' | b'\r' => {
+                            self.buf_consume_line_break()?;
 
-                                return Ok(Some(temp));
-                            },
-                            Ok(c) => self.buf_length += c,
-                            Err(ref err) if err.kind() == ErrorKind::Interrupted => (),
-                            Err(err) => return Err(err.into()),
-                        }
+                            return Ok(Some(buf.len()));
+                        },
+                        _ => (),
                     }
 
-                    if is_whitespace_3(
-                        self.buf[self.buf_offset],
-                        self.buf[self.buf_offset + 1],
-                        self.buf[self.buf_offset + 2],
-                    ) {
-                        return Ok(Some(temp));
-                    } else {
-                        let char_str_bytes = &self.buf[self.buf_offset..(self.buf_offset + width)];
-
-                        temp.extend_from_slice(char_str_bytes);
+                    self.buf_left_shift(1);
 
-                        self.buf_left_shift(width);
-                    }
+                    buf.push(e);
                 },
                 _ => {
                     while self.buf_length < width {
                         match self.reader.read(&mut self.buf[(self.buf_offset + self.buf_length)..])
                         {
                             Ok(0) => {
-                                temp.extend_from_slice(
+                                buf.extend_from_slice(
                                     &self.buf[self.buf_offset..(self.buf_offset + self.buf_length)],
                                 );
 
                                 self.buf_left_shift(self.buf_length);
 
-                                return Ok(Some(temp));
+                                return Ok(Some(buf.len()));
                             },
                             Ok(c) => self.buf_length += c,
                             Err(ref err) if err.kind() == ErrorKind::Interrupted => (),
@@ -875,7 +1186,7 @@ impl<R: Read, N: ArrayLength + IsGreaterOrEqual<U4, Output = True>> Scanner<R, N
 
                     let char_str_bytes = &self.buf[self.buf_offset..(self.buf_offset + width)];
 
-                    temp.extend_from_slice(char_str_bytes);
+                    buf.extend_from_slice(char_str_bytes);
 
                     self.buf_left_shift(width);
                 },
@@ -885,7 +1196,7 @@ impl<R: Read, N: ArrayLength + IsGreaterOrEqual<U4, Output = True>> Scanner<R, N
                 let size = self.reader.read(&mut self.buf[self.buf_offset..])?;
 
                 if size == 0 {
-                    return Ok(Some(temp));
+                    return Ok(Some(buf.len()));
                 }
 
                 self.buf_length += size;
@@ -893,32 +1204,96 @@ impl<R: Read, N: ArrayLength + IsGreaterOrEqual<U4, Output = True>> Scanner<R, N
         }
     }
 
-    /// Drop the next token separated by whitespaces. If there is nothing to read, it will return `Ok(None)`. If there are something to read, it will return `Ok(Some(i))`. The `i` is the length of the dropped line.
+    /// Read the next line but not include the tailing line character (or line chracters like `CrLf`(`\r\n`)), and borrow it from the buffer instead of allocating a new string whenever possible. If there is nothing to read, it will return `Ok(None)`.
     ///
     /// ```rust
     /// use scanner_rust::Scanner;
     ///
     /// let mut sc = Scanner::new("123 456\r\n789 \n\n 中文 ".as_bytes());
     ///
-    /// assert_eq!(Some(3), sc.drop_next().unwrap());
-    /// assert_eq!(Some("456".into()), sc.next().unwrap());
-    /// assert_eq!(Some(3), sc.drop_next().unwrap());
-    /// assert_eq!(Some("中文".into()), sc.next().unwrap());
-    /// assert_eq!(None, sc.drop_next().unwrap());
+    /// assert_eq!(Some("123 456"), sc.next_line_ref().unwrap());
+    /// assert_eq!(Some("789 "), sc.next_line_ref().unwrap());
+    /// assert_eq!(Some(""), sc.next_line_ref().unwrap());
+    /// assert_eq!(Some(" 中文 "), sc.next_line_ref().unwrap());
+    /// assert_eq!(None, sc.next_line_ref().unwrap());
     /// ```
-    pub fn drop_next(&mut self) -> Result<Option<usize>, ScannerError> {
-        if !self.skip_whitespaces()? {
+    pub fn next_line_ref(&mut self) -> Result<Option<&str>, ScannerError> {
+        if !self.passing_read()? {
             return Ok(None);
         }
 
-        if self.buf_length == 0 {
-            let size = self.reader.read(&mut self.buf[self.buf_offset..])?;
+        match self.find_line()? {
+            Some((length, line_break_length))
+                if from_utf8(&self.buf[self.buf_offset..(self.buf_offset + length)]).is_ok() =>
+            {
+                let line = self.consume_line_ref(length, line_break_length);
 
-            if size == 0 {
-                return Ok(None);
-            }
+                // the bytes have just been validated
+                Ok(Some(unsafe { from_utf8_unchecked(line) }))
+            },
+            _ => {
+                // the line is too long or needs to be decoded by `next_line`
+                match self.next_line()? {
+                    Some(line) => {
+                        self.spill = line.into_bytes();
 
-            self.buf_length += size;
+                        Ok(Some(unsafe { from_utf8_unchecked(&self.spill) }))
+                    },
+                    None => Ok(None),
+                }
+            },
+        }
+    }
+
+    /// Read the next line include the tailing line character (or line chracters like `CrLf`(`\r\n`)) without validating UTF-8, and borrow it from the buffer instead of allocating a new vector whenever possible. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("123 456\r\n789 \n\n 中文 ".as_bytes());
+    ///
+    /// assert_eq!(Some("123 456".as_bytes()), sc.next_line_raw_ref().unwrap());
+    /// assert_eq!(Some("789 ".as_bytes()), sc.next_line_raw_ref().unwrap());
+    /// assert_eq!(Some("".as_bytes()), sc.next_line_raw_ref().unwrap());
+    /// assert_eq!(Some(" 中文 ".as_bytes()), sc.next_line_raw_ref().unwrap());
+    /// assert_eq!(None, sc.next_line_raw_ref().unwrap());
+    /// ```
+    pub fn next_line_raw_ref(&mut self) -> Result<Option<&[u8]>, ScannerError> {
+        if !self.passing_read()? {
+            return Ok(None);
+        }
+
+        match self.find_line()? {
+            Some((length, line_break_length)) => {
+                Ok(Some(self.consume_line_ref(length, line_break_length)))
+            },
+            None => match self.next_line_raw()? {
+                Some(line) => {
+                    self.spill = line;
+
+                    Ok(Some(&self.spill))
+                },
+                None => Ok(None),
+            },
+        }
+    }
+
+    /// Drop the next line but not include the tailing line character (or line chracters like `CrLf`(`\r\n`)). If there is nothing to read, it will return `Ok(None)`. If there are something to read, it will return `Ok(Some(i))`. The `i` is the length of the dropped line.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("123 456\r\n789 \n\n 中文 ".as_bytes());
+    ///
+    /// assert_eq!(Some(7), sc.drop_next_line().unwrap());
+    /// assert_eq!(Some("789 ".into()), sc.next_line().unwrap());
+    /// assert_eq!(Some(0), sc.drop_next_line().unwrap());
+    /// assert_eq!(Some(" 中文 ".into()), sc.next_line().unwrap());
+    /// assert_eq!(None, sc.drop_next_line().unwrap());
+    /// ```
+    pub fn drop_next_line(&mut self) -> Result<Option<usize>, ScannerError> {
+        if !self.passing_read()? {
+            return Ok(None);
         }
 
         let mut c = 0;
@@ -935,40 +1310,19 @@ impl<R: Read, N: ArrayLength + IsGreaterOrEqual<U4, Output = True>> Scanner<R, N
                     c += 1;
                 },
                 1 => {
-                    if is_whitespace_1(e) {
-                        return Ok(Some(c));
+                    match e {
+                        b'\n' | b'\r' => {
+                            self.buf_consume_line_break()?;
+
+                            return Ok(Some(c));
+                        },
+                        _ => (),
                     }
 
                     self.buf_left_shift(1);
 
                     c += 1;
                 },
-                3 => {
-                    while self.buf_length < width {
-                        match self.reader.read(&mut self.buf[(self.buf_offset + self.buf_length)..])
-                        {
-                            Ok(0) => {
-                                self.buf_left_shift(self.buf_length);
-                                c += self.buf_length;
-
-                                return Ok(Some(c));
-                            },
-                            Ok(c) => self.buf_length += c,
-                            Err(ref err) if err.kind() == ErrorKind::Interrupted => (),
-                            Err(err) => return Err(err.into()),
-                        }
-                    }
-
-                    if is_whitespace_3(
-                        self.buf[self.buf_offset],
-                        self.buf[self.buf_offset + 1],
-                        self.buf[self.buf_offset + 2],
-                    ) {
-                        return Ok(Some(c));
-                    } else {
-                        self.buf_left_shift(width);
-                    }
-                },
                 _ => {
                     while self.buf_length < width {
                         match self.reader.read(&mut self.buf[(self.buf_offset + self.buf_length)..])
@@ -986,6 +1340,7 @@ impl<R: Read, N: ArrayLength + IsGreaterOrEqual<U4, Output = True>> Scanner<R, N
                     }
 
                     self.buf_left_shift(width);
+                    c += width;
                 },
             }
 
@@ -1002,906 +1357,3011 @@ impl<R: Read, N: ArrayLength + IsGreaterOrEqual<U4, Output = True>> Scanner<R, N
     }
 }
 
-impl<R: Read, N: ArrayLength + IsGreaterOrEqual<U4, Output = True>> Scanner<R, N> {
-    /// Read the next bytes. If there is nothing to read, it will return `Ok(None)`.
+impl<R: Read, N: BufferSize> Scanner<R, N> {
+    /// Skip the next delimiters, which are whitespaces (`javaWhitespace`) by default. If there is nothing to read, it will return `Ok(false)`.
     ///
     /// ```rust
     /// use scanner_rust::Scanner;
     ///
-    /// let mut sc = Scanner::new("123 456\r\n789 \n\n 中文 ".as_bytes());
+    /// let mut sc = Scanner::new("1 2   c".as_bytes());
     ///
-    /// assert_eq!(Some("123".into()), sc.next_bytes(3).unwrap());
-    /// assert_eq!(Some(" 456".into()), sc.next_bytes(4).unwrap());
-    /// assert_eq!(Some("\r\n789 ".into()), sc.next_bytes(6).unwrap());
-    /// assert_eq!(Some("中文".into()), sc.next_raw().unwrap());
-    /// assert_eq!(Some(" ".into()), sc.next_bytes(2).unwrap());
-    /// assert_eq!(None, sc.next_bytes(2).unwrap());
+    /// assert_eq!(Some('1'), sc.next_char().unwrap());
+    /// assert_eq!(Some(' '), sc.next_char().unwrap());
+    /// assert_eq!(Some('2'), sc.next_char().unwrap());
+    /// assert_eq!(true, sc.skip_whitespaces().unwrap());
+    /// assert_eq!(Some('c'), sc.next_char().unwrap());
+    /// assert_eq!(false, sc.skip_whitespaces().unwrap());
     /// ```
-    pub fn next_bytes(
-        &mut self,
-        max_number_of_bytes: usize,
-    ) -> Result<Option<Vec<u8>>, ScannerError> {
+    pub fn skip_whitespaces(&mut self) -> Result<bool, ScannerError> {
         if !self.passing_read()? {
-            return Ok(None);
+            return Ok(false);
         }
 
-        let mut temp = Vec::new();
-        let mut c = 0;
+        loop {
+            let length = self.delimiter_length()?;
+
+            if length == 0 {
+                break;
+            }
+
+            self.buf_left_shift(length);
 
-        while c < max_number_of_bytes {
             if self.buf_length == 0 {
                 let size = self.reader.read(&mut self.buf[self.buf_offset..])?;
 
                 if size == 0 {
-                    return Ok(Some(temp));
+                    return Ok(true);
                 }
 
                 self.buf_length += size;
             }
-
-            let dropping_bytes = self.buf_length.min(max_number_of_bytes - c);
-
-            temp.extend_from_slice(&self.buf[self.buf_offset..(self.buf_offset + dropping_bytes)]);
-
-            self.buf_left_shift(dropping_bytes);
-
-            c += dropping_bytes;
         }
 
-        Ok(Some(temp))
+        Ok(true)
     }
 
-    /// Drop the next N bytes. If there is nothing to read, it will return `Ok(None)`. If there are something to read, it will return `Ok(Some(i))`. The `i` is the length of the actually dropped bytes.
+    /// Read the next token separated by delimiters. If there is nothing to read, it will return `Ok(None)`.
     ///
     /// ```rust
     /// use scanner_rust::Scanner;
     ///
     /// let mut sc = Scanner::new("123 456\r\n789 \n\n 中文 ".as_bytes());
     ///
-    /// assert_eq!(Some(7), sc.drop_next_bytes(7).unwrap());
-    /// assert_eq!(Some("".into()), sc.next_line().unwrap());
-    /// assert_eq!(Some("789 ".into()), sc.next_line().unwrap());
-    /// assert_eq!(Some(1), sc.drop_next_bytes(1).unwrap());
-    /// assert_eq!(Some(" 中文 ".into()), sc.next_line().unwrap());
-    /// assert_eq!(None, sc.drop_next_bytes(1).unwrap());
+    /// assert_eq!(Some("123".into()), sc.next().unwrap());
+    /// assert_eq!(Some("456".into()), sc.next().unwrap());
+    /// assert_eq!(Some("789".into()), sc.next().unwrap());
+    /// assert_eq!(Some("中文".into()), sc.next().unwrap());
+    /// assert_eq!(None, sc.next().unwrap());
     /// ```
-    pub fn drop_next_bytes(
-        &mut self,
-        max_number_of_bytes: usize,
-    ) -> Result<Option<usize>, ScannerError> {
-        if !self.passing_read()? {
-            return Ok(None);
-        }
-
-        let mut c = 0;
-
-        while c < max_number_of_bytes {
-            if self.buf_length == 0 {
-                let size = self.reader.read(&mut self.buf[self.buf_offset..])?;
-
-                if size == 0 {
-                    return Ok(Some(c));
-                }
-
-                self.buf_length += size;
-            }
-
-            let dropping_bytes = self.buf_length.min(max_number_of_bytes - c);
-
-            self.buf_left_shift(dropping_bytes);
-
-            c += dropping_bytes;
-        }
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Result<Option<String>, ScannerError> {
+        let mut temp = String::new();
 
-        Ok(Some(c))
+        Ok(self.next_into(&mut temp)?.map(|_| temp))
     }
-}
 
-impl<R: Read, N: ArrayLength + IsGreaterOrEqual<U4, Output = True>> Scanner<R, N> {
-    /// Read the next text until it reaches a specific boundary. If there is nothing to read, it will return `Ok(None)`.
+    /// Read the next token separated by delimiters into `buf`, just like `next`, so that the allocation of `buf` can be reused. `buf` is cleared first. If there is nothing to read, it will return `Ok(None)`. Otherwise, it will return `Ok(Some(i))`. The `i` is the length of the token in bytes.
     ///
     /// ```rust
     /// use scanner_rust::Scanner;
     ///
     /// let mut sc = Scanner::new("123 456\r\n789 \n\n 中文 ".as_bytes());
-    ///
-    /// assert_eq!(Some("123".into()), sc.next_until(" ").unwrap());
-    /// assert_eq!(Some("456\r".into()), sc.next_until("\n").unwrap());
-    /// assert_eq!(Some("78".into()), sc.next_until("9 ").unwrap());
-    /// assert_eq!(Some("\n\n 中文 ".into()), sc.next_until("kk").unwrap());
-    /// assert_eq!(None, sc.next().unwrap());
+    /// let mut token = String::new();
+    ///
+    /// assert_eq!(Some(3), sc.next_into(&mut token).unwrap());
+    /// assert_eq!("123", token);
+    /// assert_eq!(Some(3), sc.next_into(&mut token).unwrap());
+    /// assert_eq!("456", token);
+    /// assert_eq!(Some(3), sc.next_into(&mut token).unwrap());
+    /// assert_eq!(Some(6), sc.next_into(&mut token).unwrap());
+    /// assert_eq!(None, sc.next_into(&mut token).unwrap());
+    /// assert_eq!("", token);
     /// ```
-    pub fn next_until<S: AsRef<str>>(
-        &mut self,
-        boundary: S,
-    ) -> Result<Option<String>, ScannerError> {
-        if !self.passing_read()? {
+    pub fn next_into(&mut self, buf: &mut String) -> Result<Option<usize>, ScannerError> {
+        buf.clear();
+
+        if !self.skip_whitespaces()? {
             return Ok(None);
         }
 
-        let boundary = boundary.as_ref().as_bytes();
-        let boundary_length = boundary.len();
-        let mut temp = String::new();
-
-        let mut b = 0;
+        if self.buf_length == 0 {
+            let size = self.reader.read(&mut self.buf[self.buf_offset..])?;
 
-        loop {
-            let mut p = 0;
+            if size == 0 {
+                return Ok(None);
+            }
 
-            while p < self.buf_length {
-                if self.buf[self.buf_offset + p] == boundary[b] {
-                    b += 1;
-                    p += 1;
+            self.buf_length += size;
+        }
 
-                    if b == boundary_length {
-                        match p.cmp(&boundary_length) {
-                            Ordering::Equal => (),
-                            Ordering::Greater => {
-                                temp.push_str(
-                                    String::from_utf8_lossy(
-                                        &self.buf[self.buf_offset
-                                            ..(self.buf_offset + p - boundary_length)],
-                                    )
-                                    .as_ref(),
-                                );
-                            },
-                            Ordering::Less => {
-                                let adjusted_temp_length = temp.len() - (boundary_length - p);
+        loop {
+            if self.delimiter_length()? > 0 {
+                return Ok(Some(buf.len()));
+            }
 
-                                unsafe {
-                                    temp.as_mut_vec().set_len(adjusted_temp_length);
-                                }
-                            },
-                        }
+            let e = self.buf[self.buf_offset];
 
-                        self.buf_left_shift(p);
+            let width = get_width(e);
 
-                        return Ok(Some(temp));
+            match width {
+                0 => {
+                    if self.strict_utf8 {
+                        return Err(self.invalid_utf8(1));
                     }
-                } else {
-                    b = 0;
-                    p += 1;
-                }
-            }
 
-            let mut utf8_length = 0;
-
-            loop {
-                let width = get_width(self.buf[self.buf_offset + utf8_length]).max(1);
+                    self.buf_left_shift(1);
 
-                utf8_length += width;
+                    buf.push(REPLACEMENT_CHARACTER);
+                },
+                1 => {
+                    self.buf_left_shift(1);
 
-                match utf8_length.cmp(&self.buf_length) {
-                    Ordering::Equal => break,
-                    Ordering::Greater => {
-                        utf8_length -= width;
-                        break;
-                    },
-                    Ordering::Less => (),
-                }
-            }
+                    buf.push(e as char);
+                },
+                _ => {
+                    while self.buf_length < width {
+                        match self.reader.read(&mut self.buf[(self.buf_offset + self.buf_length)..])
+                        {
+                            Ok(0) => {
+                                buf.push_str(&self.consume_str(self.buf_length)?);
+
+                                return Ok(Some(buf.len()));
+                            },
+                            Ok(c) => self.buf_length += c,
+                            Err(ref err) if err.kind() == ErrorKind::Interrupted => (),
+                            Err(err) => return Err(err.into()),
+                        }
+                    }
 
-            temp.push_str(
-                String::from_utf8_lossy(
-                    &self.buf[self.buf_offset..(self.buf_offset + utf8_length)],
-                )
-                .as_ref(),
-            );
+                    let char_str_bytes = &self.buf[self.buf_offset..(self.buf_offset + width)];
 
-            self.buf_left_shift(utf8_length);
+                    match from_utf8(char_str_bytes) {
+                        Ok(char_str) => {
+                            buf.push_str(char_str);
 
-            let size = self.reader.read(&mut self.buf[(self.buf_offset + self.buf_length)..])?;
+                            self.buf_left_shift(width);
+                        },
+                        Err(err) => {
+                            if self.strict_utf8 {
+                                return Err(self.invalid_utf8(err.error_len().unwrap_or(1)));
+                            }
 
-            if size == 0 {
-                return Ok(Some(temp));
+                            self.buf_left_shift(1);
+
+                            buf.push(REPLACEMENT_CHARACTER);
+                        },
+                    }
+                },
             }
 
-            self.buf_length += size;
+            if self.buf_length == 0 {
+                let size = self.reader.read(&mut self.buf[self.buf_offset..])?;
+
+                if size == 0 {
+                    return Ok(Some(buf.len()));
+                }
+
+                self.buf_length += size;
+            }
         }
     }
 
-    /// Read the next data until it reaches a specific boundary without fully validating UTF-8. If there is nothing to read, it will return `Ok(None)`.
+    /// Read the next token separated by the delimiter without fully validating UTF-8. If there is nothing to read, it will return `Ok(None)`.
     ///
     /// ```rust
     /// use scanner_rust::Scanner;
     ///
     /// let mut sc = Scanner::new("123 456\r\n789 \n\n 中文 ".as_bytes());
     ///
-    /// assert_eq!(Some("123".into()), sc.next_until_raw(" ").unwrap());
-    /// assert_eq!(Some("456\r".into()), sc.next_until_raw("\n").unwrap());
-    /// assert_eq!(Some("78".into()), sc.next_until_raw("9 ").unwrap());
-    /// assert_eq!(Some("\n\n 中文 ".into()), sc.next_until_raw("kk").unwrap());
-    /// assert_eq!(None, sc.next().unwrap());
+    /// assert_eq!(Some("123".into()), sc.next_raw().unwrap());
+    /// assert_eq!(Some("456".into()), sc.next_raw().unwrap());
+    /// assert_eq!(Some("789".into()), sc.next_raw().unwrap());
+    /// assert_eq!(Some("中文".into()), sc.next_raw().unwrap());
+    /// assert_eq!(None, sc.next_raw().unwrap());
     /// ```
-    pub fn next_until_raw<D: ?Sized + AsRef<[u8]>>(
-        &mut self,
-        boundary: &D,
-    ) -> Result<Option<Vec<u8>>, ScannerError> {
-        if !self.passing_read()? {
+    pub fn next_raw(&mut self) -> Result<Option<Vec<u8>>, ScannerError> {
+        let mut temp = Vec::new();
+
+        Ok(self.next_raw_into(&mut temp)?.map(|_| temp))
+    }
+
+    /// Read the next token separated by delimiters into `buf` without fully validating UTF-8, just like `next_raw`, so that the allocation of `buf` can be reused. `buf` is cleared first. If there is nothing to read, it will return `Ok(None)`. Otherwise, it will return `Ok(Some(i))`. The `i` is the length of the token.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("123 456\r\n789 \n\n 中文 ".as_bytes());
+    /// let mut token = Vec::new();
+    ///
+    /// assert_eq!(Some(3), sc.next_raw_into(&mut token).unwrap());
+    /// assert_eq!(b"123", token.as_slice());
+    /// assert_eq!(Some(3), sc.next_raw_into(&mut token).unwrap());
+    /// assert_eq!(Some(3), sc.next_raw_into(&mut token).unwrap());
+    /// assert_eq!(b"789", token.as_slice());
+    /// assert_eq!(Some(6), sc.next_raw_into(&mut token).unwrap());
+    /// assert_eq!("中文".as_bytes(), token.as_slice());
+    /// assert_eq!(None, sc.next_raw_into(&mut token).unwrap());
+    /// ```
+    pub fn next_raw_into(&mut self, buf: &mut Vec<u8>) -> Result<Option<usize>, ScannerError> {
+        buf.clear();
+
+        if !self.skip_whitespaces()? {
             return Ok(None);
         }
 
-        let boundary = boundary.as_ref();
-        let boundary_length = boundary.len();
-        let mut temp = Vec::new();
+        if self.buf_length == 0 {
+            let size = self.reader.read(&mut self.buf[self.buf_offset..])?;
 
-        let mut b = 0;
+            if size == 0 {
+                return Ok(None);
+            }
+
+            self.buf_length += size;
+        }
 
         loop {
-            let mut p = 0;
+            if self.delimiter_length()? > 0 {
+                return Ok(Some(buf.len()));
+            }
 
-            while p < self.buf_length {
-                if self.buf[self.buf_offset + p] == boundary[b] {
-                    b += 1;
-                    p += 1;
+            let e = self.buf[self.buf_offset];
 
-                    if b == boundary_length {
-                        match p.cmp(&boundary_length) {
-                            Ordering::Equal => (),
-                            Ordering::Greater => {
-                                temp.extend_from_slice(
-                                    &self.buf
-                                        [self.buf_offset..(self.buf_offset + p - boundary_length)],
+            let width = get_width(e);
+
+            match width {
+                0 => {
+                    self.buf_left_shift(1);
+
+                    buf.push(e);
+                },
+                1 => {
+                    self.buf_left_shift(1);
+
+                    buf.push(e);
+                },
+                _ => {
+                    while self.buf_length < width {
+                        match self.reader.read(&mut self.buf[(self.buf_offset + self.buf_length)..])
+                        {
+                            Ok(0) => {
+                                buf.extend_from_slice(
+                                    &self.buf[self.buf_offset..(self.buf_offset + self.buf_length)],
                                 );
-                            },
-                            Ordering::Less => {
-                                let adjusted_temp_length = temp.len() - (boundary_length - p);
 
-                                unsafe {
-                                    temp.set_len(adjusted_temp_length);
-                                }
+                                self.buf_left_shift(self.buf_length);
+
+                                return Ok(Some(buf.len()));
                             },
+                            Ok(c) => self.buf_length += c,
+                            Err(ref err) if err.kind() == ErrorKind::Interrupted => (),
+                            Err(err) => return Err(err.into()),
                         }
+                    }
 
-                        self.buf_left_shift(p);
+                    let char_str_bytes = &self.buf[self.buf_offset..(self.buf_offset + width)];
 
-                        return Ok(Some(temp));
-                    }
-                } else {
-                    b = 0;
-                    p += 1;
+                    buf.extend_from_slice(char_str_bytes);
+
+                    self.buf_left_shift(width);
+                },
+            }
+
+            if self.buf_length == 0 {
+                let size = self.reader.read(&mut self.buf[self.buf_offset..])?;
+
+                if size == 0 {
+                    return Ok(Some(buf.len()));
                 }
+
+                self.buf_length += size;
             }
+        }
+    }
 
-            let mut utf8_length = 0;
+    /// Read the next token separated by delimiters, and borrow it from the buffer instead of allocating a new string whenever possible. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("123 456\r\n789 \n\n 中文 ".as_bytes());
+    ///
+    /// assert_eq!(Some("123"), sc.next_ref().unwrap());
+    /// assert_eq!(Some("456"), sc.next_ref().unwrap());
+    /// assert_eq!(Some("789"), sc.next_ref().unwrap());
+    /// assert_eq!(Some("中文"), sc.next_ref().unwrap());
+    /// assert_eq!(None, sc.next_ref().unwrap());
+    /// ```
+    pub fn next_ref(&mut self) -> Result<Option<&str>, ScannerError> {
+        if !self.skip_whitespaces()? {
+            return Ok(None);
+        }
 
-            loop {
-                let width = get_width(self.buf[self.buf_offset + utf8_length]).max(1);
+        match self.find_token()? {
+            Some((start, end, true))
+                if from_utf8(&self.buf[(self.buf_offset + start)..(self.buf_offset + end)])
+                    .is_ok() =>
+            {
+                self.buf_left_shift(start);
 
-                utf8_length += width;
+                let token = self.buf_consume_ref(end - start, 0);
 
-                match utf8_length.cmp(&self.buf_length) {
-                    Ordering::Equal => break,
-                    Ordering::Greater => {
-                        utf8_length -= width;
-                        break;
+                // the bytes have just been validated
+                Ok(Some(unsafe { from_utf8_unchecked(token) }))
+            },
+            Some(_) => {
+                // the token is too long or needs to be decoded by `next`
+                match self.next()? {
+                    Some(token) => {
+                        self.spill = token.into_bytes();
+
+                        Ok(Some(unsafe { from_utf8_unchecked(&self.spill) }))
                     },
-                    Ordering::Less => (),
+                    None => Ok(None),
                 }
-            }
+            },
+            None => Ok(None),
+        }
+    }
+
+    /// Read the next token separated by delimiters without validating UTF-8, and borrow it from the buffer instead of allocating a new vector whenever possible. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("123 456\r\n789 \n\n 中文 ".as_bytes());
+    ///
+    /// assert_eq!(Some("123".as_bytes()), sc.next_raw_ref().unwrap());
+    /// assert_eq!(Some("456".as_bytes()), sc.next_raw_ref().unwrap());
+    /// assert_eq!(Some("789".as_bytes()), sc.next_raw_ref().unwrap());
+    /// assert_eq!(Some("中文".as_bytes()), sc.next_raw_ref().unwrap());
+    /// assert_eq!(None, sc.next_raw_ref().unwrap());
+    /// ```
+    pub fn next_raw_ref(&mut self) -> Result<Option<&[u8]>, ScannerError> {
+        if !self.skip_whitespaces()? {
+            return Ok(None);
+        }
 
-            temp.extend_from_slice(&self.buf[self.buf_offset..(self.buf_offset + utf8_length)]);
+        match self.find_token()? {
+            Some((start, end, true)) => {
+                self.buf_left_shift(start);
 
-            self.buf_left_shift(utf8_length);
+                Ok(Some(self.buf_consume_ref(end - start, 0)))
+            },
+            Some(_) => match self.next_raw()? {
+                Some(token) => {
+                    self.spill = token;
 
-            let size = self.reader.read(&mut self.buf[(self.buf_offset + self.buf_length)..])?;
+                    Ok(Some(&self.spill))
+                },
+                None => Ok(None),
+            },
+            None => Ok(None),
+        }
+    }
+
+    /// Drop the next token separated by the delimiter. If there is nothing to read, it will return `Ok(None)`. If there are something to read, it will return `Ok(Some(i))`. The `i` is the length of the dropped line.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("123 456\r\n789 \n\n 中文 ".as_bytes());
+    ///
+    /// assert_eq!(Some(3), sc.drop_next().unwrap());
+    /// assert_eq!(Some("456".into()), sc.next().unwrap());
+    /// assert_eq!(Some(3), sc.drop_next().unwrap());
+    /// assert_eq!(Some("中文".into()), sc.next().unwrap());
+    /// assert_eq!(None, sc.drop_next().unwrap());
+    /// ```
+    pub fn drop_next(&mut self) -> Result<Option<usize>, ScannerError> {
+        if !self.skip_whitespaces()? {
+            return Ok(None);
+        }
+
+        if self.buf_length == 0 {
+            let size = self.reader.read(&mut self.buf[self.buf_offset..])?;
 
             if size == 0 {
-                return Ok(Some(temp));
+                return Ok(None);
             }
 
             self.buf_length += size;
         }
+
+        let mut c = 0;
+
+        loop {
+            if self.delimiter_length()? > 0 {
+                return Ok(Some(c));
+            }
+
+            let e = self.buf[self.buf_offset];
+
+            let width = get_width(e);
+
+            match width {
+                0 => {
+                    self.buf_left_shift(1);
+
+                    c += 1;
+                },
+                1 => {
+                    self.buf_left_shift(1);
+
+                    c += 1;
+                },
+                _ => {
+                    while self.buf_length < width {
+                        match self.reader.read(&mut self.buf[(self.buf_offset + self.buf_length)..])
+                        {
+                            Ok(0) => {
+                                self.buf_left_shift(self.buf_length);
+                                c += self.buf_length;
+
+                                return Ok(Some(c));
+                            },
+                            Ok(c) => self.buf_length += c,
+                            Err(ref err) if err.kind() == ErrorKind::Interrupted => (),
+                            Err(err) => return Err(err.into()),
+                        }
+                    }
+
+                    self.buf_left_shift(width);
+                },
+            }
+
+            if self.buf_length == 0 {
+                let size = self.reader.read(&mut self.buf[self.buf_offset..])?;
+
+                if size == 0 {
+                    return Ok(Some(c));
+                }
+
+                self.buf_length += size;
+            }
+        }
+    }
+}
+
+impl<R: Read, N: BufferSize> Scanner<R, N> {
+    /// Read the next bytes. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("123 456\r\n789 \n\n 中文 ".as_bytes());
+    ///
+    /// assert_eq!(Some("123".into()), sc.next_bytes(3).unwrap());
+    /// assert_eq!(Some(" 456".into()), sc.next_bytes(4).unwrap());
+    /// assert_eq!(Some("\r\n789 ".into()), sc.next_bytes(6).unwrap());
+    /// assert_eq!(Some("中文".into()), sc.next_raw().unwrap());
+    /// assert_eq!(Some(" ".into()), sc.next_bytes(2).unwrap());
+    /// assert_eq!(None, sc.next_bytes(2).unwrap());
+    /// ```
+    pub fn next_bytes(
+        &mut self,
+        max_number_of_bytes: usize,
+    ) -> Result<Option<Vec<u8>>, ScannerError> {
+        let mut temp = Vec::new();
+
+        Ok(self.next_bytes_into(max_number_of_bytes, &mut temp)?.map(|_| temp))
+    }
+
+    /// Read the next bytes into `buf`, just like `next_bytes`, so that the allocation of `buf` can be reused. `buf` is cleared first. If there is nothing to read, it will return `Ok(None)`. Otherwise, it will return `Ok(Some(i))`. The `i` is the number of the bytes which have actually been read.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("123 456\r\n789 \n\n 中文 ".as_bytes());
+    /// let mut bytes = Vec::new();
+    ///
+    /// assert_eq!(Some(3), sc.next_bytes_into(3, &mut bytes).unwrap());
+    /// assert_eq!(b"123", bytes.as_slice());
+    /// assert_eq!(Some(4), sc.next_bytes_into(4, &mut bytes).unwrap());
+    /// assert_eq!(b" 456", bytes.as_slice());
+    /// assert_eq!(Some(6), sc.next_bytes_into(6, &mut bytes).unwrap());
+    /// assert_eq!(Some(10), sc.next_bytes_into(100, &mut bytes).unwrap());
+    /// assert_eq!("\n\n 中文 ".as_bytes(), bytes.as_slice());
+    /// assert_eq!(None, sc.next_bytes_into(2, &mut bytes).unwrap());
+    /// ```
+    pub fn next_bytes_into(
+        &mut self,
+        max_number_of_bytes: usize,
+        buf: &mut Vec<u8>,
+    ) -> Result<Option<usize>, ScannerError> {
+        buf.clear();
+
+        if !self.passing_read()? {
+            return Ok(None);
+        }
+
+        let mut c = 0;
+
+        while c < max_number_of_bytes {
+            if self.buf_length == 0 {
+                let size = self.reader.read(&mut self.buf[self.buf_offset..])?;
+
+                if size == 0 {
+                    return Ok(Some(buf.len()));
+                }
+
+                self.buf_length += size;
+            }
+
+            let dropping_bytes = self.buf_length.min(max_number_of_bytes - c);
+
+            buf.extend_from_slice(&self.buf[self.buf_offset..(self.buf_offset + dropping_bytes)]);
+
+            self.buf_left_shift(dropping_bytes);
+
+            c += dropping_bytes;
+        }
+
+        Ok(Some(buf.len()))
+    }
+
+    /// Drop the next N bytes. If there is nothing to read, it will return `Ok(None)`. If there are something to read, it will return `Ok(Some(i))`. The `i` is the length of the actually dropped bytes.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("123 456\r\n789 \n\n 中文 ".as_bytes());
+    ///
+    /// assert_eq!(Some(7), sc.drop_next_bytes(7).unwrap());
+    /// assert_eq!(Some("".into()), sc.next_line().unwrap());
+    /// assert_eq!(Some("789 ".into()), sc.next_line().unwrap());
+    /// assert_eq!(Some(1), sc.drop_next_bytes(1).unwrap());
+    /// assert_eq!(Some(" 中文 ".into()), sc.next_line().unwrap());
+    /// assert_eq!(None, sc.drop_next_bytes(1).unwrap());
+    /// ```
+    pub fn drop_next_bytes(
+        &mut self,
+        max_number_of_bytes: usize,
+    ) -> Result<Option<usize>, ScannerError> {
+        if !self.passing_read()? {
+            return Ok(None);
+        }
+
+        let mut c = 0;
+
+        while c < max_number_of_bytes {
+            if self.buf_length == 0 {
+                let size = self.reader.read(&mut self.buf[self.buf_offset..])?;
+
+                if size == 0 {
+                    return Ok(Some(c));
+                }
+
+                self.buf_length += size;
+            }
+
+            let dropping_bytes = self.buf_length.min(max_number_of_bytes - c);
+
+            self.buf_left_shift(dropping_bytes);
+
+            c += dropping_bytes;
+        }
+
+        Ok(Some(c))
+    }
+}
+
+impl<R: Read, N: BufferSize> Scanner<R, N> {
+    /// Read the next text until it reaches a specific boundary. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("123 456\r\n789 \n\n 中文 ".as_bytes());
+    ///
+    /// assert_eq!(Some("123".into()), sc.next_until(" ").unwrap());
+    /// assert_eq!(Some("456\r".into()), sc.next_until("\n").unwrap());
+    /// assert_eq!(Some("78".into()), sc.next_until("9 ").unwrap());
+    /// assert_eq!(Some("\n\n 中文 ".into()), sc.next_until("kk").unwrap());
+    /// assert_eq!(None, sc.next().unwrap());
+    /// ```
+    pub fn next_until<S: AsRef<str>>(
+        &mut self,
+        boundary: S,
+    ) -> Result<Option<String>, ScannerError> {
+        let mut temp = String::new();
+
+        Ok(self.next_until_into(boundary, &mut temp)?.map(|_| temp))
+    }
+
+    /// Read the next text until it reaches a specific boundary into `buf`, just like `next_until`, so that the allocation of `buf` can be reused. `buf` is cleared first. If there is nothing to read, it will return `Ok(None)`. Otherwise, it will return `Ok(Some(i))`. The `i` is the length of the text in bytes.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("123 456\r\n789 \n\n 中文 ".as_bytes());
+    /// let mut text = String::new();
+    ///
+    /// assert_eq!(Some(3), sc.next_until_into(" ", &mut text).unwrap());
+    /// assert_eq!("123", text);
+    /// assert_eq!(Some(4), sc.next_until_into("\n", &mut text).unwrap());
+    /// assert_eq!("456\r", text);
+    /// assert_eq!(Some(2), sc.next_until_into("9 ", &mut text).unwrap());
+    /// assert_eq!(Some(10), sc.next_until_into("kk", &mut text).unwrap());
+    /// assert_eq!(None, sc.next_until_into("kk", &mut text).unwrap());
+    /// ```
+    pub fn next_until_into<S: AsRef<str>>(
+        &mut self,
+        boundary: S,
+        buf: &mut String,
+    ) -> Result<Option<usize>, ScannerError> {
+        buf.clear();
+
+        if !self.passing_read()? {
+            return Ok(None);
+        }
+
+        let boundary = boundary.as_ref().as_bytes();
+        let boundary_length = boundary.len();
+
+        let mut b = 0;
+
+        loop {
+            let mut p = 0;
+
+            while p < self.buf_length {
+                if self.buf[self.buf_offset + p] == boundary[b] {
+                    b += 1;
+                    p += 1;
+
+                    if b == boundary_length {
+                        match p.cmp(&boundary_length) {
+                            Ordering::Equal => (),
+                            Ordering::Greater => {
+                                buf.push_str(&self.consume_str(p - boundary_length)?);
+
+                                p = boundary_length;
+                            },
+                            Ordering::Less => {
+                                let adjusted_temp_length = buf.len() - (boundary_length - p);
+
+                                unsafe {
+                                    buf.as_mut_vec().set_len(adjusted_temp_length);
+                                }
+                            },
+                        }
+
+                        self.buf_left_shift(p);
+
+                        return Ok(Some(buf.len()));
+                    }
+                } else {
+                    b = 0;
+                    p += 1;
+                }
+            }
+
+            let mut utf8_length = 0;
+
+            loop {
+                let width = get_width(self.buf[self.buf_offset + utf8_length]).max(1);
+
+                utf8_length += width;
+
+                match utf8_length.cmp(&self.buf_length) {
+                    Ordering::Equal => break,
+                    Ordering::Greater => {
+                        utf8_length -= width;
+                        break;
+                    },
+                    Ordering::Less => (),
+                }
+            }
+
+            buf.push_str(&self.consume_str(utf8_length)?);
+
+            let size = self.reader.read(&mut self.buf[(self.buf_offset + self.buf_length)..])?;
+
+            if size == 0 {
+                // the rest is an incomplete char
+                buf.push_str(&self.consume_str(self.buf_length)?);
+
+                return Ok(Some(buf.len()));
+            }
+
+            self.buf_length += size;
+        }
+    }
+
+    /// Read the next data until it reaches a specific boundary without fully validating UTF-8. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("123 456\r\n789 \n\n 中文 ".as_bytes());
+    ///
+    /// assert_eq!(Some("123".into()), sc.next_until_raw(" ").unwrap());
+    /// assert_eq!(Some("456\r".into()), sc.next_until_raw("\n").unwrap());
+    /// assert_eq!(Some("78".into()), sc.next_until_raw("9 ").unwrap());
+    /// assert_eq!(Some("\n\n 中文 ".into()), sc.next_until_raw("kk").unwrap());
+    /// assert_eq!(None, sc.next().unwrap());
+    /// ```
+    pub fn next_until_raw<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+    ) -> Result<Option<Vec<u8>>, ScannerError> {
+        let mut temp = Vec::new();
+
+        Ok(self.next_until_raw_into(boundary, &mut temp)?.map(|_| temp))
+    }
+
+    /// Read the next data until it reaches a specific boundary into `buf` without fully validating UTF-8, just like `next_until_raw`, so that the allocation of `buf` can be reused. `buf` is cleared first. If there is nothing to read, it will return `Ok(None)`. Otherwise, it will return `Ok(Some(i))`. The `i` is the length of the data.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("123 456\r\n789 \n\n 中文 ".as_bytes());
+    /// let mut data = Vec::new();
+    ///
+    /// assert_eq!(Some(3), sc.next_until_raw_into(" ", &mut data).unwrap());
+    /// assert_eq!(b"123", data.as_slice());
+    /// assert_eq!(Some(4), sc.next_until_raw_into("\n", &mut data).unwrap());
+    /// assert_eq!(Some(2), sc.next_until_raw_into("9 ", &mut data).unwrap());
+    /// assert_eq!(b"78", data.as_slice());
+    /// assert_eq!(Some(10), sc.next_until_raw_into("kk", &mut data).unwrap());
+    /// assert_eq!(None, sc.next_until_raw_into("kk", &mut data).unwrap());
+    /// ```
+    pub fn next_until_raw_into<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+        buf: &mut Vec<u8>,
+    ) -> Result<Option<usize>, ScannerError> {
+        buf.clear();
+
+        if !self.passing_read()? {
+            return Ok(None);
+        }
+
+        let boundary = boundary.as_ref();
+        let boundary_length = boundary.len();
+
+        let mut b = 0;
+
+        loop {
+            let mut p = 0;
+
+            while p < self.buf_length {
+                if self.buf[self.buf_offset + p] == boundary[b] {
+                    b += 1;
+                    p += 1;
+
+                    if b == boundary_length {
+                        match p.cmp(&boundary_length) {
+                            Ordering::Equal => (),
+                            Ordering::Greater => {
+                                buf.extend_from_slice(
+                                    &self.buf
+                                        [self.buf_offset..(self.buf_offset + p - boundary_length)],
+                                );
+                            },
+                            Ordering::Less => {
+                                let adjusted_temp_length = buf.len() - (boundary_length - p);
+
+                                unsafe {
+                                    buf.set_len(adjusted_temp_length);
+                                }
+                            },
+                        }
+
+                        self.buf_left_shift(p);
+
+                        return Ok(Some(buf.len()));
+                    }
+                } else {
+                    b = 0;
+                    p += 1;
+                }
+            }
+
+            let mut utf8_length = 0;
+
+            loop {
+                let width = get_width(self.buf[self.buf_offset + utf8_length]).max(1);
+
+                utf8_length += width;
+
+                match utf8_length.cmp(&self.buf_length) {
+                    Ordering::Equal => break,
+                    Ordering::Greater => {
+                        utf8_length -= width;
+                        break;
+                    },
+                    Ordering::Less => (),
+                }
+            }
+
+            buf.extend_from_slice(&self.buf[self.buf_offset..(self.buf_offset + utf8_length)]);
+
+            self.buf_left_shift(utf8_length);
+
+            let size = self.reader.read(&mut self.buf[(self.buf_offset + self.buf_length)..])?;
+
+            if size == 0 {
+                // the rest is an incomplete char
+                buf.extend_from_slice(
+                    &self.buf[self.buf_offset..(self.buf_offset + self.buf_length)],
+                );
+
+                self.buf_left_shift(self.buf_length);
+
+                return Ok(Some(buf.len()));
+            }
+
+            self.buf_length += size;
+        }
+    }
+
+    /// Drop the next data until it reaches a specific boundary. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("123 456\r\n789 \n\n 中文 ".as_bytes());
+    ///
+    /// assert_eq!(Some(7), sc.drop_next_until("\r\n").unwrap());
+    /// assert_eq!(Some("789 ".into()), sc.next_line().unwrap());
+    /// assert_eq!(Some(0), sc.drop_next_until("\n").unwrap());
+    /// assert_eq!(Some(" 中文 ".into()), sc.next_line().unwrap());
+    /// assert_eq!(None, sc.drop_next_until("").unwrap());
+    /// ```
+    pub fn drop_next_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+    ) -> Result<Option<usize>, ScannerError> {
+        if !self.passing_read()? {
+            return Ok(None);
+        }
+
+        let boundary = boundary.as_ref();
+        let boundary_length = boundary.len();
+        let mut c = 0;
+
+        let mut b = 0;
+
+        loop {
+            let mut p = 0;
+
+            while p < self.buf_length {
+                if self.buf[self.buf_offset + p] == boundary[b] {
+                    b += 1;
+                    p += 1;
+
+                    if b == boundary_length {
+                        match p.cmp(&boundary_length) {
+                            Ordering::Equal => (),
+                            Ordering::Greater => {
+                                c += p - boundary_length;
+                            },
+                            Ordering::Less => {
+                                c -= boundary_length - p;
+                            },
+                        }
+
+                        self.buf_left_shift(p);
+
+                        return Ok(Some(c));
+                    }
+                } else {
+                    b = 0;
+                    p += 1;
+                }
+            }
+
+            let mut utf8_length = 0;
+
+            loop {
+                let width = get_width(self.buf[self.buf_offset + utf8_length]).max(1);
+
+                utf8_length += width;
+
+                match utf8_length.cmp(&self.buf_length) {
+                    Ordering::Equal => break,
+                    Ordering::Greater => {
+                        utf8_length -= width;
+                        break;
+                    },
+                    Ordering::Less => (),
+                }
+            }
+
+            c += utf8_length;
+
+            self.buf_left_shift(utf8_length);
+
+            let size = self.reader.read(&mut self.buf[(self.buf_offset + self.buf_length)..])?;
+
+            if size == 0 {
+                // the rest is an incomplete char
+                c += self.buf_length;
+
+                self.buf_left_shift(self.buf_length);
+
+                return Ok(Some(c));
+            }
+
+            self.buf_length += size;
+        }
+    }
+}
+
+#[cfg(feature = "regex")]
+impl<R: Read, N: BufferSize> Scanner<R, N> {
+    /// Move the buffered data to the front of the buffer and read more data into it until there are at least `length` bytes buffered, a line break is buffered (if `stop_at_line_break` is `true`), or the reader reaches EOF.
+    fn fill_buffer(&mut self, length: usize, stop_at_line_break: bool) -> Result<(), ScannerError> {
+        let length = length.min(self.buf.len());
+
+        if self.buf_length >= length {
+            return Ok(());
+        }
+
+        self.buf_align_to_frond_end();
+
+        let mut checked_length = 0;
+
+        while self.buf_length < length {
+            if stop_at_line_break
+                && self.buf[checked_length..self.buf_length]
+                    .iter()
+                    .any(|&e| e == b'\n' || e == b'\r')
+            {
+                break;
+            }
+
+            checked_length = self.buf_length;
+
+            match self.reader.read(&mut self.buf[self.buf_length..]) {
+                Ok(0) => break,
+                Ok(c) => self.buf_length += c,
+                Err(ref err) if err.kind() == ErrorKind::Interrupted => (),
+                Err(err) => return Err(err.into()),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Skip the next delimiters and read the text matched by a regular expression right after them. If the text there does not match, it will return `Ok(None)` and nothing but the delimiters will be consumed. If there is nothing to read, it will return `Ok(None)` too.
+    ///
+    /// The text is looked up in the buffer after it is filled up to a line break, so a match cannot be longer than the buffer.
+    ///
+    /// ```rust
+    /// use regex::bytes::Regex;
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("  3.14159 ABC123".as_bytes());
+    ///
+    /// let decimal = Regex::new(r"\d+\.\d+").unwrap();
+    /// let code = Regex::new(r"[A-Z]{3}").unwrap();
+    ///
+    /// assert_eq!(Some("3.14159".into()), sc.next_matching(&decimal).unwrap());
+    /// assert_eq!(None, sc.next_matching(&decimal).unwrap());
+    /// assert_eq!(Some("ABC".into()), sc.next_matching(&code).unwrap());
+    /// assert_eq!(Some(123), sc.next_u8().unwrap());
+    /// ```
+    pub fn next_matching(&mut self, regex: &Regex) -> Result<Option<String>, ScannerError> {
+        if !self.skip_whitespaces()? {
+            return Ok(None);
+        }
+
+        self.fill_buffer(self.buf.len(), true)?;
+
+        if self.buf_length == 0 {
+            return Ok(None);
+        }
+
+        self.consume_match(regex, self.buf_length, true)
+    }
+
+    /// Skip the text matched by a regular expression at the current position, ignoring delimiters. If the text there does not match, it will return `Ok(false)` and nothing will be consumed.
+    ///
+    /// The text is looked up in the buffer after it is filled up to a line break, so a match cannot be longer than the buffer.
+    ///
+    /// ```rust
+    /// use regex::bytes::Regex;
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("ID: 42".as_bytes());
+    ///
+    /// let label = Regex::new(r"[A-Z]+:").unwrap();
+    ///
+    /// assert_eq!(true, sc.skip_pattern(&label).unwrap());
+    /// assert_eq!(false, sc.skip_pattern(&label).unwrap());
+    /// assert_eq!(Some(42), sc.next_u8().unwrap());
+    /// ```
+    pub fn skip_pattern(&mut self, regex: &Regex) -> Result<bool, ScannerError> {
+        if !self.passing_read()? {
+            return Ok(false);
+        }
+
+        self.fill_buffer(self.buf.len(), true)?;
+
+        match regex.find(&self.buf[self.buf_offset..(self.buf_offset + self.buf_length)]) {
+            Some(m) if m.start() == 0 => {
+                self.buf_left_shift(m.end());
+
+                Ok(true)
+            },
+            _ => Ok(false),
+        }
+    }
+
+    /// Find the text matched by a regular expression in the rest of the current line, ignoring delimiters. If it is found, the text before it will be skipped and the scanner will be positioned right after it. Otherwise, it will return `Ok(None)` and nothing will be consumed.
+    ///
+    /// Only the part of the line which fits in the buffer is searched.
+    ///
+    /// ```rust
+    /// use regex::bytes::Regex;
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("width=640 height=480\nfoo=bar".as_bytes());
+    ///
+    /// let number = Regex::new(r"\d+").unwrap();
+    ///
+    /// assert_eq!(Some("640".into()), sc.find_in_line(&number).unwrap());
+    /// assert_eq!(Some("480".into()), sc.find_in_line(&number).unwrap());
+    /// assert_eq!(None, sc.find_in_line(&number).unwrap());
+    /// assert_eq!(Some("".into()), sc.next_line().unwrap());
+    /// ```
+    pub fn find_in_line(&mut self, regex: &Regex) -> Result<Option<String>, ScannerError> {
+        if !self.passing_read()? {
+            return Ok(None);
+        }
+
+        self.fill_buffer(self.buf.len(), true)?;
+
+        let line_length = self.buf[self.buf_offset..(self.buf_offset + self.buf_length)]
+            .iter()
+            .position(|&e| e == b'\r' || e == b'\n')
+            .unwrap_or(self.buf_length);
+
+        self.consume_match(regex, line_length, false)
+    }
+
+    /// Find the text matched by a regular expression within the next `horizon` bytes, ignoring delimiters. If `horizon` is `0` or larger than the buffer, the horizon will be the size of the buffer. If it is found, the text before it will be skipped and the scanner will be positioned right after it. Otherwise, it will return `Ok(None)` and nothing will be consumed.
+    ///
+    /// ```rust
+    /// use regex::bytes::Regex;
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("a\nb\nABC".as_bytes());
+    ///
+    /// let code = Regex::new(r"[A-Z]{3}").unwrap();
+    ///
+    /// assert_eq!(None, sc.find_within_horizon(&code, 6).unwrap());
+    /// assert_eq!(Some("ABC".into()), sc.find_within_horizon(&code, 7).unwrap());
+    /// ```
+    pub fn find_within_horizon(
+        &mut self,
+        regex: &Regex,
+        horizon: usize,
+    ) -> Result<Option<String>, ScannerError> {
+        if !self.passing_read()? {
+            return Ok(None);
+        }
+
+        let horizon = if horizon == 0 { self.buf.len() } else { horizon.min(self.buf.len()) };
+
+        self.fill_buffer(horizon, false)?;
+
+        self.consume_match(regex, horizon.min(self.buf_length), false)
+    }
+
+    /// Search the first `length` bytes of the buffer. If `anchored` is `true`, the match has to start at the beginning of the buffer.
+    fn consume_match(
+        &mut self,
+        regex: &Regex,
+        length: usize,
+        anchored: bool,
+    ) -> Result<Option<String>, ScannerError> {
+        let (start, end) = match regex.find(&self.buf[self.buf_offset..(self.buf_offset + length)])
+        {
+            Some(m) if !anchored || m.start() == 0 => (m.start(), m.end()),
+            _ => return Ok(None),
+        };
+
+        self.buf_left_shift(start);
+
+        self.consume_str(end - start).map(Some)
+    }
+}
+
+impl<R: Read, N: BufferSize> Scanner<R, N> {
+    /// Try to fill up the buffer and return the immutable byte slice of the valid buffered data.
+    /// If the `shift` parameter is set to `false`, the guaranteed minimum data length of the result is **32** (if the unread data is long enough), otherwise it is `BUFFER_SIZE`.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("123 456\r\n789 \n\n 中文 ".as_bytes());
+    ///
+    /// assert_eq!("123 456\r\n789 \n\n 中文 ".as_bytes(), sc.peek(false).unwrap());
+    /// ```
+    #[inline]
+    pub fn peek(&mut self, shift: bool) -> Result<&[u8], ScannerError> {
+        if shift {
+            self.buf_align_to_frond_end();
+        }
+
+        loop {
+            let size = self.reader.read(&mut self.buf[(self.buf_offset + self.buf_length)..])?;
+
+            if size == 0 {
+                break;
+            }
+
+            self.buf_length += size;
+        }
+
+        Ok(&self.buf[self.buf_offset..(self.buf_offset + self.buf_length)])
+    }
+
+    /// Call `f` and go back to where the scanner was, so that nothing is consumed. If the consumed bytes are still in the buffer, only the offset of the buffer moves back. Otherwise, they are replayed like `reset` does.
+    fn peek_with<T>(
+        &mut self,
+        f: impl FnOnce(&mut Self) -> Result<T, ScannerError>,
+    ) -> Result<T, ScannerError> {
+        let mark = self.mark.replace(Mark::new(usize::MAX, self.tracker, self.passing_byte));
+        // the buffer may be overwritten while peeking
+        self.history.keep(&self.buf[..self.buf_offset]);
+
+        let history = mem::take(&mut self.history);
+
+        let result = f(self);
+
+        let mut skipped = None;
+
+        if let Some(peek_mark) = self.mark.as_mut() {
+            skipped = peek_mark.skip_passing_byte();
+
+            let consumed = peek_mark.data.len();
+
+            // the bytes before `buf_offset` are the ones consumed since the buffer was aligned
+            if consumed <= self.buf_offset {
+                debug_assert_eq!(
+                    &peek_mark.data[..],
+                    &self.buf[(self.buf_offset - consumed)..self.buf_offset]
+                );
+
+                self.buf_offset -= consumed;
+                self.buf_length += consumed;
+                self.tracker = peek_mark.tracker;
+                self.passing_byte = peek_mark.passing_byte;
+            } else {
+                self.reset();
+            }
+        }
+
+        self.mark = mark;
+        self.history = history;
+
+        // the pending byte dropped while peeking stays consumed
+        if let Some(e) = skipped {
+            self.history.push(e);
+
+            if let Some(mark) = self.mark.as_mut() {
+                if !mark.record(&[e]) {
+                    self.mark = None;
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Read the next char without consuming it. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("中文".as_bytes());
+    ///
+    /// assert_eq!(Some('中'), sc.peek_char().unwrap());
+    /// assert_eq!(Some('中'), sc.next_char().unwrap());
+    /// ```
+    #[inline]
+    pub fn peek_char(&mut self) -> Result<Option<char>, ScannerError> {
+        self.peek_with(Self::next_char)
+    }
+
+    /// Read the next token separated by delimiters without consuming it. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("123 456".as_bytes());
+    ///
+    /// assert_eq!(Some("123".into()), sc.peek_token().unwrap());
+    /// assert_eq!(Some(123), sc.next_u16().unwrap());
+    /// ```
+    #[inline]
+    pub fn peek_token(&mut self) -> Result<Option<String>, ScannerError> {
+        self.peek_with(Self::next)
+    }
+
+    /// Read the next line without consuming it. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("123 456\r\n789".as_bytes());
+    ///
+    /// assert_eq!(Some("123 456".into()), sc.peek_line().unwrap());
+    /// assert_eq!(Some("123".into()), sc.next().unwrap());
+    /// ```
+    #[inline]
+    pub fn peek_line(&mut self) -> Result<Option<String>, ScannerError> {
+        self.peek_with(Self::next_line)
+    }
+
+    /// Read the next bytes without consuming them. Unlike `peek`, the number of bytes is not limited by the size of the buffer. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("123 456".as_bytes());
+    ///
+    /// assert_eq!(Some(b"123 4".to_vec()), sc.peek_bytes(5).unwrap());
+    /// assert_eq!(Some("123".into()), sc.next().unwrap());
+    /// ```
+    #[inline]
+    pub fn peek_bytes(
+        &mut self,
+        max_number_of_bytes: usize,
+    ) -> Result<Option<Vec<u8>>, ScannerError> {
+        self.peek_with(|sc| sc.next_bytes(max_number_of_bytes))
+    }
+}
+
+impl<R: Read, N: BufferSize> Scanner<R, N> {
+    #[inline]
+    fn next_raw_parse<T: FromStr>(&mut self) -> Result<Option<T>, ScannerError>
+    where
+        <T as FromStr>::Err: Error + Send + Sync + 'static, {
+        self.skip_whitespaces()?;
+
+        let position = self.tracker.position();
+
+        let result = self.next_raw()?;
+
+        match result {
+            Some(s) => Ok(Some(parse_number(
+                decode_utf8(&s, position.offset, self.strict_utf8)?,
+                &self.number_format,
+                position,
+            )?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Parse the next token to an integer straight from the buffer. Nothing is allocated unless the token is not a plain integer or it is longer than the buffer, in which case `next_raw_parse` is used instead.
+    fn next_integer<T: Integer>(&mut self) -> Result<Option<T>, ScannerError> {
+        if !self.delimiter.excludes_numbers() {
+            return self.next_parse_radix(10);
+        }
+
+        if !self.skip_whitespaces()? || (self.buf_length == 0 && !self.read_more()?) {
+            return Ok(None);
+        }
+
+        let mut length = 0;
+
+        loop {
+            while length < self.buf_length
+                && matches!(self.buf[self.buf_offset + length], b'0'..=b'9' | b'+' | b'-')
+            {
+                length += 1;
+            }
+
+            if length < self.buf_length {
+                match self.delimiter_length_at(length)? {
+                    Some(0) | None => return self.next_parse_radix(10),
+                    Some(_) => break,
+                }
+            }
+
+            if !self.read_more()? {
+                if self.buf_length == self.buf.len() {
+                    return self.next_parse_radix(10);
+                }
+
+                break;
+            }
+        }
+
+        let position = self.tracker.position();
+        let token = &self.buf[self.buf_offset..(self.buf_offset + length)];
+
+        let result = match T::from_ascii_digits(token) {
+            Some(value) => Ok(value),
+            None => {
+                parse_integer(String::from_utf8_lossy(token), 10, &self.number_format, position)
+            },
+        };
+
+        self.buf_left_shift(length);
+
+        result.map(Some)
+    }
+
+    /// Read the next token separated by the delimiter and parse it to a value of any type which implements `FromStr`. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use std::net::IpAddr;
+    ///
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("true 127.0.0.1".as_bytes());
+    ///
+    /// assert_eq!(Some(true), sc.next_parse().unwrap());
+    /// assert_eq!(Some(IpAddr::from([127, 0, 0, 1])), sc.next_parse().unwrap());
+    /// ```
+    #[inline]
+    pub fn next_parse<T: FromStr>(&mut self) -> Result<Option<T>, ScannerError>
+    where
+        <T as FromStr>::Err: Error + Send + Sync + 'static, {
+        self.skip_whitespaces()?;
+
+        let position = self.tracker.position();
+
+        let result = self.next()?;
+
+        match result {
+            Some(s) => Ok(Some(parse_token(s, position)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Read the next token separated by the delimiter and parse it to a `u8` value. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("1 2".as_bytes());
+    ///
+    /// assert_eq!(Some(1), sc.next_u8().unwrap());
+    /// assert_eq!(Some(2), sc.next_u8().unwrap());
+    /// ```
+    #[inline]
+    pub fn next_u8(&mut self) -> Result<Option<u8>, ScannerError> {
+        self.next_integer()
+    }
+
+    /// Read the next token separated by the delimiter and parse it to a `u16` value. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("1 2".as_bytes());
+    ///
+    /// assert_eq!(Some(1), sc.next_u16().unwrap());
+    /// assert_eq!(Some(2), sc.next_u16().unwrap());
+    /// ```
+    #[inline]
+    pub fn next_u16(&mut self) -> Result<Option<u16>, ScannerError> {
+        self.next_integer()
+    }
+
+    /// Read the next token separated by the delimiter and parse it to a `u32` value. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("1 2".as_bytes());
+    ///
+    /// assert_eq!(Some(1), sc.next_u32().unwrap());
+    /// assert_eq!(Some(2), sc.next_u32().unwrap());
+    /// ```
+    #[inline]
+    pub fn next_u32(&mut self) -> Result<Option<u32>, ScannerError> {
+        self.next_integer()
+    }
+
+    /// Read the next token separated by the delimiter and parse it to a `u64` value. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("1 2".as_bytes());
+    ///
+    /// assert_eq!(Some(1), sc.next_u64().unwrap());
+    /// assert_eq!(Some(2), sc.next_u64().unwrap());
+    /// ```
+    #[inline]
+    pub fn next_u64(&mut self) -> Result<Option<u64>, ScannerError> {
+        self.next_integer()
+    }
+
+    /// Read the next token separated by the delimiter and parse it to a `u128` value. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("1 2".as_bytes());
+    ///
+    /// assert_eq!(Some(1), sc.next_u128().unwrap());
+    /// assert_eq!(Some(2), sc.next_u128().unwrap());
+    /// ```
+    #[inline]
+    pub fn next_u128(&mut self) -> Result<Option<u128>, ScannerError> {
+        self.next_integer()
+    }
+
+    /// Read the next token separated by the delimiter and parse it to a `usize` value. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("1 2".as_bytes());
+    ///
+    /// assert_eq!(Some(1), sc.next_usize().unwrap());
+    /// assert_eq!(Some(2), sc.next_usize().unwrap());
+    /// ```
+    #[inline]
+    pub fn next_usize(&mut self) -> Result<Option<usize>, ScannerError> {
+        self.next_integer()
+    }
+
+    /// Read the next token separated by the delimiter and parse it to a `i8` value. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("1 2".as_bytes());
+    ///
+    /// assert_eq!(Some(1), sc.next_i8().unwrap());
+    /// assert_eq!(Some(2), sc.next_i8().unwrap());
+    /// ```
+    #[inline]
+    pub fn next_i8(&mut self) -> Result<Option<i8>, ScannerError> {
+        self.next_integer()
+    }
+
+    /// Read the next token separated by the delimiter and parse it to a `i16` value. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("1 2".as_bytes());
+    ///
+    /// assert_eq!(Some(1), sc.next_i16().unwrap());
+    /// assert_eq!(Some(2), sc.next_i16().unwrap());
+    /// ```
+    #[inline]
+    pub fn next_i16(&mut self) -> Result<Option<i16>, ScannerError> {
+        self.next_integer()
+    }
+
+    /// Read the next token separated by the delimiter and parse it to a `i32` value. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("1 2".as_bytes());
+    ///
+    /// assert_eq!(Some(1), sc.next_i32().unwrap());
+    /// assert_eq!(Some(2), sc.next_i32().unwrap());
+    /// ```
+    #[inline]
+    pub fn next_i32(&mut self) -> Result<Option<i32>, ScannerError> {
+        self.next_integer()
+    }
+
+    /// Read the next token separated by the delimiter and parse it to a `i64` value. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("1 2".as_bytes());
+    ///
+    /// assert_eq!(Some(1), sc.next_i64().unwrap());
+    /// assert_eq!(Some(2), sc.next_i64().unwrap());
+    /// ```
+    #[inline]
+    pub fn next_i64(&mut self) -> Result<Option<i64>, ScannerError> {
+        self.next_integer()
+    }
+
+    /// Read the next token separated by the delimiter and parse it to a `i128` value. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("1 2".as_bytes());
+    ///
+    /// assert_eq!(Some(1), sc.next_i128().unwrap());
+    /// assert_eq!(Some(2), sc.next_i128().unwrap());
+    /// ```
+    #[inline]
+    pub fn next_i128(&mut self) -> Result<Option<i128>, ScannerError> {
+        self.next_integer()
+    }
+
+    /// Read the next token separated by the delimiter and parse it to a `isize` value. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("1 2".as_bytes());
+    ///
+    /// assert_eq!(Some(1), sc.next_isize().unwrap());
+    /// assert_eq!(Some(2), sc.next_isize().unwrap());
+    /// ```
+    #[inline]
+    pub fn next_isize(&mut self) -> Result<Option<isize>, ScannerError> {
+        self.next_integer()
+    }
+
+    /// Read the next token separated by the delimiter and parse it to a `f32` value. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("1 2.5".as_bytes());
+    ///
+    /// assert_eq!(Some(1.0), sc.next_f32().unwrap());
+    /// assert_eq!(Some(2.5), sc.next_f32().unwrap());
+    /// ```
+    #[inline]
+    pub fn next_f32(&mut self) -> Result<Option<f32>, ScannerError> {
+        self.next_raw_parse()
+    }
+
+    /// Read the next token separated by the delimiter and parse it to a `f64` value. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("1 2.5".as_bytes());
+    ///
+    /// assert_eq!(Some(1.0), sc.next_f64().unwrap());
+    /// assert_eq!(Some(2.5), sc.next_f64().unwrap());
+    /// ```
+    #[inline]
+    pub fn next_f64(&mut self) -> Result<Option<f64>, ScannerError> {
+        self.next_raw_parse()
+    }
+}
+
+impl<R: Read, N: BufferSize> Scanner<R, N> {
+    /// Check whether there is a next token separated by delimiters without consuming it. Nothing is consumed, even if the delimiters before the token do not fit in the buffer.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new(" 123 ".as_bytes());
+    ///
+    /// assert_eq!(true, sc.has_next().unwrap());
+    /// assert_eq!(Some("123".into()), sc.next().unwrap());
+    /// assert_eq!(false, sc.has_next().unwrap());
+    /// ```
+    pub fn has_next(&mut self) -> Result<bool, ScannerError> {
+        self.peek_with(|sc| Ok(sc.skip_whitespaces()? && sc.buf_length > 0))
+    }
+
+    /// Check whether there is a next line, which may be empty, without consuming it.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("123\n".as_bytes());
+    ///
+    /// assert_eq!(true, sc.has_next_line().unwrap());
+    /// assert_eq!(Some("123".into()), sc.next_line().unwrap());
+    /// assert_eq!(false, sc.has_next_line().unwrap());
+    /// ```
+    pub fn has_next_line(&mut self) -> Result<bool, ScannerError> {
+        self.passing_read()
+    }
+
+    /// Check whether the next token separated by delimiters can be parsed to a value of any type which implements `FromStr`, without consuming it. The token can be longer than the buffer.
+    ///
+    /// ```rust
+    /// use std::net::IpAddr;
+    ///
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("127.0.0.1 x".as_bytes());
+    ///
+    /// assert_eq!(true, sc.has_next_parse::<IpAddr>().unwrap());
+    /// assert_eq!(Some(IpAddr::from([127, 0, 0, 1])), sc.next_parse().unwrap());
+    /// assert_eq!(false, sc.has_next_parse::<IpAddr>().unwrap());
+    /// assert_eq!(Some("x".into()), sc.next().unwrap());
+    /// ```
+    pub fn has_next_parse<T: FromStr>(&mut self) -> Result<bool, ScannerError> {
+        self.has_next_with(|_, token| token.parse::<T>().is_ok())
+    }
+
+    /// Read the next token without consuming it and check whether it can be parsed with `f`. An error which is not an I/O error means that it cannot be parsed.
+    fn has_next_with(&mut self, f: impl FnOnce(&Self, &str) -> bool) -> Result<bool, ScannerError> {
+        match self.peek_token() {
+            Ok(Some(token)) => Ok(f(self, &token)),
+            Ok(None) => Ok(false),
+            Err(ScannerError::IOError(err)) => Err(ScannerError::IOError(err)),
+            Err(_) => Ok(false),
+        }
+    }
+
+    /// Check whether the next token can be parsed to a number in the number format, without consuming it.
+    #[inline]
+    fn has_next_number<T: FromStr>(&mut self) -> Result<bool, ScannerError> {
+        self.has_next_with(|sc, token| sc.number_format.normalize(token).parse::<T>().is_ok())
+    }
+
+    /// Check whether the next token can be parsed to an integer in the number format, without consuming it.
+    #[inline]
+    fn has_next_integer<T: Integer>(&mut self) -> Result<bool, ScannerError> {
+        self.has_next_with(|sc, token| {
+            parse_integer::<T, _>(token, 10, &sc.number_format, sc.tracker.position()).is_ok()
+        })
+    }
+
+    /// Check whether the next token separated by delimiters can be parsed to a `u8` value without consuming it.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("12 x".as_bytes());
+    ///
+    /// assert_eq!(true, sc.has_next_u8().unwrap());
+    /// assert_eq!(Some(12), sc.next_u8().unwrap());
+    /// assert_eq!(false, sc.has_next_u8().unwrap());
+    /// ```
+    #[inline]
+    pub fn has_next_u8(&mut self) -> Result<bool, ScannerError> {
+        self.has_next_integer::<u8>()
+    }
+
+    /// Check whether the next token separated by delimiters can be parsed to a `u16` value without consuming it.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("12 x".as_bytes());
+    ///
+    /// assert_eq!(true, sc.has_next_u16().unwrap());
+    /// assert_eq!(Some(12), sc.next_u16().unwrap());
+    /// assert_eq!(false, sc.has_next_u16().unwrap());
+    /// ```
+    #[inline]
+    pub fn has_next_u16(&mut self) -> Result<bool, ScannerError> {
+        self.has_next_integer::<u16>()
+    }
+
+    /// Check whether the next token separated by delimiters can be parsed to a `u32` value without consuming it.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("12 x".as_bytes());
+    ///
+    /// assert_eq!(true, sc.has_next_u32().unwrap());
+    /// assert_eq!(Some(12), sc.next_u32().unwrap());
+    /// assert_eq!(false, sc.has_next_u32().unwrap());
+    /// ```
+    #[inline]
+    pub fn has_next_u32(&mut self) -> Result<bool, ScannerError> {
+        self.has_next_integer::<u32>()
+    }
+
+    /// Check whether the next token separated by delimiters can be parsed to a `u64` value without consuming it.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("12 x".as_bytes());
+    ///
+    /// assert_eq!(true, sc.has_next_u64().unwrap());
+    /// assert_eq!(Some(12), sc.next_u64().unwrap());
+    /// assert_eq!(false, sc.has_next_u64().unwrap());
+    /// ```
+    #[inline]
+    pub fn has_next_u64(&mut self) -> Result<bool, ScannerError> {
+        self.has_next_integer::<u64>()
+    }
+
+    /// Check whether the next token separated by delimiters can be parsed to a `u128` value without consuming it.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("12 x".as_bytes());
+    ///
+    /// assert_eq!(true, sc.has_next_u128().unwrap());
+    /// assert_eq!(Some(12), sc.next_u128().unwrap());
+    /// assert_eq!(false, sc.has_next_u128().unwrap());
+    /// ```
+    #[inline]
+    pub fn has_next_u128(&mut self) -> Result<bool, ScannerError> {
+        self.has_next_integer::<u128>()
+    }
+
+    /// Check whether the next token separated by delimiters can be parsed to a `usize` value without consuming it.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("12 x".as_bytes());
+    ///
+    /// assert_eq!(true, sc.has_next_usize().unwrap());
+    /// assert_eq!(Some(12), sc.next_usize().unwrap());
+    /// assert_eq!(false, sc.has_next_usize().unwrap());
+    /// ```
+    #[inline]
+    pub fn has_next_usize(&mut self) -> Result<bool, ScannerError> {
+        self.has_next_integer::<usize>()
+    }
+
+    /// Check whether the next token separated by delimiters can be parsed to a `i8` value without consuming it.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("12 x".as_bytes());
+    ///
+    /// assert_eq!(true, sc.has_next_i8().unwrap());
+    /// assert_eq!(Some(12), sc.next_i8().unwrap());
+    /// assert_eq!(false, sc.has_next_i8().unwrap());
+    /// ```
+    #[inline]
+    pub fn has_next_i8(&mut self) -> Result<bool, ScannerError> {
+        self.has_next_integer::<i8>()
+    }
+
+    /// Check whether the next token separated by delimiters can be parsed to a `i16` value without consuming it.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("12 x".as_bytes());
+    ///
+    /// assert_eq!(true, sc.has_next_i16().unwrap());
+    /// assert_eq!(Some(12), sc.next_i16().unwrap());
+    /// assert_eq!(false, sc.has_next_i16().unwrap());
+    /// ```
+    #[inline]
+    pub fn has_next_i16(&mut self) -> Result<bool, ScannerError> {
+        self.has_next_integer::<i16>()
+    }
+
+    /// Check whether the next token separated by delimiters can be parsed to a `i32` value without consuming it.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("12 x".as_bytes());
+    ///
+    /// assert_eq!(true, sc.has_next_i32().unwrap());
+    /// assert_eq!(Some(12), sc.next_i32().unwrap());
+    /// assert_eq!(false, sc.has_next_i32().unwrap());
+    /// ```
+    #[inline]
+    pub fn has_next_i32(&mut self) -> Result<bool, ScannerError> {
+        self.has_next_integer::<i32>()
+    }
+
+    /// Check whether the next token separated by delimiters can be parsed to a `i64` value without consuming it.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("12 x".as_bytes());
+    ///
+    /// assert_eq!(true, sc.has_next_i64().unwrap());
+    /// assert_eq!(Some(12), sc.next_i64().unwrap());
+    /// assert_eq!(false, sc.has_next_i64().unwrap());
+    /// ```
+    #[inline]
+    pub fn has_next_i64(&mut self) -> Result<bool, ScannerError> {
+        self.has_next_integer::<i64>()
+    }
+
+    /// Check whether the next token separated by delimiters can be parsed to a `i128` value without consuming it.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("12 x".as_bytes());
+    ///
+    /// assert_eq!(true, sc.has_next_i128().unwrap());
+    /// assert_eq!(Some(12), sc.next_i128().unwrap());
+    /// assert_eq!(false, sc.has_next_i128().unwrap());
+    /// ```
+    #[inline]
+    pub fn has_next_i128(&mut self) -> Result<bool, ScannerError> {
+        self.has_next_integer::<i128>()
+    }
+
+    /// Check whether the next token separated by delimiters can be parsed to a `isize` value without consuming it.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("12 x".as_bytes());
+    ///
+    /// assert_eq!(true, sc.has_next_isize().unwrap());
+    /// assert_eq!(Some(12), sc.next_isize().unwrap());
+    /// assert_eq!(false, sc.has_next_isize().unwrap());
+    /// ```
+    #[inline]
+    pub fn has_next_isize(&mut self) -> Result<bool, ScannerError> {
+        self.has_next_integer::<isize>()
+    }
+
+    /// Check whether the next token separated by delimiters can be parsed to a `f32` value without consuming it.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("1.5 x".as_bytes());
+    ///
+    /// assert_eq!(true, sc.has_next_f32().unwrap());
+    /// assert_eq!(Some(1.5), sc.next_f32().unwrap());
+    /// assert_eq!(false, sc.has_next_f32().unwrap());
+    /// ```
+    #[inline]
+    pub fn has_next_f32(&mut self) -> Result<bool, ScannerError> {
+        self.has_next_number::<f32>()
+    }
+
+    /// Check whether the next token separated by delimiters can be parsed to a `f64` value without consuming it.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("1.5 x".as_bytes());
+    ///
+    /// assert_eq!(true, sc.has_next_f64().unwrap());
+    /// assert_eq!(Some(1.5), sc.next_f64().unwrap());
+    /// assert_eq!(false, sc.has_next_f64().unwrap());
+    /// ```
+    #[inline]
+    pub fn has_next_f64(&mut self) -> Result<bool, ScannerError> {
+        self.has_next_number::<f64>()
+    }
+}
+
+impl<R: Read, N: BufferSize> Scanner<R, N> {
+    #[inline]
+    fn next_until_raw_parse<T: FromStr, D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+    ) -> Result<Option<T>, ScannerError>
+    where
+        <T as FromStr>::Err: Error + Send + Sync + 'static, {
+        let position = self.tracker.position();
+
+        let result = self.next_until_raw(boundary)?;
+
+        match result {
+            Some(s) => Ok(Some(parse_number(
+                decode_utf8(&s, position.offset, self.strict_utf8)?,
+                &self.number_format,
+                position,
+            )?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Read the next text until it reaches a specific boundary and parse it to a value of any type which implements `FromStr`. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use std::net::IpAddr;
+    ///
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("true,127.0.0.1".as_bytes());
+    ///
+    /// assert_eq!(Some(true), sc.next_parse_until(",").unwrap());
+    /// assert_eq!(
+    ///     Some(IpAddr::from([127, 0, 0, 1])),
+    ///     sc.next_parse_until(",").unwrap()
+    /// );
+    /// ```
+    #[inline]
+    pub fn next_parse_until<T: FromStr, D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+    ) -> Result<Option<T>, ScannerError>
+    where
+        <T as FromStr>::Err: Error + Send + Sync + 'static, {
+        let position = self.tracker.position();
+
+        let result = self.next_until_raw(boundary)?;
+
+        match result {
+            Some(s) => Ok(Some(parse_token(
+                decode_utf8(&s, position.offset, self.strict_utf8)?,
+                position,
+            )?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `u8` value. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("1 2".as_bytes());
+    ///
+    /// assert_eq!(Some(1), sc.next_u8_until(" ").unwrap());
+    /// assert_eq!(Some(2), sc.next_u8_until(" ").unwrap());
+    /// ```
+    #[inline]
+    pub fn next_u8_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+    ) -> Result<Option<u8>, ScannerError> {
+        self.next_parse_radix_until(boundary, 10)
+    }
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `u16` value. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("1 2".as_bytes());
+    ///
+    /// assert_eq!(Some(1), sc.next_u16_until(" ").unwrap());
+    /// assert_eq!(Some(2), sc.next_u16_until(" ").unwrap());
+    /// ```
+    #[inline]
+    pub fn next_u16_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+    ) -> Result<Option<u16>, ScannerError> {
+        self.next_parse_radix_until(boundary, 10)
+    }
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `u32` value. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("1 2".as_bytes());
+    ///
+    /// assert_eq!(Some(1), sc.next_u32_until(" ").unwrap());
+    /// assert_eq!(Some(2), sc.next_u32_until(" ").unwrap());
+    /// ```
+    #[inline]
+    pub fn next_u32_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+    ) -> Result<Option<u32>, ScannerError> {
+        self.next_parse_radix_until(boundary, 10)
+    }
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `u64` value. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("1 2".as_bytes());
+    ///
+    /// assert_eq!(Some(1), sc.next_u64_until(" ").unwrap());
+    /// assert_eq!(Some(2), sc.next_u64_until(" ").unwrap());
+    /// ```
+    #[inline]
+    pub fn next_u64_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+    ) -> Result<Option<u64>, ScannerError> {
+        self.next_parse_radix_until(boundary, 10)
+    }
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `u128` value. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("1 2".as_bytes());
+    ///
+    /// assert_eq!(Some(1), sc.next_u128_until(" ").unwrap());
+    /// assert_eq!(Some(2), sc.next_u128_until(" ").unwrap());
+    /// ```
+    #[inline]
+    pub fn next_u128_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+    ) -> Result<Option<u128>, ScannerError> {
+        self.next_parse_radix_until(boundary, 10)
+    }
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `usize` value. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("1 2".as_bytes());
+    ///
+    /// assert_eq!(Some(1), sc.next_usize_until(" ").unwrap());
+    /// assert_eq!(Some(2), sc.next_usize_until(" ").unwrap());
+    /// ```
+    #[inline]
+    pub fn next_usize_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+    ) -> Result<Option<usize>, ScannerError> {
+        self.next_parse_radix_until(boundary, 10)
+    }
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `i8` value. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("1 2".as_bytes());
+    ///
+    /// assert_eq!(Some(1), sc.next_usize_until(" ").unwrap());
+    /// assert_eq!(Some(2), sc.next_usize_until(" ").unwrap());
+    /// ```
+    #[inline]
+    pub fn next_i8_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+    ) -> Result<Option<i8>, ScannerError> {
+        self.next_parse_radix_until(boundary, 10)
+    }
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `i16` value. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("1 2".as_bytes());
+    ///
+    /// assert_eq!(Some(1), sc.next_i16_until(" ").unwrap());
+    /// assert_eq!(Some(2), sc.next_i16_until(" ").unwrap());
+    /// ```
+    #[inline]
+    pub fn next_i16_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+    ) -> Result<Option<i16>, ScannerError> {
+        self.next_parse_radix_until(boundary, 10)
+    }
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `i32` value. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("1 2".as_bytes());
+    ///
+    /// assert_eq!(Some(1), sc.next_i32_until(" ").unwrap());
+    /// assert_eq!(Some(2), sc.next_i32_until(" ").unwrap());
+    /// ```
+    #[inline]
+    pub fn next_i32_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+    ) -> Result<Option<i32>, ScannerError> {
+        self.next_parse_radix_until(boundary, 10)
+    }
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `i64` value. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("1 2".as_bytes());
+    ///
+    /// assert_eq!(Some(1), sc.next_i64_until(" ").unwrap());
+    /// assert_eq!(Some(2), sc.next_i64_until(" ").unwrap());
+    /// ```
+    #[inline]
+    pub fn next_i64_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+    ) -> Result<Option<i64>, ScannerError> {
+        self.next_parse_radix_until(boundary, 10)
+    }
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `i128` value. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("1 2".as_bytes());
+    ///
+    /// assert_eq!(Some(1), sc.next_i128_until(" ").unwrap());
+    /// assert_eq!(Some(2), sc.next_i128_until(" ").unwrap());
+    /// ```
+    #[inline]
+    pub fn next_i128_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+    ) -> Result<Option<i128>, ScannerError> {
+        self.next_parse_radix_until(boundary, 10)
+    }
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `isize` value. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("1 2".as_bytes());
+    ///
+    /// assert_eq!(Some(1), sc.next_isize_until(" ").unwrap());
+    /// assert_eq!(Some(2), sc.next_isize_until(" ").unwrap());
+    /// ```
+    #[inline]
+    pub fn next_isize_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+    ) -> Result<Option<isize>, ScannerError> {
+        self.next_parse_radix_until(boundary, 10)
+    }
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `f32` value. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("1 2.5".as_bytes());
+    ///
+    /// assert_eq!(Some(1.0), sc.next_f32_until(" ").unwrap());
+    /// assert_eq!(Some(2.5), sc.next_f32_until(" ").unwrap());
+    /// ```
+    #[inline]
+    pub fn next_f32_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+    ) -> Result<Option<f32>, ScannerError> {
+        self.next_until_raw_parse(boundary)
     }
 
-    /// Drop the next data until it reaches a specific boundary. If there is nothing to read, it will return `Ok(None)`.
+    /// Read the next text until it reaches a specific boundary and parse it to a `f64` value. If there is nothing to read, it will return `Ok(None)`.
     ///
     /// ```rust
     /// use scanner_rust::Scanner;
     ///
-    /// let mut sc = Scanner::new("123 456\r\n789 \n\n 中文 ".as_bytes());
+    /// let mut sc = Scanner::new("1 2.5".as_bytes());
     ///
-    /// assert_eq!(Some(7), sc.drop_next_until("\r\n").unwrap());
-    /// assert_eq!(Some("789 ".into()), sc.next_line().unwrap());
-    /// assert_eq!(Some(0), sc.drop_next_until("\n").unwrap());
-    /// assert_eq!(Some(" 中文 ".into()), sc.next_line().unwrap());
-    /// assert_eq!(None, sc.drop_next_until("").unwrap());
+    /// assert_eq!(Some(1.0), sc.next_f64_until(" ").unwrap());
+    /// assert_eq!(Some(2.5), sc.next_f64_until(" ").unwrap());
     /// ```
-    pub fn drop_next_until<D: ?Sized + AsRef<[u8]>>(
+    #[inline]
+    pub fn next_f64_until<D: ?Sized + AsRef<[u8]>>(
         &mut self,
         boundary: &D,
-    ) -> Result<Option<usize>, ScannerError> {
-        if !self.passing_read()? {
-            return Ok(None);
-        }
-
-        let boundary = boundary.as_ref();
-        let boundary_length = boundary.len();
-        let mut c = 0;
-
-        let mut b = 0;
-
-        loop {
-            let mut p = 0;
+    ) -> Result<Option<f64>, ScannerError> {
+        self.next_until_raw_parse(boundary)
+    }
+}
 
-            while p < self.buf_length {
-                if self.buf[self.buf_offset + p] == boundary[b] {
-                    b += 1;
-                    p += 1;
+impl<R: Read, N: BufferSize> Scanner<R, N> {
+    #[inline]
+    fn next_parse_radix<T: Integer>(&mut self, radix: u32) -> Result<Option<T>, ScannerError> {
+        check_radix(radix)?;
 
-                    if b == boundary_length {
-                        match p.cmp(&boundary_length) {
-                            Ordering::Equal => (),
-                            Ordering::Greater => {
-                                c += p - boundary_length;
-                            },
-                            Ordering::Less => {
-                                c -= boundary_length - p;
-                            },
-                        }
+        self.skip_whitespaces()?;
 
-                        self.buf_left_shift(p);
+        let position = self.tracker.position();
 
-                        return Ok(Some(c));
-                    }
-                } else {
-                    b = 0;
-                    p += 1;
-                }
-            }
+        let result = self.next_raw()?;
 
-            let mut utf8_length = 0;
+        match result {
+            Some(s) => Ok(Some(parse_integer(
+                decode_utf8(&s, position.offset, self.strict_utf8)?,
+                radix,
+                &self.number_format,
+                position,
+            )?)),
+            None => Ok(None),
+        }
+    }
 
-            loop {
-                let width = get_width(self.buf[self.buf_offset + utf8_length]).max(1);
+    #[inline]
+    fn next_parse_radix_until<T: Integer, D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+        radix: u32,
+    ) -> Result<Option<T>, ScannerError> {
+        check_radix(radix)?;
 
-                utf8_length += width;
+        let position = self.tracker.position();
 
-                match utf8_length.cmp(&self.buf_length) {
-                    Ordering::Equal => break,
-                    Ordering::Greater => {
-                        utf8_length -= width;
-                        break;
-                    },
-                    Ordering::Less => (),
-                }
-            }
+        let result = self.next_until_raw(boundary)?;
 
-            c += utf8_length;
+        match result {
+            Some(s) => Ok(Some(parse_integer(
+                decode_utf8(&s, position.offset, self.strict_utf8)?,
+                radix,
+                &self.number_format,
+                position,
+            )?)),
+            None => Ok(None),
+        }
+    }
 
-            self.buf_left_shift(utf8_length);
+    /// Read the next token separated by the delimiter and parse it to a `u8` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("7f 0b101".as_bytes());
+    ///
+    /// assert_eq!(Some(127), sc.next_u8_radix(16).unwrap());
+    /// assert_eq!(Some(5), sc.next_u8_radix(0).unwrap());
+    /// ```
+    #[inline]
+    pub fn next_u8_radix(&mut self, radix: u32) -> Result<Option<u8>, ScannerError> {
+        self.next_parse_radix(radix)
+    }
 
-            let size = self.reader.read(&mut self.buf[(self.buf_offset + self.buf_length)..])?;
+    /// Read the next token separated by the delimiter and parse it to a `u16` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("7f 0b101".as_bytes());
+    ///
+    /// assert_eq!(Some(127), sc.next_u16_radix(16).unwrap());
+    /// assert_eq!(Some(5), sc.next_u16_radix(0).unwrap());
+    /// ```
+    #[inline]
+    pub fn next_u16_radix(&mut self, radix: u32) -> Result<Option<u16>, ScannerError> {
+        self.next_parse_radix(radix)
+    }
 
-            if size == 0 {
-                return Ok(Some(c));
-            }
+    /// Read the next token separated by the delimiter and parse it to a `u32` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("7f 0b101".as_bytes());
+    ///
+    /// assert_eq!(Some(127), sc.next_u32_radix(16).unwrap());
+    /// assert_eq!(Some(5), sc.next_u32_radix(0).unwrap());
+    /// ```
+    #[inline]
+    pub fn next_u32_radix(&mut self, radix: u32) -> Result<Option<u32>, ScannerError> {
+        self.next_parse_radix(radix)
+    }
 
-            self.buf_length += size;
-        }
+    /// Read the next token separated by the delimiter and parse it to a `u64` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("7f 0b101".as_bytes());
+    ///
+    /// assert_eq!(Some(127), sc.next_u64_radix(16).unwrap());
+    /// assert_eq!(Some(5), sc.next_u64_radix(0).unwrap());
+    /// ```
+    #[inline]
+    pub fn next_u64_radix(&mut self, radix: u32) -> Result<Option<u64>, ScannerError> {
+        self.next_parse_radix(radix)
     }
-}
 
-impl<R: Read, N: ArrayLength + IsGreaterOrEqual<U4, Output = True>> Scanner<R, N> {
-    /// Try to fill up the buffer and return the immutable byte slice of the valid buffered data.
-    /// If the `shift` parameter is set to `false`, the guaranteed minimum data length of the result is **32** (if the unread data is long enough), otherwise it is `BUFFER_SIZE`.
+    /// Read the next token separated by the delimiter and parse it to a `u128` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
     ///
     /// ```rust
     /// use scanner_rust::Scanner;
     ///
-    /// let mut sc = Scanner::new("123 456\r\n789 \n\n 中文 ".as_bytes());
+    /// let mut sc = Scanner::new("7f 0b101".as_bytes());
     ///
-    /// assert_eq!("123 456\r\n789 \n\n 中文 ".as_bytes(), sc.peek(false).unwrap());
+    /// assert_eq!(Some(127), sc.next_u128_radix(16).unwrap());
+    /// assert_eq!(Some(5), sc.next_u128_radix(0).unwrap());
     /// ```
     #[inline]
-    pub fn peek(&mut self, shift: bool) -> Result<&[u8], ScannerError> {
-        if shift {
-            self.buf_align_to_frond_end();
-        }
+    pub fn next_u128_radix(&mut self, radix: u32) -> Result<Option<u128>, ScannerError> {
+        self.next_parse_radix(radix)
+    }
 
-        loop {
-            let size = self.reader.read(&mut self.buf[(self.buf_offset + self.buf_length)..])?;
+    /// Read the next token separated by the delimiter and parse it to a `usize` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("7f 0b101".as_bytes());
+    ///
+    /// assert_eq!(Some(127), sc.next_usize_radix(16).unwrap());
+    /// assert_eq!(Some(5), sc.next_usize_radix(0).unwrap());
+    /// ```
+    #[inline]
+    pub fn next_usize_radix(&mut self, radix: u32) -> Result<Option<usize>, ScannerError> {
+        self.next_parse_radix(radix)
+    }
 
-            if size == 0 {
-                break;
-            }
+    /// Read the next token separated by the delimiter and parse it to a `i8` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("-7f 0b101".as_bytes());
+    ///
+    /// assert_eq!(Some(-127), sc.next_i8_radix(16).unwrap());
+    /// assert_eq!(Some(5), sc.next_i8_radix(0).unwrap());
+    /// ```
+    #[inline]
+    pub fn next_i8_radix(&mut self, radix: u32) -> Result<Option<i8>, ScannerError> {
+        self.next_parse_radix(radix)
+    }
 
-            self.buf_length += size;
-        }
+    /// Read the next token separated by the delimiter and parse it to a `i16` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("-7f 0b101".as_bytes());
+    ///
+    /// assert_eq!(Some(-127), sc.next_i16_radix(16).unwrap());
+    /// assert_eq!(Some(5), sc.next_i16_radix(0).unwrap());
+    /// ```
+    #[inline]
+    pub fn next_i16_radix(&mut self, radix: u32) -> Result<Option<i16>, ScannerError> {
+        self.next_parse_radix(radix)
+    }
 
-        Ok(&self.buf[self.buf_offset..(self.buf_offset + self.buf_length)])
+    /// Read the next token separated by the delimiter and parse it to a `i32` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("-7f 0b101".as_bytes());
+    ///
+    /// assert_eq!(Some(-127), sc.next_i32_radix(16).unwrap());
+    /// assert_eq!(Some(5), sc.next_i32_radix(0).unwrap());
+    /// ```
+    #[inline]
+    pub fn next_i32_radix(&mut self, radix: u32) -> Result<Option<i32>, ScannerError> {
+        self.next_parse_radix(radix)
     }
-}
 
-impl<R: Read, N: ArrayLength + IsGreaterOrEqual<U4, Output = True>> Scanner<R, N> {
+    /// Read the next token separated by the delimiter and parse it to a `i64` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("-7f 0b101".as_bytes());
+    ///
+    /// assert_eq!(Some(-127), sc.next_i64_radix(16).unwrap());
+    /// assert_eq!(Some(5), sc.next_i64_radix(0).unwrap());
+    /// ```
     #[inline]
-    fn next_raw_parse<T: FromStr>(&mut self) -> Result<Option<T>, ScannerError>
-    where
-        ScannerError: From<<T as FromStr>::Err>, {
-        let result = self.next_raw()?;
+    pub fn next_i64_radix(&mut self, radix: u32) -> Result<Option<i64>, ScannerError> {
+        self.next_parse_radix(radix)
+    }
 
-        match result {
-            Some(s) => Ok(Some(unsafe { from_utf8_unchecked(&s) }.parse()?)),
-            None => Ok(None),
-        }
+    /// Read the next token separated by the delimiter and parse it to a `i128` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
+    ///
+    /// ```rust
+    /// use scanner_rust::Scanner;
+    ///
+    /// let mut sc = Scanner::new("-7f 0b101".as_bytes());
+    ///
+    /// assert_eq!(Some(-127), sc.next_i128_radix(16).unwrap());
+    /// assert_eq!(Some(5), sc.next_i128_radix(0).unwrap());
+    /// ```
+    #[inline]
+    pub fn next_i128_radix(&mut self, radix: u32) -> Result<Option<i128>, ScannerError> {
+        self.next_parse_radix(radix)
     }
 
-    /// Read the next token separated by whitespaces and parse it to a `u8` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Read the next token separated by the delimiter and parse it to a `isize` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
     ///
     /// ```rust
     /// use scanner_rust::Scanner;
     ///
-    /// let mut sc = Scanner::new("1 2".as_bytes());
+    /// let mut sc = Scanner::new("-7f 0b101".as_bytes());
     ///
-    /// assert_eq!(Some(1), sc.next_u8().unwrap());
-    /// assert_eq!(Some(2), sc.next_u8().unwrap());
+    /// assert_eq!(Some(-127), sc.next_isize_radix(16).unwrap());
+    /// assert_eq!(Some(5), sc.next_isize_radix(0).unwrap());
     /// ```
     #[inline]
-    pub fn next_u8(&mut self) -> Result<Option<u8>, ScannerError> {
-        self.next_raw_parse()
+    pub fn next_isize_radix(&mut self, radix: u32) -> Result<Option<isize>, ScannerError> {
+        self.next_parse_radix(radix)
     }
 
-    /// Read the next token separated by whitespaces and parse it to a `u16` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Read the next text until it reaches a specific boundary and parse it to a `u8` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
     ///
     /// ```rust
     /// use scanner_rust::Scanner;
     ///
-    /// let mut sc = Scanner::new("1 2".as_bytes());
+    /// let mut sc = Scanner::new("7f,0b101".as_bytes());
     ///
-    /// assert_eq!(Some(1), sc.next_u16().unwrap());
-    /// assert_eq!(Some(2), sc.next_u16().unwrap());
+    /// assert_eq!(Some(127), sc.next_u8_radix_until(",", 16).unwrap());
+    /// assert_eq!(Some(5), sc.next_u8_radix_until(",", 0).unwrap());
     /// ```
     #[inline]
-    pub fn next_u16(&mut self) -> Result<Option<u16>, ScannerError> {
-        self.next_raw_parse()
+    pub fn next_u8_radix_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+        radix: u32,
+    ) -> Result<Option<u8>, ScannerError> {
+        self.next_parse_radix_until(boundary, radix)
     }
 
-    /// Read the next token separated by whitespaces and parse it to a `u32` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Read the next text until it reaches a specific boundary and parse it to a `u16` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
     ///
     /// ```rust
     /// use scanner_rust::Scanner;
     ///
-    /// let mut sc = Scanner::new("1 2".as_bytes());
+    /// let mut sc = Scanner::new("7f,0b101".as_bytes());
     ///
-    /// assert_eq!(Some(1), sc.next_u32().unwrap());
-    /// assert_eq!(Some(2), sc.next_u32().unwrap());
+    /// assert_eq!(Some(127), sc.next_u16_radix_until(",", 16).unwrap());
+    /// assert_eq!(Some(5), sc.next_u16_radix_until(",", 0).unwrap());
     /// ```
     #[inline]
-    pub fn next_u32(&mut self) -> Result<Option<u32>, ScannerError> {
-        self.next_raw_parse()
+    pub fn next_u16_radix_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+        radix: u32,
+    ) -> Result<Option<u16>, ScannerError> {
+        self.next_parse_radix_until(boundary, radix)
     }
 
-    /// Read the next token separated by whitespaces and parse it to a `u64` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Read the next text until it reaches a specific boundary and parse it to a `u32` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
     ///
     /// ```rust
     /// use scanner_rust::Scanner;
     ///
-    /// let mut sc = Scanner::new("1 2".as_bytes());
+    /// let mut sc = Scanner::new("7f,0b101".as_bytes());
     ///
-    /// assert_eq!(Some(1), sc.next_u64().unwrap());
-    /// assert_eq!(Some(2), sc.next_u64().unwrap());
+    /// assert_eq!(Some(127), sc.next_u32_radix_until(",", 16).unwrap());
+    /// assert_eq!(Some(5), sc.next_u32_radix_until(",", 0).unwrap());
     /// ```
     #[inline]
-    pub fn next_u64(&mut self) -> Result<Option<u64>, ScannerError> {
-        self.next_raw_parse()
+    pub fn next_u32_radix_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+        radix: u32,
+    ) -> Result<Option<u32>, ScannerError> {
+        self.next_parse_radix_until(boundary, radix)
     }
 
-    /// Read the next token separated by whitespaces and parse it to a `u128` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Read the next text until it reaches a specific boundary and parse it to a `u64` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
     ///
     /// ```rust
     /// use scanner_rust::Scanner;
     ///
-    /// let mut sc = Scanner::new("1 2".as_bytes());
+    /// let mut sc = Scanner::new("7f,0b101".as_bytes());
     ///
-    /// assert_eq!(Some(1), sc.next_u128().unwrap());
-    /// assert_eq!(Some(2), sc.next_u128().unwrap());
+    /// assert_eq!(Some(127), sc.next_u64_radix_until(",", 16).unwrap());
+    /// assert_eq!(Some(5), sc.next_u64_radix_until(",", 0).unwrap());
     /// ```
     #[inline]
-    pub fn next_u128(&mut self) -> Result<Option<u128>, ScannerError> {
-        self.next_raw_parse()
+    pub fn next_u64_radix_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+        radix: u32,
+    ) -> Result<Option<u64>, ScannerError> {
+        self.next_parse_radix_until(boundary, radix)
     }
 
-    /// Read the next token separated by whitespaces and parse it to a `usize` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Read the next text until it reaches a specific boundary and parse it to a `u128` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
     ///
     /// ```rust
     /// use scanner_rust::Scanner;
     ///
-    /// let mut sc = Scanner::new("1 2".as_bytes());
+    /// let mut sc = Scanner::new("7f,0b101".as_bytes());
     ///
-    /// assert_eq!(Some(1), sc.next_usize().unwrap());
-    /// assert_eq!(Some(2), sc.next_usize().unwrap());
+    /// assert_eq!(Some(127), sc.next_u128_radix_until(",", 16).unwrap());
+    /// assert_eq!(Some(5), sc.next_u128_radix_until(",", 0).unwrap());
     /// ```
     #[inline]
-    pub fn next_usize(&mut self) -> Result<Option<usize>, ScannerError> {
-        self.next_raw_parse()
+    pub fn next_u128_radix_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+        radix: u32,
+    ) -> Result<Option<u128>, ScannerError> {
+        self.next_parse_radix_until(boundary, radix)
     }
 
-    /// Read the next token separated by whitespaces and parse it to a `i8` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Read the next text until it reaches a specific boundary and parse it to a `usize` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
     ///
     /// ```rust
     /// use scanner_rust::Scanner;
     ///
-    /// let mut sc = Scanner::new("1 2".as_bytes());
+    /// let mut sc = Scanner::new("7f,0b101".as_bytes());
     ///
-    /// assert_eq!(Some(1), sc.next_i8().unwrap());
-    /// assert_eq!(Some(2), sc.next_i8().unwrap());
+    /// assert_eq!(Some(127), sc.next_usize_radix_until(",", 16).unwrap());
+    /// assert_eq!(Some(5), sc.next_usize_radix_until(",", 0).unwrap());
     /// ```
     #[inline]
-    pub fn next_i8(&mut self) -> Result<Option<i8>, ScannerError> {
-        self.next_raw_parse()
+    pub fn next_usize_radix_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+        radix: u32,
+    ) -> Result<Option<usize>, ScannerError> {
+        self.next_parse_radix_until(boundary, radix)
     }
 
-    /// Read the next token separated by whitespaces and parse it to a `i16` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Read the next text until it reaches a specific boundary and parse it to a `i8` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
     ///
     /// ```rust
     /// use scanner_rust::Scanner;
     ///
-    /// let mut sc = Scanner::new("1 2".as_bytes());
+    /// let mut sc = Scanner::new("-7f,0b101".as_bytes());
     ///
-    /// assert_eq!(Some(1), sc.next_i16().unwrap());
-    /// assert_eq!(Some(2), sc.next_i16().unwrap());
+    /// assert_eq!(Some(-127), sc.next_i8_radix_until(",", 16).unwrap());
+    /// assert_eq!(Some(5), sc.next_i8_radix_until(",", 0).unwrap());
     /// ```
     #[inline]
-    pub fn next_i16(&mut self) -> Result<Option<i16>, ScannerError> {
-        self.next_raw_parse()
+    pub fn next_i8_radix_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+        radix: u32,
+    ) -> Result<Option<i8>, ScannerError> {
+        self.next_parse_radix_until(boundary, radix)
     }
 
-    /// Read the next token separated by whitespaces and parse it to a `i32` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Read the next text until it reaches a specific boundary and parse it to a `i16` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
     ///
     /// ```rust
     /// use scanner_rust::Scanner;
     ///
-    /// let mut sc = Scanner::new("1 2".as_bytes());
+    /// let mut sc = Scanner::new("-7f,0b101".as_bytes());
     ///
-    /// assert_eq!(Some(1), sc.next_i32().unwrap());
-    /// assert_eq!(Some(2), sc.next_i32().unwrap());
+    /// assert_eq!(Some(-127), sc.next_i16_radix_until(",", 16).unwrap());
+    /// assert_eq!(Some(5), sc.next_i16_radix_until(",", 0).unwrap());
     /// ```
     #[inline]
-    pub fn next_i32(&mut self) -> Result<Option<i32>, ScannerError> {
-        self.next_raw_parse()
+    pub fn next_i16_radix_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+        radix: u32,
+    ) -> Result<Option<i16>, ScannerError> {
+        self.next_parse_radix_until(boundary, radix)
     }
 
-    /// Read the next token separated by whitespaces and parse it to a `i64` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Read the next text until it reaches a specific boundary and parse it to a `i32` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
     ///
     /// ```rust
     /// use scanner_rust::Scanner;
     ///
-    /// let mut sc = Scanner::new("1 2".as_bytes());
+    /// let mut sc = Scanner::new("-7f,0b101".as_bytes());
     ///
-    /// assert_eq!(Some(1), sc.next_i64().unwrap());
-    /// assert_eq!(Some(2), sc.next_i64().unwrap());
+    /// assert_eq!(Some(-127), sc.next_i32_radix_until(",", 16).unwrap());
+    /// assert_eq!(Some(5), sc.next_i32_radix_until(",", 0).unwrap());
     /// ```
     #[inline]
-    pub fn next_i64(&mut self) -> Result<Option<i64>, ScannerError> {
-        self.next_raw_parse()
+    pub fn next_i32_radix_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+        radix: u32,
+    ) -> Result<Option<i32>, ScannerError> {
+        self.next_parse_radix_until(boundary, radix)
     }
 
-    /// Read the next token separated by whitespaces and parse it to a `i128` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Read the next text until it reaches a specific boundary and parse it to a `i64` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
     ///
     /// ```rust
     /// use scanner_rust::Scanner;
     ///
-    /// let mut sc = Scanner::new("1 2".as_bytes());
+    /// let mut sc = Scanner::new("-7f,0b101".as_bytes());
     ///
-    /// assert_eq!(Some(1), sc.next_i128().unwrap());
-    /// assert_eq!(Some(2), sc.next_i128().unwrap());
+    /// assert_eq!(Some(-127), sc.next_i64_radix_until(",", 16).unwrap());
+    /// assert_eq!(Some(5), sc.next_i64_radix_until(",", 0).unwrap());
     /// ```
     #[inline]
-    pub fn next_i128(&mut self) -> Result<Option<i128>, ScannerError> {
-        self.next_raw_parse()
+    pub fn next_i64_radix_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+        radix: u32,
+    ) -> Result<Option<i64>, ScannerError> {
+        self.next_parse_radix_until(boundary, radix)
     }
 
-    /// Read the next token separated by whitespaces and parse it to a `isize` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Read the next text until it reaches a specific boundary and parse it to a `i128` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
     ///
     /// ```rust
     /// use scanner_rust::Scanner;
     ///
-    /// let mut sc = Scanner::new("1 2".as_bytes());
+    /// let mut sc = Scanner::new("-7f,0b101".as_bytes());
     ///
-    /// assert_eq!(Some(1), sc.next_isize().unwrap());
-    /// assert_eq!(Some(2), sc.next_isize().unwrap());
+    /// assert_eq!(Some(-127), sc.next_i128_radix_until(",", 16).unwrap());
+    /// assert_eq!(Some(5), sc.next_i128_radix_until(",", 0).unwrap());
     /// ```
     #[inline]
-    pub fn next_isize(&mut self) -> Result<Option<isize>, ScannerError> {
-        self.next_raw_parse()
+    pub fn next_i128_radix_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+        radix: u32,
+    ) -> Result<Option<i128>, ScannerError> {
+        self.next_parse_radix_until(boundary, radix)
     }
 
-    /// Read the next token separated by whitespaces and parse it to a `f32` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Read the next text until it reaches a specific boundary and parse it to a `isize` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
     ///
     /// ```rust
     /// use scanner_rust::Scanner;
     ///
-    /// let mut sc = Scanner::new("1 2.5".as_bytes());
+    /// let mut sc = Scanner::new("-7f,0b101".as_bytes());
     ///
-    /// assert_eq!(Some(1.0), sc.next_f32().unwrap());
-    /// assert_eq!(Some(2.5), sc.next_f32().unwrap());
+    /// assert_eq!(Some(-127), sc.next_isize_radix_until(",", 16).unwrap());
+    /// assert_eq!(Some(5), sc.next_isize_radix_until(",", 0).unwrap());
     /// ```
     #[inline]
-    pub fn next_f32(&mut self) -> Result<Option<f32>, ScannerError> {
-        self.next_raw_parse()
+    pub fn next_isize_radix_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+        radix: u32,
+    ) -> Result<Option<isize>, ScannerError> {
+        self.next_parse_radix_until(boundary, radix)
     }
+}
 
-    /// Read the next token separated by whitespaces and parse it to a `f64` value. If there is nothing to read, it will return `Ok(None)`.
+impl<R: Read, N: BufferSize> Scanner<R, N> {
+    /// Read the next token separated by the delimiter and parse it to a `bool` value with the boolean vocabulary, which only has `true` and `false` by default. If the token is not in the vocabulary, it will return a `ParseBoolError` error. If there is nothing to read, it will return `Ok(None)`.
     ///
     /// ```rust
-    /// use scanner_rust::Scanner;
+    /// use scanner_rust::{BoolVocabulary, Scanner};
     ///
-    /// let mut sc = Scanner::new("1 2.5".as_bytes());
+    /// let mut sc = Scanner::new("true Off".as_bytes());
     ///
-    /// assert_eq!(Some(1.0), sc.next_f64().unwrap());
-    /// assert_eq!(Some(2.5), sc.next_f64().unwrap());
+    /// assert_eq!(Some(true), sc.next_bool().unwrap());
+    ///
+    /// sc.set_bool_vocabulary(BoolVocabulary::lenient());
+    ///
+    /// assert_eq!(Some(false), sc.next_bool().unwrap());
     /// ```
     #[inline]
-    pub fn next_f64(&mut self) -> Result<Option<f64>, ScannerError> {
-        self.next_raw_parse()
-    }
-}
+    pub fn next_bool(&mut self) -> Result<Option<bool>, ScannerError> {
+        self.skip_whitespaces()?;
 
-impl<R: Read, N: ArrayLength + IsGreaterOrEqual<U4, Output = True>> Scanner<R, N> {
-    #[inline]
-    fn next_until_raw_parse<T: FromStr, D: ?Sized + AsRef<[u8]>>(
-        &mut self,
-        boundary: &D,
-    ) -> Result<Option<T>, ScannerError>
-    where
-        ScannerError: From<<T as FromStr>::Err>, {
-        let result = self.next_until_raw(boundary)?;
+        let position = self.tracker.position();
+
+        let result = self.next_raw()?;
 
         match result {
-            Some(s) => Ok(Some(unsafe { from_utf8_unchecked(&s) }.parse()?)),
+            Some(s) => Ok(Some(parse_bool(
+                decode_utf8(&s, position.offset, self.strict_utf8)?,
+                &self.bool_vocabulary,
+                position,
+            )?)),
             None => Ok(None),
         }
     }
 
-    /// Read the next text until it reaches a specific boundary and parse it to a `u8` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Read the next text until it reaches a specific boundary and parse it to a `bool` value with the boolean vocabulary, which only has `true` and `false` by default. If the text is not in the vocabulary, it will return a `ParseBoolError` error. If there is nothing to read, it will return `Ok(None)`.
     ///
     /// ```rust
-    /// use scanner_rust::Scanner;
+    /// use scanner_rust::{BoolVocabulary, Scanner};
     ///
-    /// let mut sc = Scanner::new("1 2".as_bytes());
+    /// let mut sc = Scanner::new("false,y".as_bytes());
     ///
-    /// assert_eq!(Some(1), sc.next_u8_until(" ").unwrap());
-    /// assert_eq!(Some(2), sc.next_u8_until(" ").unwrap());
+    /// assert_eq!(Some(false), sc.next_bool_until(",").unwrap());
+    ///
+    /// sc.set_bool_vocabulary(BoolVocabulary::lenient());
+    ///
+    /// assert_eq!(Some(true), sc.next_bool_until(",").unwrap());
     /// ```
     #[inline]
-    pub fn next_u8_until<D: ?Sized + AsRef<[u8]>>(
+    pub fn next_bool_until<D: ?Sized + AsRef<[u8]>>(
         &mut self,
         boundary: &D,
-    ) -> Result<Option<u8>, ScannerError> {
-        self.next_until_raw_parse(boundary)
+    ) -> Result<Option<bool>, ScannerError> {
+        let position = self.tracker.position();
+
+        let result = self.next_until_raw(boundary)?;
+
+        match result {
+            Some(s) => Ok(Some(parse_bool(
+                decode_utf8(&s, position.offset, self.strict_utf8)?,
+                &self.bool_vocabulary,
+                position,
+            )?)),
+            None => Ok(None),
+        }
     }
+}
 
-    /// Read the next text until it reaches a specific boundary and parse it to a `u16` value. If there is nothing to read, it will return `Ok(None)`.
+#[cfg(feature = "num-bigint")]
+impl<R: Read, N: BufferSize> Scanner<R, N> {
+    /// Read the next token separated by the delimiter and parse it to a `BigInt` value without losing precision. If there is nothing to read, it will return `Ok(None)`.
     ///
     /// ```rust
+    /// use num_bigint::BigInt;
     /// use scanner_rust::Scanner;
     ///
-    /// let mut sc = Scanner::new("1 2".as_bytes());
+    /// let mut sc =
+    ///     Scanner::new("-340282366920938463463374607431768211456 2".as_bytes());
     ///
-    /// assert_eq!(Some(1), sc.next_u16_until(" ").unwrap());
-    /// assert_eq!(Some(2), sc.next_u16_until(" ").unwrap());
+    /// assert_eq!(
+    ///     Some(-(BigInt::from(u128::MAX) + 1u8)),
+    ///     sc.next_bigint().unwrap()
+    /// );
+    /// assert_eq!(Some(BigInt::from(2)), sc.next_bigint().unwrap());
     /// ```
     #[inline]
-    pub fn next_u16_until<D: ?Sized + AsRef<[u8]>>(
-        &mut self,
-        boundary: &D,
-    ) -> Result<Option<u16>, ScannerError> {
-        self.next_until_raw_parse(boundary)
+    pub fn next_bigint(&mut self) -> Result<Option<BigInt>, ScannerError> {
+        self.next_raw_parse()
     }
 
-    /// Read the next text until it reaches a specific boundary and parse it to a `u32` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Read the next token separated by the delimiter and parse it to a `BigUint` value without losing precision. If there is nothing to read, it will return `Ok(None)`.
     ///
     /// ```rust
+    /// use num_bigint::BigUint;
     /// use scanner_rust::Scanner;
     ///
-    /// let mut sc = Scanner::new("1 2".as_bytes());
+    /// let mut sc =
+    ///     Scanner::new("340282366920938463463374607431768211456 2".as_bytes());
     ///
-    /// assert_eq!(Some(1), sc.next_u32_until(" ").unwrap());
-    /// assert_eq!(Some(2), sc.next_u32_until(" ").unwrap());
+    /// assert_eq!(
+    ///     Some(BigUint::from(u128::MAX) + 1u8),
+    ///     sc.next_biguint().unwrap()
+    /// );
+    /// assert_eq!(Some(BigUint::from(2u8)), sc.next_biguint().unwrap());
     /// ```
     #[inline]
-    pub fn next_u32_until<D: ?Sized + AsRef<[u8]>>(
-        &mut self,
-        boundary: &D,
-    ) -> Result<Option<u32>, ScannerError> {
-        self.next_until_raw_parse(boundary)
+    pub fn next_biguint(&mut self) -> Result<Option<BigUint>, ScannerError> {
+        self.next_raw_parse()
     }
 
-    /// Read the next text until it reaches a specific boundary and parse it to a `u64` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Read the next text until it reaches a specific boundary and parse it to a `BigInt` value without losing precision. If there is nothing to read, it will return `Ok(None)`.
     ///
     /// ```rust
+    /// use num_bigint::BigInt;
     /// use scanner_rust::Scanner;
     ///
-    /// let mut sc = Scanner::new("1 2".as_bytes());
+    /// let mut sc =
+    ///     Scanner::new("-340282366920938463463374607431768211456,2".as_bytes());
     ///
-    /// assert_eq!(Some(1), sc.next_u64_until(" ").unwrap());
-    /// assert_eq!(Some(2), sc.next_u64_until(" ").unwrap());
+    /// assert_eq!(
+    ///     Some(-(BigInt::from(u128::MAX) + 1u8)),
+    ///     sc.next_bigint_until(",").unwrap()
+    /// );
+    /// assert_eq!(Some(BigInt::from(2)), sc.next_bigint_until(",").unwrap());
     /// ```
     #[inline]
-    pub fn next_u64_until<D: ?Sized + AsRef<[u8]>>(
+    pub fn next_bigint_until<D: ?Sized + AsRef<[u8]>>(
         &mut self,
         boundary: &D,
-    ) -> Result<Option<u64>, ScannerError> {
+    ) -> Result<Option<BigInt>, ScannerError> {
         self.next_until_raw_parse(boundary)
     }
 
-    /// Read the next text until it reaches a specific boundary and parse it to a `u128` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Read the next text until it reaches a specific boundary and parse it to a `BigUint` value without losing precision. If there is nothing to read, it will return `Ok(None)`.
     ///
     /// ```rust
+    /// use num_bigint::BigUint;
     /// use scanner_rust::Scanner;
     ///
-    /// let mut sc = Scanner::new("1 2".as_bytes());
+    /// let mut sc =
+    ///     Scanner::new("340282366920938463463374607431768211456,2".as_bytes());
     ///
-    /// assert_eq!(Some(1), sc.next_u128_until(" ").unwrap());
-    /// assert_eq!(Some(2), sc.next_u128_until(" ").unwrap());
+    /// assert_eq!(
+    ///     Some(BigUint::from(u128::MAX) + 1u8),
+    ///     sc.next_biguint_until(",").unwrap()
+    /// );
+    /// assert_eq!(Some(BigUint::from(2u8)), sc.next_biguint_until(",").unwrap());
     /// ```
     #[inline]
-    pub fn next_u128_until<D: ?Sized + AsRef<[u8]>>(
+    pub fn next_biguint_until<D: ?Sized + AsRef<[u8]>>(
         &mut self,
         boundary: &D,
-    ) -> Result<Option<u128>, ScannerError> {
+    ) -> Result<Option<BigUint>, ScannerError> {
         self.next_until_raw_parse(boundary)
     }
+}
 
-    /// Read the next text until it reaches a specific boundary and parse it to a `usize` value. If there is nothing to read, it will return `Ok(None)`.
+#[cfg(feature = "rust_decimal")]
+impl<R: Read, N: BufferSize> Scanner<R, N> {
+    /// Read the next token separated by the delimiter and parse it to a `Decimal` value without going through a floating-point number, so that no precision is lost. If there is nothing to read, it will return `Ok(None)`.
     ///
     /// ```rust
+    /// use rust_decimal::Decimal;
     /// use scanner_rust::Scanner;
     ///
-    /// let mut sc = Scanner::new("1 2".as_bytes());
+    /// let mut sc = Scanner::new("12345678901234567890.12 0.1".as_bytes());
     ///
-    /// assert_eq!(Some(1), sc.next_usize_until(" ").unwrap());
-    /// assert_eq!(Some(2), sc.next_usize_until(" ").unwrap());
+    /// assert_eq!(
+    ///     Some(Decimal::from_i128_with_scale(1234567890123456789012, 2)),
+    ///     sc.next_decimal().unwrap()
+    /// );
+    /// assert_eq!(Some(Decimal::new(1, 1)), sc.next_decimal().unwrap());
     /// ```
     #[inline]
-    pub fn next_usize_until<D: ?Sized + AsRef<[u8]>>(
-        &mut self,
-        boundary: &D,
-    ) -> Result<Option<usize>, ScannerError> {
-        self.next_until_raw_parse(boundary)
+    pub fn next_decimal(&mut self) -> Result<Option<Decimal>, ScannerError> {
+        self.next_raw_parse()
     }
 
-    /// Read the next text until it reaches a specific boundary and parse it to a `i8` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Read the next text until it reaches a specific boundary and parse it to a `Decimal` value without going through a floating-point number, so that no precision is lost. If there is nothing to read, it will return `Ok(None)`.
     ///
     /// ```rust
+    /// use rust_decimal::Decimal;
     /// use scanner_rust::Scanner;
     ///
-    /// let mut sc = Scanner::new("1 2".as_bytes());
+    /// let mut sc = Scanner::new("12345678901234567890.12,0.1".as_bytes());
     ///
-    /// assert_eq!(Some(1), sc.next_usize_until(" ").unwrap());
-    /// assert_eq!(Some(2), sc.next_usize_until(" ").unwrap());
+    /// assert_eq!(
+    ///     Some(Decimal::from_i128_with_scale(1234567890123456789012, 2)),
+    ///     sc.next_decimal_until(",").unwrap()
+    /// );
+    /// assert_eq!(Some(Decimal::new(1, 1)), sc.next_decimal_until(",").unwrap());
     /// ```
     #[inline]
-    pub fn next_i8_until<D: ?Sized + AsRef<[u8]>>(
+    pub fn next_decimal_until<D: ?Sized + AsRef<[u8]>>(
         &mut self,
         boundary: &D,
-    ) -> Result<Option<i8>, ScannerError> {
+    ) -> Result<Option<Decimal>, ScannerError> {
         self.next_until_raw_parse(boundary)
     }
+}
 
-    /// Read the next text until it reaches a specific boundary and parse it to a `i16` value. If there is nothing to read, it will return `Ok(None)`.
+impl<R: Read, N: BufferSize> Scanner<R, N> {
+    /// Read the next token separated by delimiters, or a quoted string which can contain delimiters. A quoted string starts and ends with the same `"` or `'`, and its value is returned without the quotes. Inside it, a doubled quote stands for the quote itself, and the escape sequences `\n`, `\t`, `\r`, `\0`, `\\`, `\'`, `\"` and `\u{...}` are supported. If the data ends before the closing quote, it will return an `UnterminatedQuote` error. If there is nothing to read, it will return `Ok(None)`.
     ///
     /// ```rust
     /// use scanner_rust::Scanner;
     ///
-    /// let mut sc = Scanner::new("1 2".as_bytes());
+    /// let mut sc =
+    ///     Scanner::new(r#"name "John Smith" 42 'it''s' "a\tb\u{41}""#.as_bytes());
     ///
-    /// assert_eq!(Some(1), sc.next_i16_until(" ").unwrap());
-    /// assert_eq!(Some(2), sc.next_i16_until(" ").unwrap());
+    /// assert_eq!(Some("name".into()), sc.next_quoted().unwrap());
+    /// assert_eq!(Some("John Smith".into()), sc.next_quoted().unwrap());
+    /// assert_eq!(Some(42), sc.next_u8().unwrap());
+    /// assert_eq!(Some("it's".into()), sc.next_quoted().unwrap());
+    /// assert_eq!(Some("a\tbA".into()), sc.next_quoted().unwrap());
+    /// assert_eq!(None, sc.next_quoted().unwrap());
     /// ```
     #[inline]
-    pub fn next_i16_until<D: ?Sized + AsRef<[u8]>>(
-        &mut self,
-        boundary: &D,
-    ) -> Result<Option<i16>, ScannerError> {
-        self.next_until_raw_parse(boundary)
+    pub fn next_quoted(&mut self) -> Result<Option<String>, ScannerError> {
+        next_quoted(self)
     }
+}
 
-    /// Read the next text until it reaches a specific boundary and parse it to a `i32` value. If there is nothing to read, it will return `Ok(None)`.
+impl<R: Read, N: BufferSize> Scanner<R, N> {
+    /// Get an iterator over the next tokens separated by delimiters. Unlike iterating the scanner itself, an error is yielded instead of ending the iteration silently.
     ///
     /// ```rust
     /// use scanner_rust::Scanner;
     ///
-    /// let mut sc = Scanner::new("1 2".as_bytes());
+    /// let mut sc = Scanner::new("123 456\n789".as_bytes());
     ///
-    /// assert_eq!(Some(1), sc.next_i32_until(" ").unwrap());
-    /// assert_eq!(Some(2), sc.next_i32_until(" ").unwrap());
+    /// let tokens = sc.tokens().collect::<Result<Vec<_>, _>>().unwrap();
+    ///
+    /// assert_eq!(vec!["123", "456", "789"], tokens);
     /// ```
     #[inline]
-    pub fn next_i32_until<D: ?Sized + AsRef<[u8]>>(
-        &mut self,
-        boundary: &D,
-    ) -> Result<Option<i32>, ScannerError> {
-        self.next_until_raw_parse(boundary)
+    pub fn tokens(&mut self) -> Tokens<'_, Self> {
+        Tokens::new(self)
     }
 
-    /// Read the next text until it reaches a specific boundary and parse it to a `i64` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Get an iterator over the next tokens or quoted strings read by `next_quoted`.
     ///
     /// ```rust
     /// use scanner_rust::Scanner;
     ///
-    /// let mut sc = Scanner::new("1 2".as_bytes());
+    /// let mut sc = Scanner::new(r#"a "b c" d"#.as_bytes());
     ///
-    /// assert_eq!(Some(1), sc.next_i64_until(" ").unwrap());
-    /// assert_eq!(Some(2), sc.next_i64_until(" ").unwrap());
+    /// let tokens = sc.quoted_tokens().collect::<Result<Vec<_>, _>>().unwrap();
+    ///
+    /// assert_eq!(vec!["a", "b c", "d"], tokens);
     /// ```
     #[inline]
-    pub fn next_i64_until<D: ?Sized + AsRef<[u8]>>(
-        &mut self,
-        boundary: &D,
-    ) -> Result<Option<i64>, ScannerError> {
-        self.next_until_raw_parse(boundary)
+    pub fn quoted_tokens(&mut self) -> QuotedTokens<'_, Self> {
+        QuotedTokens::new(self)
     }
 
-    /// Read the next text until it reaches a specific boundary and parse it to a `i128` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Get an iterator over the next lines.
     ///
     /// ```rust
     /// use scanner_rust::Scanner;
     ///
-    /// let mut sc = Scanner::new("1 2".as_bytes());
+    /// let mut sc = Scanner::new("123 456\r\n\n789".as_bytes());
     ///
-    /// assert_eq!(Some(1), sc.next_i128_until(" ").unwrap());
-    /// assert_eq!(Some(2), sc.next_i128_until(" ").unwrap());
+    /// let lines = sc.lines().collect::<Result<Vec<_>, _>>().unwrap();
+    ///
+    /// assert_eq!(vec!["123 456", "", "789"], lines);
     /// ```
     #[inline]
-    pub fn next_i128_until<D: ?Sized + AsRef<[u8]>>(
-        &mut self,
-        boundary: &D,
-    ) -> Result<Option<i128>, ScannerError> {
-        self.next_until_raw_parse(boundary)
+    pub fn lines(&mut self) -> Lines<'_, Self> {
+        Lines::new(self)
     }
 
-    /// Read the next text until it reaches a specific boundary and parse it to a `isize` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Get an iterator over the next chars.
     ///
     /// ```rust
     /// use scanner_rust::Scanner;
     ///
-    /// let mut sc = Scanner::new("1 2".as_bytes());
+    /// let mut sc = Scanner::new("ab 中文".as_bytes());
     ///
-    /// assert_eq!(Some(1), sc.next_isize_until(" ").unwrap());
-    /// assert_eq!(Some(2), sc.next_isize_until(" ").unwrap());
+    /// assert_eq!("ab 中文", sc.chars().collect::<Result<String, _>>().unwrap());
     /// ```
     #[inline]
-    pub fn next_isize_until<D: ?Sized + AsRef<[u8]>>(
-        &mut self,
-        boundary: &D,
-    ) -> Result<Option<isize>, ScannerError> {
-        self.next_until_raw_parse(boundary)
+    pub fn chars(&mut self) -> Chars<'_, Self> {
+        Chars::new(self)
     }
 
-    /// Read the next text until it reaches a specific boundary and parse it to a `f32` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Get an iterator which parses the next tokens separated by delimiters to values of any type which implements `FromStr`. A token which cannot be parsed is yielded as an error and skipped.
     ///
     /// ```rust
     /// use scanner_rust::Scanner;
     ///
-    /// let mut sc = Scanner::new("1 2.5".as_bytes());
+    /// let mut sc = Scanner::new("1 2 x 4".as_bytes());
     ///
-    /// assert_eq!(Some(1.0), sc.next_f32_until(" ").unwrap());
-    /// assert_eq!(Some(2.5), sc.next_f32_until(" ").unwrap());
-    /// ```
-    #[inline]
-    pub fn next_f32_until<D: ?Sized + AsRef<[u8]>>(
-        &mut self,
-        boundary: &D,
-    ) -> Result<Option<f32>, ScannerError> {
-        self.next_until_raw_parse(boundary)
-    }
-
-    /// Read the next text until it reaches a specific boundary and parse it to a `f64` value. If there is nothing to read, it will return `Ok(None)`.
+    /// let numbers =
+    ///     sc.parse_iter::<u32>().take(2).collect::<Result<Vec<_>, _>>().unwrap();
     ///
-    /// ```rust
-    /// use scanner_rust::Scanner;
+    /// assert_eq!(vec![1, 2], numbers);
     ///
-    /// let mut sc = Scanner::new("1 2.5".as_bytes());
+    /// let mut numbers = sc.parse_iter::<u32>();
     ///
-    /// assert_eq!(Some(1.0), sc.next_f64_until(" ").unwrap());
-    /// assert_eq!(Some(2.5), sc.next_f64_until(" ").unwrap());
+    /// assert!(numbers.next().unwrap().is_err());
+    /// assert_eq!(4, numbers.next().unwrap().unwrap());
+    /// assert!(numbers.next().is_none());
     /// ```
     #[inline]
-    pub fn next_f64_until<D: ?Sized + AsRef<[u8]>>(
-        &mut self,
-        boundary: &D,
-    ) -> Result<Option<f64>, ScannerError> {
-        self.next_until_raw_parse(boundary)
+    pub fn parse_iter<T: FromStr>(&mut self) -> ParseIter<'_, Self, T>
+    where
+        <T as FromStr>::Err: Error + Send + Sync + 'static, {
+        ParseIter::new(self)
+    }
+}
+
+impl<R: Read, N: BufferSize> Scan for Scanner<R, N> {
+    type Line = String;
+    type Token = String;
+
+    impl_scan_delegate!();
+
+    #[inline]
+    fn next_char(&mut self) -> Result<Option<char>, ScannerError> {
+        Self::next_char(self)
+    }
+
+    #[inline]
+    fn next_line(&mut self) -> Result<Option<String>, ScannerError> {
+        Self::next_line(self)
+    }
+
+    #[inline]
+    fn skip_whitespaces(&mut self) -> Result<bool, ScannerError> {
+        Self::skip_whitespaces(self)
+    }
+
+    #[inline]
+    fn next(&mut self) -> Result<Option<String>, ScannerError> {
+        Self::next(self)
+    }
+
+    #[inline]
+    fn next_until<S: AsRef<str>>(&mut self, boundary: S) -> Result<Option<String>, ScannerError> {
+        Self::next_until(self, boundary)
+    }
+}
+
+impl<R: Read, N: BufferSize> Iterator for Scanner<R, N> {
+    type Item = String;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next().unwrap_or(None)
     }
 }
@@ -1,16 +1,258 @@
 use std::{
+    borrow::Cow,
     error::Error,
     fmt::{self, Display, Formatter},
     io::{self, ErrorKind},
-    num::{ParseFloatError, ParseIntError},
+    num::{IntErrorKind, ParseFloatError, ParseIntError},
+    str::{from_utf8, FromStr},
 };
 
+use crate::Position;
+
 #[derive(Debug)]
-/// The possible errors of `Scanner`, `ScannerAscii`, `ScannerStr`, `ScannerU8Slice`, `ScannerU8SliceAscii` structs.
+/// The possible errors of `Scanner`, `ScannerAscii`, `ScannerStr`, `ScannerU8Slice`, `ScannerU8SliceAscii` structs. More variants may be added in minor versions, so a `match` on it needs a wildcard arm.
+#[non_exhaustive]
 pub enum ScannerError {
     IOError(io::Error),
-    ParseIntError(ParseIntError),
-    ParseFloatError(ParseFloatError),
+    /// The token cannot be parsed to an integer.
+    ParseIntError {
+        source:   ParseIntError,
+        /// The text of the token.
+        token:    String,
+        /// The position where the token began.
+        position: Position,
+    },
+    /// The token cannot be parsed to a floating-point number.
+    ParseFloatError {
+        source:   ParseFloatError,
+        /// The text of the token.
+        token:    String,
+        /// The position where the token began.
+        position: Position,
+    },
+    /// Any other error returned by a `FromStr` implementation.
+    ParseError {
+        source:   Box<dyn Error + Send + Sync>,
+        /// The text of the token.
+        token:    String,
+        /// The position where the token began.
+        position: Position,
+    },
+    /// The token is not one of the words of the boolean vocabulary.
+    ParseBoolError {
+        /// The text of the token.
+        token:    String,
+        /// The position where the token began.
+        position: Position,
+    },
+    /// The data is not valid UTF-8. Only returned in the strict UTF-8 mode.
+    InvalidUtf8 {
+        /// The byte offset where the invalid bytes began.
+        offset: usize,
+        /// The invalid bytes.
+        bytes:  Vec<u8>,
+    },
+    /// A quoted string is not closed before the end of the data.
+    UnterminatedQuote {
+        /// The position of the opening quote.
+        position: Position,
+    },
+    /// A backslash in a quoted string is followed by something which is not a supported escape sequence.
+    InvalidEscape {
+        /// The text of the escape sequence, starting with the backslash.
+        sequence: String,
+        /// The position of the backslash.
+        position: Position,
+    },
+    /// The radix passed to a method is neither `0` nor in the range from 2 to 36. Nothing is consumed.
+    InvalidRadix {
+        /// The radix which was passed.
+        radix: u32,
+    },
+}
+
+/// The kinds of `ScannerError`, which tell what is wrong without matching the fields and the sources of the variants. More kinds may be added in minor versions.
+///
+/// ```rust
+/// use scanner_rust::{ScannerErrorKind, ScannerStr};
+///
+/// let mut sc = ScannerStr::new("300 -300 abc 1.2.3");
+///
+/// assert_eq!(ScannerErrorKind::Overflow, sc.next_u8().unwrap_err().kind());
+/// assert_eq!(ScannerErrorKind::Underflow, sc.next_i8().unwrap_err().kind());
+/// assert_eq!(
+///     ScannerErrorKind::InvalidDigit,
+///     sc.next_u8().unwrap_err().kind()
+/// );
+/// assert_eq!(
+///     ScannerErrorKind::InvalidFloat,
+///     sc.next_f64().unwrap_err().kind()
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ScannerErrorKind {
+    /// An I/O error.
+    Io,
+    /// The integer is larger than the maximum of its type.
+    Overflow,
+    /// The integer is smaller than the minimum of its type, such as `-1` for `u8`.
+    Underflow,
+    /// The token to parse is empty.
+    Empty,
+    /// The token contains something which is not a digit of an integer, even if the digits before it are already out of range.
+    InvalidDigit,
+    /// The token is not a floating-point number.
+    InvalidFloat,
+    /// The token is not in the boolean vocabulary.
+    InvalidBool,
+    /// Any other error returned by a `FromStr` implementation, such as `0` for a `NonZeroU32` value.
+    Parse,
+    /// The data is not valid UTF-8.
+    InvalidUtf8,
+    /// A quoted string is not closed.
+    UnterminatedQuote,
+    /// A quoted string contains an invalid escape sequence.
+    InvalidEscape,
+    /// The radix passed to a method is not supported.
+    InvalidRadix,
+}
+
+impl ScannerError {
+    /// Get the kind of this error.
+    ///
+    /// ```rust
+    /// use scanner_rust::{ScannerErrorKind, ScannerStr};
+    ///
+    /// let mut sc = ScannerStr::new("256 -1 x");
+    ///
+    /// assert_eq!(ScannerErrorKind::Overflow, sc.next_u8().unwrap_err().kind());
+    /// assert_eq!(ScannerErrorKind::Underflow, sc.next_u8().unwrap_err().kind());
+    /// assert_eq!(
+    ///     ScannerErrorKind::InvalidDigit,
+    ///     sc.next_u8().unwrap_err().kind()
+    /// );
+    /// ```
+    pub fn kind(&self) -> ScannerErrorKind {
+        match self {
+            ScannerError::IOError(_) => ScannerErrorKind::Io,
+            ScannerError::ParseIntError {
+                source, ..
+            } => match source.kind() {
+                IntErrorKind::Empty => ScannerErrorKind::Empty,
+                IntErrorKind::InvalidDigit => ScannerErrorKind::InvalidDigit,
+                IntErrorKind::PosOverflow => ScannerErrorKind::Overflow,
+                IntErrorKind::NegOverflow => ScannerErrorKind::Underflow,
+                _ => ScannerErrorKind::Parse,
+            },
+            ScannerError::ParseFloatError {
+                token, ..
+            } => {
+                if token.is_empty() {
+                    ScannerErrorKind::Empty
+                } else {
+                    ScannerErrorKind::InvalidFloat
+                }
+            },
+            ScannerError::ParseError {
+                token, ..
+            } => {
+                if token.is_empty() {
+                    ScannerErrorKind::Empty
+                } else {
+                    ScannerErrorKind::Parse
+                }
+            },
+            ScannerError::ParseBoolError {
+                ..
+            } => ScannerErrorKind::InvalidBool,
+            ScannerError::InvalidUtf8 {
+                ..
+            } => ScannerErrorKind::InvalidUtf8,
+            ScannerError::UnterminatedQuote {
+                ..
+            } => ScannerErrorKind::UnterminatedQuote,
+            ScannerError::InvalidEscape {
+                ..
+            } => ScannerErrorKind::InvalidEscape,
+            ScannerError::InvalidRadix {
+                ..
+            } => ScannerErrorKind::InvalidRadix,
+        }
+    }
+
+    /// Convert an error returned by a `FromStr` implementation. The errors of integers and floating-point numbers still use their own variants.
+    pub(crate) fn from_parse_error<E: Error + Send + Sync + 'static>(
+        err: E,
+        token: &str,
+        position: Position,
+    ) -> ScannerError {
+        let token = token.to_string();
+
+        let err: Box<dyn Error + Send + Sync> = Box::new(err);
+
+        let err = match err.downcast::<ParseIntError>() {
+            Ok(err) => {
+                return ScannerError::ParseIntError {
+                    source: *err,
+                    token,
+                    position,
+                }
+            },
+            Err(err) => err,
+        };
+
+        match err.downcast::<ParseFloatError>() {
+            Ok(err) => ScannerError::ParseFloatError {
+                source: *err,
+                token,
+                position,
+            },
+            Err(err) => ScannerError::ParseError {
+                source: err,
+                token,
+                position,
+            },
+        }
+    }
+}
+
+/// Parse a token which began at `position`.
+#[inline]
+pub(crate) fn parse_token<T: FromStr, S: AsRef<str>>(
+    token: S,
+    position: Position,
+) -> Result<T, ScannerError>
+where
+    <T as FromStr>::Err: Error + Send + Sync + 'static, {
+    let token = token.as_ref();
+
+    token.parse().map_err(|err| ScannerError::from_parse_error(err, token, position))
+}
+
+/// Decode data which began at the byte `offset`. Invalid bytes are replaced with `REPLACEMENT_CHARACTER`, or become an `InvalidUtf8` error if `strict` is `true`.
+#[inline]
+pub(crate) fn decode_utf8(
+    data: &[u8],
+    offset: usize,
+    strict: bool,
+) -> Result<Cow<'_, str>, ScannerError> {
+    if !strict {
+        return Ok(String::from_utf8_lossy(data));
+    }
+
+    match from_utf8(data) {
+        Ok(s) => Ok(Cow::Borrowed(s)),
+        Err(err) => {
+            let start = err.valid_up_to();
+            let end = start + err.error_len().unwrap_or(data.len() - start);
+
+            Err(ScannerError::InvalidUtf8 {
+                offset: offset + start,
+                bytes:  data[start..end].to_vec(),
+            })
+        },
+    }
 }
 
 impl From<io::Error> for ScannerError {
@@ -27,29 +269,102 @@ impl From<ErrorKind> for ScannerError {
     }
 }
 
-impl From<ParseIntError> for ScannerError {
+impl Display for ScannerError {
     #[inline]
-    fn from(err: ParseIntError) -> ScannerError {
-        ScannerError::ParseIntError(err)
-    }
-}
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            ScannerError::IOError(err) => Display::fmt(&err, f),
+            ScannerError::ParseIntError {
+                source,
+                token,
+                position,
+            } => {
+                let description = match source.kind() {
+                    IntErrorKind::Empty => "empty token",
+                    IntErrorKind::InvalidDigit => "invalid digit",
+                    IntErrorKind::PosOverflow => "number too large",
+                    IntErrorKind::NegOverflow => "number too small",
+                    _ => return f.write_fmt(format_args!("{source} in {token:?} at {position}")),
+                };
 
-impl From<ParseFloatError> for ScannerError {
-    #[inline]
-    fn from(err: ParseFloatError) -> ScannerError {
-        ScannerError::ParseFloatError(err)
+                f.write_fmt(format_args!("{description} in {token:?} at {position}"))
+            },
+            ScannerError::ParseFloatError {
+                token,
+                position,
+                ..
+            } => {
+                let description =
+                    if token.is_empty() { "empty token" } else { "invalid float literal" };
+
+                f.write_fmt(format_args!("{description} in {token:?} at {position}"))
+            },
+            ScannerError::ParseError {
+                source,
+                token,
+                position,
+            } => f.write_fmt(format_args!("{source} in {token:?} at {position}")),
+            ScannerError::ParseBoolError {
+                token,
+                position,
+            } => f.write_fmt(format_args!("invalid boolean in {token:?} at {position}")),
+            ScannerError::InvalidUtf8 {
+                offset,
+                bytes,
+            } => {
+                f.write_str("invalid UTF-8 sequence")?;
+
+                for e in bytes {
+                    f.write_fmt(format_args!(" {e:#04X}"))?;
+                }
+
+                f.write_fmt(format_args!(" at byte {offset}"))
+            },
+            ScannerError::UnterminatedQuote {
+                position,
+            } => f.write_fmt(format_args!("unterminated quote at {position}")),
+            ScannerError::InvalidEscape {
+                sequence,
+                position,
+            } => f.write_fmt(format_args!("invalid escape sequence {sequence:?} at {position}")),
+            ScannerError::InvalidRadix {
+                radix,
+            } => f.write_fmt(format_args!(
+                "invalid radix {radix}, which should be 0 or from 2 to 36"
+            )),
+        }
     }
 }
 
-impl Display for ScannerError {
+impl Error for ScannerError {
     #[inline]
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match self {
-            ScannerError::IOError(err) => Display::fmt(&err, f),
-            ScannerError::ParseIntError(err) => Display::fmt(&err, f),
-            ScannerError::ParseFloatError(err) => Display::fmt(&err, f),
+            ScannerError::IOError(err) => Some(err),
+            ScannerError::ParseIntError {
+                source, ..
+            } => Some(source),
+            ScannerError::ParseFloatError {
+                source, ..
+            } => Some(source),
+            ScannerError::ParseError {
+                source, ..
+            } => Some(source.as_ref()),
+            ScannerError::ParseBoolError {
+                ..
+            }
+            | ScannerError::InvalidUtf8 {
+                ..
+            }
+            | ScannerError::UnterminatedQuote {
+                ..
+            }
+            | ScannerError::InvalidEscape {
+                ..
+            }
+            | ScannerError::InvalidRadix {
+                ..
+            } => None,
         }
     }
 }
-
-impl Error for ScannerError {}
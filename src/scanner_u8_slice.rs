@@ -1,18 +1,39 @@
 use std::{
     char::REPLACEMENT_CHARACTER,
-    str::{from_utf8, from_utf8_unchecked, FromStr},
+    error::Error,
+    str::{from_utf8, FromStr},
 };
 
+#[cfg(feature = "num-bigint")]
+use num_bigint::{BigInt, BigUint};
+#[cfg(feature = "regex")]
+use regex::bytes::Regex;
+#[cfg(feature = "rust_decimal")]
+use rust_decimal::Decimal;
 use utf8_width::*;
 
-use crate::{whitespaces::*, ScannerError};
+use crate::{
+    bool_vocabulary::parse_bool,
+    integer::{check_radix, parse_integer, Integer},
+    number_format::parse_number,
+    position::{line_break_length, PositionTracker},
+    quoted::next_quoted,
+    scanner_error::{decode_utf8, parse_token},
+    BoolVocabulary, Chars, Checkpoint, Delimiter, Lines, NumberFormat, ParseIter, Position,
+    QuotedTokens, Scan, ScannerError, Tokens,
+};
 
 /// A simple text scanner which can in-memory-ly parse primitive types and strings using UTF-8 from a byte slice.
 #[derive(Debug)]
 pub struct ScannerU8Slice<'a> {
-    data:        &'a [u8],
-    data_length: usize,
-    position:    usize,
+    data:            &'a [u8],
+    data_length:     usize,
+    position:        usize,
+    delimiter:       Delimiter,
+    number_format:   NumberFormat,
+    bool_vocabulary: BoolVocabulary,
+    tracker:         PositionTracker,
+    strict_utf8:     bool,
 }
 
 impl<'a> ScannerU8Slice<'a> {
@@ -26,19 +47,402 @@ impl<'a> ScannerU8Slice<'a> {
     /// let mut sc = ScannerU8Slice::new(b"123 456");
     /// ```
     #[inline]
-    pub fn new<D: ?Sized + AsRef<[u8]>>(data: &D) -> ScannerU8Slice {
+    pub fn new<D: ?Sized + AsRef<[u8]>>(data: &D) -> ScannerU8Slice<'_> {
         let data = data.as_ref();
 
         ScannerU8Slice {
             data,
             data_length: data.len(),
             position: 0,
+            delimiter: Delimiter::Whitespaces,
+            number_format: NumberFormat::default(),
+            bool_vocabulary: BoolVocabulary::default(),
+            tracker: PositionTracker::default(),
+            strict_utf8: false,
+        }
+    }
+}
+
+impl<'a> ScannerU8Slice<'a> {
+    /// Set the delimiter used by `next`, `skip_whitespaces` and the methods which parse the next token to a primitive type.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("1, 2;3".as_bytes());
+    ///
+    /// sc.set_delimiter([',', ';', ' ']);
+    ///
+    /// assert_eq!(Some(1), sc.next_u8().unwrap());
+    /// assert_eq!(Some(2), sc.next_u8().unwrap());
+    /// assert_eq!(Some(3), sc.next_u8().unwrap());
+    /// assert_eq!(None, sc.next_u8().unwrap());
+    /// ```
+    #[inline]
+    pub fn set_delimiter<D: Into<Delimiter>>(&mut self, delimiter: D) {
+        self.delimiter = delimiter.into();
+    }
+
+    /// Get the delimiter used by `next`, `skip_whitespaces` and the methods which parse the next token to a primitive type.
+    ///
+    /// ```rust
+    /// use scanner_rust::{Delimiter, ScannerU8Slice};
+    ///
+    /// let sc = ScannerU8Slice::new("1 2 3".as_bytes());
+    ///
+    /// assert_eq!(&Delimiter::Whitespaces, sc.delimiter());
+    /// ```
+    #[inline]
+    pub fn delimiter(&self) -> &Delimiter {
+        &self.delimiter
+    }
+
+    /// Set the number format used by the methods which parse the next token to an integer or a floating-point number.
+    ///
+    /// ```rust
+    /// use scanner_rust::{NumberFormat, ScannerU8Slice};
+    ///
+    /// let mut sc = ScannerU8Slice::new("1_000 1,5".as_bytes());
+    ///
+    /// sc.set_number_format(NumberFormat {
+    ///     digit_separators: vec!['_'],
+    ///     decimal_point: ',',
+    ///     ..NumberFormat::default()
+    /// });
+    ///
+    /// assert_eq!(Some(1000), sc.next_u16().unwrap());
+    /// assert_eq!(Some(1.5), sc.next_f64().unwrap());
+    /// ```
+    #[inline]
+    pub fn set_number_format(&mut self, number_format: NumberFormat) {
+        self.number_format = number_format;
+    }
+
+    /// Get the number format used by the methods which parse the next token to an integer or a floating-point number.
+    ///
+    /// ```rust
+    /// use scanner_rust::{NumberFormat, ScannerU8Slice};
+    ///
+    /// let sc = ScannerU8Slice::new("1_000 1,5".as_bytes());
+    ///
+    /// assert_eq!(&NumberFormat::default(), sc.number_format());
+    /// ```
+    #[inline]
+    pub fn number_format(&self) -> &NumberFormat {
+        &self.number_format
+    }
+
+    /// Set the words read as `true` or `false` by the `next_bool` and `next_bool_until` methods.
+    ///
+    /// ```rust
+    /// use scanner_rust::{BoolVocabulary, ScannerU8Slice};
+    ///
+    /// let mut sc = ScannerU8Slice::new("yes N".as_bytes());
+    ///
+    /// sc.set_bool_vocabulary(BoolVocabulary::lenient());
+    ///
+    /// assert_eq!(Some(true), sc.next_bool().unwrap());
+    /// assert_eq!(Some(false), sc.next_bool().unwrap());
+    /// ```
+    #[inline]
+    pub fn set_bool_vocabulary(&mut self, bool_vocabulary: BoolVocabulary) {
+        self.bool_vocabulary = bool_vocabulary;
+    }
+
+    /// Get the words read as `true` or `false` by the `next_bool` and `next_bool_until` methods.
+    ///
+    /// ```rust
+    /// use scanner_rust::{BoolVocabulary, ScannerU8Slice};
+    ///
+    /// let sc = ScannerU8Slice::new("true".as_bytes());
+    ///
+    /// assert_eq!(&BoolVocabulary::default(), sc.bool_vocabulary());
+    /// ```
+    #[inline]
+    pub fn bool_vocabulary(&self) -> &BoolVocabulary {
+        &self.bool_vocabulary
+    }
+
+    /// Get the position of the data which will be read next.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("123 456\r\n789".as_bytes());
+    ///
+    /// sc.next_line().unwrap();
+    /// sc.next().unwrap();
+    ///
+    /// let position = sc.position();
+    ///
+    /// assert_eq!(12, position.offset);
+    /// assert_eq!(2, position.line);
+    /// assert_eq!(4, position.column);
+    /// ```
+    #[inline]
+    pub fn position(&self) -> Position {
+        self.tracker.position()
+    }
+
+    /// Set whether invalid UTF-8 data is an `InvalidUtf8` error instead of being replaced with `REPLACEMENT_CHARACTER`. It affects `next_char` and the methods which parse text to a value. It is `false` by default.
+    ///
+    /// ```rust
+    /// use scanner_rust::{ScannerError, ScannerU8Slice};
+    ///
+    /// let mut sc = ScannerU8Slice::new(b"a\xE4\xB8");
+    ///
+    /// sc.set_strict_utf8(true);
+    ///
+    /// assert_eq!(Some('a'), sc.next_char().unwrap());
+    ///
+    /// match sc.next_char() {
+    ///     Err(ScannerError::InvalidUtf8 {
+    ///         offset,
+    ///         bytes,
+    ///     }) => {
+    ///         assert_eq!(1, offset);
+    ///         assert_eq!(vec![0xE4, 0xB8], bytes);
+    ///     },
+    ///     _ => unreachable!(),
+    /// }
+    /// ```
+    #[inline]
+    pub fn set_strict_utf8(&mut self, strict: bool) {
+        self.strict_utf8 = strict;
+    }
+
+    /// Check whether invalid UTF-8 data is an `InvalidUtf8` error instead of being replaced with `REPLACEMENT_CHARACTER`.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let sc = ScannerU8Slice::new("123".as_bytes());
+    ///
+    /// assert!(!sc.is_strict_utf8());
+    /// ```
+    #[inline]
+    pub fn is_strict_utf8(&self) -> bool {
+        self.strict_utf8
+    }
+
+    /// Create an `InvalidUtf8` error with the next `length` bytes.
+    #[inline]
+    fn invalid_utf8(&self, length: usize) -> ScannerError {
+        ScannerError::InvalidUtf8 {
+            offset: self.tracker.position().offset,
+            bytes:  self.data[self.position..(self.position + length)].to_vec(),
+        }
+    }
+
+    /// Move forward to the byte index `p` and keep the position up to date.
+    #[inline]
+    fn advance_to(&mut self, p: usize) {
+        self.tracker.advance(&self.data[self.position..p], false);
+
+        self.position = p;
+    }
+}
+
+impl<'a> ScannerU8Slice<'a> {
+    /// Save the current state, so that the scanner can go back to it later by calling `restore`. It is as cheap as copying the position.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("123 456".as_bytes());
+    ///
+    /// sc.next().unwrap();
+    ///
+    /// let checkpoint = sc.checkpoint();
+    ///
+    /// assert_eq!(3, checkpoint.position().offset);
+    /// ```
+    #[inline]
+    pub fn checkpoint(&self) -> Checkpoint {
+        Checkpoint::new(self.tracker)
+    }
+
+    /// Go back (or forward) to a checkpoint created by this scanner. The delimiter is not restored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the checkpoint is beyond the end of the data, which means that it was created by another scanner.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("123 456".as_bytes());
+    ///
+    /// let checkpoint = sc.checkpoint();
+    ///
+    /// assert_eq!(Some("123".as_bytes()), sc.next().unwrap());
+    /// assert_eq!(Some("456".as_bytes()), sc.next().unwrap());
+    ///
+    /// sc.restore(checkpoint);
+    ///
+    /// assert_eq!(Some("123".as_bytes()), sc.next().unwrap());
+    /// ```
+    #[inline]
+    pub fn restore(&mut self, checkpoint: Checkpoint) {
+        let tracker = checkpoint.tracker();
+        let offset = tracker.position().offset;
+
+        assert!(
+            offset <= self.data_length,
+            "the checkpoint at {offset} is beyond the end of the data"
+        );
+
+        self.tracker = tracker;
+        self.position = offset;
+    }
+
+    /// Call `f` with this scanner, and go back to where the scanner was if `f` returns an error.
+    ///
+    /// ```rust
+    /// use scanner_rust::{ScannerError, ScannerU8Slice};
+    ///
+    /// let mut sc = ScannerU8Slice::new("1 2 x".as_bytes());
+    ///
+    /// let pair = |sc: &mut ScannerU8Slice| -> Result<(u8, u8), ScannerError> {
+    ///     Ok((sc.next_u8()?.unwrap_or(0), sc.next_u8()?.unwrap_or(0)))
+    /// };
+    ///
+    /// assert_eq!((1, 2), sc.try_scan(pair).unwrap());
+    /// assert!(sc.try_scan(pair).is_err());
+    /// assert_eq!(Some("x".as_bytes()), sc.next().unwrap());
+    /// ```
+    #[inline]
+    pub fn try_scan<T, E, F: FnOnce(&mut Self) -> Result<T, E>>(&mut self, f: F) -> Result<T, E> {
+        let checkpoint = self.checkpoint();
+
+        let result = f(self);
+
+        if result.is_err() {
+            self.restore(checkpoint);
         }
+
+        result
+    }
+
+    /// Move to the byte `offset` of the data. The line and column numbers are counted again from the start, so `checkpoint` and `restore` are faster for backtracking.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `offset` is beyond the end of the data.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("123\n456".as_bytes());
+    ///
+    /// sc.set_position(5);
+    ///
+    /// assert_eq!(2, sc.position().line);
+    /// assert_eq!(Some("56".as_bytes()), sc.next().unwrap());
+    /// ```
+    pub fn set_position(&mut self, offset: usize) {
+        assert!(offset <= self.data_length, "the offset {offset} is beyond the end of the data");
+
+        self.tracker = PositionTracker::from_data(&self.data[..offset], false);
+        self.position = offset;
+    }
+
+    /// Get the data which has not been read yet.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("123 456".as_bytes());
+    ///
+    /// sc.next().unwrap();
+    ///
+    /// assert_eq!(" 456".as_bytes(), sc.remaining());
+    /// ```
+    #[inline]
+    pub fn remaining(&self) -> &'a [u8] {
+        &self.data[self.position..]
+    }
+}
+
+impl<'a> ScannerU8Slice<'a> {
+    /// Call `f` and go back to where the scanner was, so that nothing is consumed.
+    #[inline]
+    fn peek_with<T>(&mut self, f: impl FnOnce(&mut Self) -> T) -> T {
+        let checkpoint = self.checkpoint();
+
+        let result = f(self);
+
+        self.restore(checkpoint);
+
+        result
+    }
+
+    /// Read the next char without consuming it. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("中文".as_bytes());
+    ///
+    /// assert_eq!(Some('中'), sc.peek_char().unwrap());
+    /// assert_eq!(Some('中'), sc.next_char().unwrap());
+    /// ```
+    #[inline]
+    pub fn peek_char(&mut self) -> Result<Option<char>, ScannerError> {
+        self.peek_with(Self::next_char)
+    }
+
+    /// Read the next token separated by delimiters without consuming it. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("123 456".as_bytes());
+    ///
+    /// assert_eq!(Some("123".as_bytes()), sc.peek_token().unwrap());
+    /// assert_eq!(Some(123), sc.next_u16().unwrap());
+    /// ```
+    #[inline]
+    pub fn peek_token(&mut self) -> Result<Option<&'a [u8]>, ScannerError> {
+        self.peek_with(Self::next)
+    }
+
+    /// Read the next line without consuming it. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("123 456\r\n789".as_bytes());
+    ///
+    /// assert_eq!(Some("123 456".as_bytes()), sc.peek_line().unwrap());
+    /// assert_eq!(Some("123".as_bytes()), sc.next().unwrap());
+    /// ```
+    #[inline]
+    pub fn peek_line(&mut self) -> Result<Option<&'a [u8]>, ScannerError> {
+        self.peek_with(Self::next_line)
+    }
+
+    /// Read the next bytes without consuming them. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("123 456".as_bytes());
+    ///
+    /// assert_eq!(Some("123 4".as_bytes()), sc.peek_bytes(5).unwrap());
+    /// assert_eq!(Some("123".as_bytes()), sc.next().unwrap());
+    /// ```
+    #[inline]
+    pub fn peek_bytes(
+        &mut self,
+        max_number_of_bytes: usize,
+    ) -> Result<Option<&'a [u8]>, ScannerError> {
+        self.peek_with(|sc| sc.next_bytes(max_number_of_bytes))
     }
 }
 
 impl<'a> ScannerU8Slice<'a> {
-    /// Read the next char. If the data is not a correct char, it will return a `Ok(Some(REPLACEMENT_CHARACTER))` which is �. If there is nothing to read, it will return `Ok(None)`.
+    /// Read the next char. If the data is not a correct char, it will return a `Ok(Some(REPLACEMENT_CHARACTER))` which is � (or an `InvalidUtf8` error in the strict UTF-8 mode). If there is nothing to read, it will return `Ok(None)`.
     ///
     /// ```rust
     /// use scanner_rust::ScannerU8Slice;
@@ -64,18 +468,26 @@ impl<'a> ScannerU8Slice<'a> {
 
         match width {
             0 => {
-                self.position += 1;
+                if self.strict_utf8 {
+                    return Err(self.invalid_utf8(1));
+                }
+
+                self.advance_to(self.position + 1);
 
                 Ok(Some(REPLACEMENT_CHARACTER))
             },
             1 => {
-                self.position += 1;
+                self.advance_to(self.position + 1);
 
                 Ok(Some(e as char))
             },
             _ => {
                 if self.position + width > self.data_length {
-                    self.position += 1;
+                    if self.strict_utf8 {
+                        return Err(self.invalid_utf8(self.data_length - self.position));
+                    }
+
+                    self.advance_to(self.position + 1);
 
                     Ok(Some(REPLACEMENT_CHARACTER))
                 } else {
@@ -83,12 +495,16 @@ impl<'a> ScannerU8Slice<'a> {
 
                     match from_utf8(char_str_bytes) {
                         Ok(char_str) => {
-                            self.position += width;
+                            self.advance_to(self.position + width);
 
                             Ok(char_str.chars().next())
                         },
-                        Err(_) => {
-                            self.position += 1;
+                        Err(err) => {
+                            if self.strict_utf8 {
+                                return Err(self.invalid_utf8(err.error_len().unwrap_or(1)));
+                            }
+
+                            self.advance_to(self.position + 1);
 
                             Ok(Some(REPLACEMENT_CHARACTER))
                         },
@@ -128,25 +544,18 @@ impl<'a> ScannerU8Slice<'a> {
                 },
                 1 => {
                     match e {
-                        b'\n' => {
+                        b'\n' | b'\r' => {
                             let data = &self.data[self.position..p];
 
-                            if p + 1 < self.data_length && self.data[p + 1] == b'\r' {
-                                self.position = p + 2;
-                            } else {
-                                self.position = p + 1;
-                            }
-
-                            return Ok(Some(data));
-                        },
-                        b'\r' => {
-                            let data = &self.data[self.position..p];
+                            // the second byte of the line break consumed right before is not paired again
+                            let length =
+                                if p == self.position && self.tracker.completes_line_break(e) {
+                                    1
+                                } else {
+                                    line_break_length(&self.data[p..])
+                                };
 
-                            if p + 1 < self.data_length && self.data[p + 1] == b'\n' {
-                                self.position = p + 2;
-                            } else {
-                                self.position = p + 1;
-                            }
+                            self.advance_to(p + length);
 
                             return Ok(Some(data));
                         },
@@ -159,7 +568,7 @@ impl<'a> ScannerU8Slice<'a> {
                     if p + width >= self.data_length {
                         let data = &self.data[self.position..];
 
-                        self.position = self.data_length;
+                        self.advance_to(self.data_length);
 
                         return Ok(Some(data));
                     } else {
@@ -175,14 +584,14 @@ impl<'a> ScannerU8Slice<'a> {
 
         let data = &self.data[self.position..p];
 
-        self.position = p;
+        self.advance_to(p);
 
         Ok(Some(data))
     }
 }
 
 impl<'a> ScannerU8Slice<'a> {
-    /// Skip the next whitespaces (`javaWhitespace`). If there is nothing to read, it will return `Ok(false)`.
+    /// Skip the next delimiters, which are whitespaces (`javaWhitespace`) by default. If there is nothing to read, it will return `Ok(false)`.
     ///
     /// ```rust
     /// use scanner_rust::ScannerU8Slice;
@@ -202,39 +611,14 @@ impl<'a> ScannerU8Slice<'a> {
         }
 
         loop {
-            let e = self.data[self.position];
-
-            let width = get_width(e);
-
-            match width {
-                0 => {
-                    break;
-                },
-                1 => {
-                    if !is_whitespace_1(e) {
-                        break;
-                    }
+            let length = self.delimiter.match_length(&self.data[self.position..], false);
 
-                    self.position += 1;
-                },
-                3 => {
-                    if self.position + width <= self.data_length
-                        && is_whitespace_3(
-                            self.data[self.position],
-                            self.data[self.position + 1],
-                            self.data[self.position + 2],
-                        )
-                    {
-                        self.position += 3;
-                    } else {
-                        break;
-                    }
-                },
-                _ => {
-                    break;
-                },
+            if length == 0 {
+                break;
             }
 
+            self.advance_to(self.position + length);
+
             if self.position == self.data_length {
                 break;
             }
@@ -243,7 +627,7 @@ impl<'a> ScannerU8Slice<'a> {
         Ok(true)
     }
 
-    /// Read the next token separated by whitespaces. If there is nothing to read, it will return `Ok(None)`.
+    /// Read the next token separated by delimiters. If there is nothing to read, it will return `Ok(None)`.
     ///
     /// ```rust
     /// use scanner_rust::ScannerU8Slice;
@@ -269,67 +653,24 @@ impl<'a> ScannerU8Slice<'a> {
         let mut p = self.position;
 
         loop {
-            let e = self.data[p];
+            if self.delimiter.match_length(&self.data[p..], false) > 0 {
+                let data = &self.data[self.position..p];
 
-            let width = get_width(e);
+                self.advance_to(p);
 
-            match width {
-                0 => {
-                    p += 1;
-                },
-                1 => {
-                    if is_whitespace_1(e) {
-                        let data = &self.data[self.position..p];
+                return Ok(Some(data));
+            }
 
-                        self.position = p;
+            p += get_width(self.data[p]).max(1);
 
-                        return Ok(Some(data));
-                    }
+            if p >= self.data_length {
+                break;
+            }
+        }
 
-                    p += 1;
-                },
-                3 => {
-                    if self.position + width > self.data_length {
-                        let data = &self.data[self.position..];
-
-                        self.position = self.data_length;
-
-                        return Ok(Some(data));
-                    } else if is_whitespace_3(
-                        self.data[self.position],
-                        self.data[self.position + 1],
-                        self.data[self.position + 2],
-                    ) {
-                        let data = &self.data[self.position..p];
-
-                        self.position = p;
-
-                        return Ok(Some(data));
-                    } else {
-                        p += 3;
-                    }
-                },
-                _ => {
-                    if self.position + width >= self.data_length {
-                        let data = &self.data[self.position..];
-
-                        self.position = self.data_length;
-
-                        return Ok(Some(data));
-                    } else {
-                        p += width;
-                    }
-                },
-            }
-
-            if p == self.data_length {
-                break;
-            }
-        }
-
-        let data = &self.data[self.position..p];
+        let data = &self.data[self.position..];
 
-        self.position = p;
+        self.advance_to(self.data_length);
 
         Ok(Some(data))
     }
@@ -362,7 +703,7 @@ impl<'a> ScannerU8Slice<'a> {
 
         let data = &self.data[self.position..(self.position + dropping_bytes)];
 
-        self.position += dropping_bytes;
+        self.advance_to(self.position + dropping_bytes);
 
         Ok(Some(data))
     }
@@ -391,7 +732,7 @@ impl<'a> ScannerU8Slice<'a> {
 
         let dropping_bytes = max_number_of_bytes.min(self.data_length - self.position);
 
-        self.position += dropping_bytes;
+        self.advance_to(self.position + dropping_bytes);
 
         Ok(Some(dropping_bytes))
     }
@@ -422,21 +763,21 @@ impl<'a> ScannerU8Slice<'a> {
         let boundary = boundary.as_ref();
         let boundary_length = boundary.len();
 
-        if boundary_length == 0 || boundary_length >= self.data_length - self.position {
+        if boundary_length == 0 || boundary_length > self.data_length - self.position {
             let data = &self.data[self.position..];
 
-            self.position = self.data_length;
+            self.advance_to(self.data_length);
 
             return Ok(Some(data));
         }
 
-        for i in self.position..(self.data_length - boundary_length) {
+        for i in self.position..=(self.data_length - boundary_length) {
             let e = i + boundary_length;
 
             if &self.data[i..e] == boundary {
                 let data = &self.data[self.position..i];
 
-                self.position = e;
+                self.advance_to(e);
 
                 return Ok(Some(data));
             }
@@ -444,26 +785,186 @@ impl<'a> ScannerU8Slice<'a> {
 
         let data = &self.data[self.position..];
 
-        self.position = self.data_length;
+        self.advance_to(self.data_length);
 
         Ok(Some(data))
     }
 }
 
+#[cfg(feature = "regex")]
+impl<'a> ScannerU8Slice<'a> {
+    /// Skip the next delimiters and read the text matched by a regular expression right after them. If the text there does not match, it will return `Ok(None)` and nothing but the delimiters will be consumed. If there is nothing to read, it will return `Ok(None)` too.
+    ///
+    /// ```rust
+    /// use regex::bytes::Regex;
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("  3.14159 ABC123".as_bytes());
+    ///
+    /// let decimal = Regex::new(r"\d+\.\d+").unwrap();
+    /// let code = Regex::new(r"[A-Z]{3}").unwrap();
+    ///
+    /// assert_eq!(Some("3.14159".as_bytes()), sc.next_matching(&decimal).unwrap());
+    /// assert_eq!(None, sc.next_matching(&decimal).unwrap());
+    /// assert_eq!(Some("ABC".as_bytes()), sc.next_matching(&code).unwrap());
+    /// assert_eq!(Some(123), sc.next_u8().unwrap());
+    /// ```
+    pub fn next_matching(&mut self, regex: &Regex) -> Result<Option<&'a [u8]>, ScannerError> {
+        if !self.skip_whitespaces()? || self.position == self.data_length {
+            return Ok(None);
+        }
+
+        Ok(self.consume_match_at(regex))
+    }
+
+    /// Skip the text matched by a regular expression at the current position, ignoring delimiters. If the text there does not match, it will return `Ok(false)` and nothing will be consumed.
+    ///
+    /// ```rust
+    /// use regex::bytes::Regex;
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("ID: 42".as_bytes());
+    ///
+    /// let label = Regex::new(r"[A-Z]+:").unwrap();
+    ///
+    /// assert_eq!(true, sc.skip_pattern(&label).unwrap());
+    /// assert_eq!(false, sc.skip_pattern(&label).unwrap());
+    /// assert_eq!(Some(42), sc.next_u8().unwrap());
+    /// ```
+    pub fn skip_pattern(&mut self, regex: &Regex) -> Result<bool, ScannerError> {
+        Ok(self.consume_match_at(regex).is_some())
+    }
+
+    /// Find the text matched by a regular expression in the rest of the current line, ignoring delimiters. If it is found, the text before it will be skipped and the scanner will be positioned right after it. Otherwise, it will return `Ok(None)` and nothing will be consumed.
+    ///
+    /// ```rust
+    /// use regex::bytes::Regex;
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc =
+    ///     ScannerU8Slice::new("width=640 height=480\nfoo=bar".as_bytes());
+    ///
+    /// let number = Regex::new(r"\d+").unwrap();
+    ///
+    /// assert_eq!(Some("640".as_bytes()), sc.find_in_line(&number).unwrap());
+    /// assert_eq!(Some("480".as_bytes()), sc.find_in_line(&number).unwrap());
+    /// assert_eq!(None, sc.find_in_line(&number).unwrap());
+    /// assert_eq!(Some("".as_bytes()), sc.next_line().unwrap());
+    /// ```
+    pub fn find_in_line(&mut self, regex: &Regex) -> Result<Option<&'a [u8]>, ScannerError> {
+        let line = &self.data[self.position..];
+
+        let line_length = line.iter().position(|&e| e == b'\r' || e == b'\n').unwrap_or(line.len());
+
+        Ok(self.consume_match_in(regex, self.position + line_length))
+    }
+
+    /// Find the text matched by a regular expression within the next `horizon` bytes, ignoring delimiters. If `horizon` is `0`, the rest of the text will be searched. If it is found, the text before it will be skipped and the scanner will be positioned right after it. Otherwise, it will return `Ok(None)` and nothing will be consumed.
+    ///
+    /// ```rust
+    /// use regex::bytes::Regex;
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("a\nb\nABC".as_bytes());
+    ///
+    /// let code = Regex::new(r"[A-Z]{3}").unwrap();
+    ///
+    /// assert_eq!(None, sc.find_within_horizon(&code, 6).unwrap());
+    /// assert_eq!(
+    ///     Some("ABC".as_bytes()),
+    ///     sc.find_within_horizon(&code, 7).unwrap()
+    /// );
+    /// ```
+    pub fn find_within_horizon(
+        &mut self,
+        regex: &Regex,
+        horizon: usize,
+    ) -> Result<Option<&'a [u8]>, ScannerError> {
+        let end = if horizon == 0 {
+            self.data_length
+        } else {
+            self.data_length.min(self.position + horizon)
+        };
+
+        Ok(self.consume_match_in(regex, end))
+    }
+
+    #[inline]
+    fn consume_match_at(&mut self, regex: &Regex) -> Option<&'a [u8]> {
+        let m = regex.find_at(self.data, self.position)?;
+
+        if m.start() != self.position {
+            return None;
+        }
+
+        self.advance_to(m.end());
+
+        Some(m.as_bytes())
+    }
+
+    #[inline]
+    fn consume_match_in(&mut self, regex: &Regex, end: usize) -> Option<&'a [u8]> {
+        let m = regex.find_at(&self.data[..end], self.position)?;
+
+        self.advance_to(m.end());
+
+        Some(m.as_bytes())
+    }
+}
+
 impl<'a> ScannerU8Slice<'a> {
+    /// Read the next token and parse it to a number in the number format.
+    #[inline]
+    fn next_number<T: FromStr>(&mut self) -> Result<Option<T>, ScannerError>
+    where
+        <T as FromStr>::Err: Error + Send + Sync + 'static, {
+        self.skip_whitespaces()?;
+
+        let position = self.tracker.position();
+
+        let result = self.next()?;
+
+        match result {
+            Some(s) => Ok(Some(parse_number(
+                decode_utf8(s, position.offset, self.strict_utf8)?,
+                &self.number_format,
+                position,
+            )?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Read the next token separated by the delimiter and parse it to a value of any type which implements `FromStr`. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use std::net::IpAddr;
+    ///
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("true 127.0.0.1".as_bytes());
+    ///
+    /// assert_eq!(Some(true), sc.next_parse().unwrap());
+    /// assert_eq!(Some(IpAddr::from([127, 0, 0, 1])), sc.next_parse().unwrap());
+    /// ```
     #[inline]
-    fn next_parse<T: FromStr>(&mut self) -> Result<Option<T>, ScannerError>
+    pub fn next_parse<T: FromStr>(&mut self) -> Result<Option<T>, ScannerError>
     where
-        ScannerError: From<<T as FromStr>::Err>, {
+        <T as FromStr>::Err: Error + Send + Sync + 'static, {
+        self.skip_whitespaces()?;
+
+        let position = self.tracker.position();
+
         let result = self.next()?;
 
         match result {
-            Some(s) => Ok(Some(unsafe { from_utf8_unchecked(s) }.parse()?)),
+            Some(s) => {
+                Ok(Some(parse_token(decode_utf8(s, position.offset, self.strict_utf8)?, position)?))
+            },
             None => Ok(None),
         }
     }
 
-    /// Read the next token separated by whitespaces and parse it to a `u8` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Read the next token separated by the delimiter and parse it to a `u8` value. If there is nothing to read, it will return `Ok(None)`.
     ///
     /// ```rust
     /// use scanner_rust::ScannerU8Slice;
@@ -475,10 +976,10 @@ impl<'a> ScannerU8Slice<'a> {
     /// ```
     #[inline]
     pub fn next_u8(&mut self) -> Result<Option<u8>, ScannerError> {
-        self.next_parse()
+        self.next_parse_radix(10)
     }
 
-    /// Read the next token separated by whitespaces and parse it to a `u16` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Read the next token separated by the delimiter and parse it to a `u16` value. If there is nothing to read, it will return `Ok(None)`.
     ///
     /// ```rust
     /// use scanner_rust::ScannerU8Slice;
@@ -490,10 +991,10 @@ impl<'a> ScannerU8Slice<'a> {
     /// ```
     #[inline]
     pub fn next_u16(&mut self) -> Result<Option<u16>, ScannerError> {
-        self.next_parse()
+        self.next_parse_radix(10)
     }
 
-    /// Read the next token separated by whitespaces and parse it to a `u32` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Read the next token separated by the delimiter and parse it to a `u32` value. If there is nothing to read, it will return `Ok(None)`.
     ///
     /// ```rust
     /// use scanner_rust::ScannerU8Slice;
@@ -504,442 +1005,1668 @@ impl<'a> ScannerU8Slice<'a> {
     /// assert_eq!(Some(2), sc.next_u32().unwrap());
     /// ```
     #[inline]
-    pub fn next_u32(&mut self) -> Result<Option<u32>, ScannerError> {
-        self.next_parse()
+    pub fn next_u32(&mut self) -> Result<Option<u32>, ScannerError> {
+        self.next_parse_radix(10)
+    }
+
+    /// Read the next token separated by the delimiter and parse it to a `u64` value. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("1 2".as_bytes());
+    ///
+    /// assert_eq!(Some(1), sc.next_u64().unwrap());
+    /// assert_eq!(Some(2), sc.next_u64().unwrap());
+    /// ```
+    #[inline]
+    pub fn next_u64(&mut self) -> Result<Option<u64>, ScannerError> {
+        self.next_parse_radix(10)
+    }
+
+    /// Read the next token separated by the delimiter and parse it to a `u128` value. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("1 2".as_bytes());
+    ///
+    /// assert_eq!(Some(1), sc.next_u128().unwrap());
+    /// assert_eq!(Some(2), sc.next_u128().unwrap());
+    /// ```
+    #[inline]
+    pub fn next_u128(&mut self) -> Result<Option<u128>, ScannerError> {
+        self.next_parse_radix(10)
+    }
+
+    /// Read the next token separated by the delimiter and parse it to a `usize` value. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("1 2".as_bytes());
+    ///
+    /// assert_eq!(Some(1), sc.next_usize().unwrap());
+    /// assert_eq!(Some(2), sc.next_usize().unwrap());
+    /// ```
+    #[inline]
+    pub fn next_usize(&mut self) -> Result<Option<usize>, ScannerError> {
+        self.next_parse_radix(10)
+    }
+
+    /// Read the next token separated by the delimiter and parse it to a `i8` value. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("1 2".as_bytes());
+    ///
+    /// assert_eq!(Some(1), sc.next_i8().unwrap());
+    /// assert_eq!(Some(2), sc.next_i8().unwrap());
+    /// ```
+    #[inline]
+    pub fn next_i8(&mut self) -> Result<Option<i8>, ScannerError> {
+        self.next_parse_radix(10)
+    }
+
+    /// Read the next token separated by the delimiter and parse it to a `i16` value. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("1 2".as_bytes());
+    ///
+    /// assert_eq!(Some(1), sc.next_i16().unwrap());
+    /// assert_eq!(Some(2), sc.next_i16().unwrap());
+    /// ```
+    #[inline]
+    pub fn next_i16(&mut self) -> Result<Option<i16>, ScannerError> {
+        self.next_parse_radix(10)
+    }
+
+    /// Read the next token separated by the delimiter and parse it to a `i32` value. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("1 2".as_bytes());
+    ///
+    /// assert_eq!(Some(1), sc.next_i32().unwrap());
+    /// assert_eq!(Some(2), sc.next_i32().unwrap());
+    /// ```
+    #[inline]
+    pub fn next_i32(&mut self) -> Result<Option<i32>, ScannerError> {
+        self.next_parse_radix(10)
+    }
+
+    /// Read the next token separated by the delimiter and parse it to a `i64` value. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("1 2".as_bytes());
+    ///
+    /// assert_eq!(Some(1), sc.next_i64().unwrap());
+    /// assert_eq!(Some(2), sc.next_i64().unwrap());
+    /// ```
+    #[inline]
+    pub fn next_i64(&mut self) -> Result<Option<i64>, ScannerError> {
+        self.next_parse_radix(10)
+    }
+
+    /// Read the next token separated by the delimiter and parse it to a `i128` value. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("1 2".as_bytes());
+    ///
+    /// assert_eq!(Some(1), sc.next_i128().unwrap());
+    /// assert_eq!(Some(2), sc.next_i128().unwrap());
+    /// ```
+    #[inline]
+    pub fn next_i128(&mut self) -> Result<Option<i128>, ScannerError> {
+        self.next_parse_radix(10)
+    }
+
+    /// Read the next token separated by the delimiter and parse it to a `isize` value. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("1 2".as_bytes());
+    ///
+    /// assert_eq!(Some(1), sc.next_isize().unwrap());
+    /// assert_eq!(Some(2), sc.next_isize().unwrap());
+    /// ```
+    #[inline]
+    pub fn next_isize(&mut self) -> Result<Option<isize>, ScannerError> {
+        self.next_parse_radix(10)
+    }
+
+    /// Read the next token separated by the delimiter and parse it to a `f32` value. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("1 2.5".as_bytes());
+    ///
+    /// assert_eq!(Some(1.0), sc.next_f32().unwrap());
+    /// assert_eq!(Some(2.5), sc.next_f32().unwrap());
+    /// ```
+    #[inline]
+    pub fn next_f32(&mut self) -> Result<Option<f32>, ScannerError> {
+        self.next_number()
+    }
+
+    /// Read the next token separated by the delimiter and parse it to a `f64` value. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("1 2.5".as_bytes());
+    ///
+    /// assert_eq!(Some(1.0), sc.next_f64().unwrap());
+    /// assert_eq!(Some(2.5), sc.next_f64().unwrap());
+    /// ```
+    #[inline]
+    pub fn next_f64(&mut self) -> Result<Option<f64>, ScannerError> {
+        self.next_number()
+    }
+}
+
+impl<'a> ScannerU8Slice<'a> {
+    /// Check whether there is a next token separated by delimiters without consuming it.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new(" 123 ".as_bytes());
+    ///
+    /// assert_eq!(true, sc.has_next().unwrap());
+    /// assert_eq!(Some("123".as_bytes()), sc.next().unwrap());
+    /// assert_eq!(false, sc.has_next().unwrap());
+    /// ```
+    pub fn has_next(&mut self) -> Result<bool, ScannerError> {
+        let (position, tracker) = (self.position, self.tracker);
+
+        let result = self.next()?.is_some();
+
+        self.position = position;
+        self.tracker = tracker;
+
+        Ok(result)
+    }
+
+    /// Check whether there is a next line, which may be empty, without consuming it.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("123\n".as_bytes());
+    ///
+    /// assert_eq!(true, sc.has_next_line().unwrap());
+    /// assert_eq!(Some("123".as_bytes()), sc.next_line().unwrap());
+    /// assert_eq!(false, sc.has_next_line().unwrap());
+    /// ```
+    pub fn has_next_line(&mut self) -> Result<bool, ScannerError> {
+        Ok(self.position < self.data_length)
+    }
+
+    /// Check whether the next token separated by delimiters can be parsed to a value of any type which implements `FromStr`, without consuming it.
+    ///
+    /// ```rust
+    /// use std::net::IpAddr;
+    ///
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("127.0.0.1 x".as_bytes());
+    ///
+    /// assert_eq!(true, sc.has_next_parse::<IpAddr>().unwrap());
+    /// assert_eq!(Some(IpAddr::from([127, 0, 0, 1])), sc.next_parse().unwrap());
+    /// assert_eq!(false, sc.has_next_parse::<IpAddr>().unwrap());
+    /// assert_eq!(Some("x".as_bytes()), sc.next().unwrap());
+    /// ```
+    pub fn has_next_parse<T: FromStr>(&mut self) -> Result<bool, ScannerError> {
+        let (position, tracker) = (self.position, self.tracker);
+
+        let result = match self.next()? {
+            Some(s) => String::from_utf8_lossy(s).parse::<T>().is_ok(),
+            None => false,
+        };
+
+        self.position = position;
+        self.tracker = tracker;
+
+        Ok(result)
+    }
+
+    /// Check whether the next token can be parsed to a number in the number format, without consuming it.
+    #[inline]
+    fn has_next_number<T: FromStr>(&mut self) -> Result<bool, ScannerError> {
+        let (position, tracker) = (self.position, self.tracker);
+
+        let result = match self.next()? {
+            Some(s) => {
+                self.number_format.normalize(&String::from_utf8_lossy(s)).parse::<T>().is_ok()
+            },
+            None => false,
+        };
+
+        self.position = position;
+        self.tracker = tracker;
+
+        Ok(result)
+    }
+
+    /// Check whether the next token can be parsed to an integer in the number format, without consuming it.
+    #[inline]
+    fn has_next_integer<T: Integer>(&mut self) -> Result<bool, ScannerError> {
+        let (position, tracker) = (self.position, self.tracker);
+
+        let result = match self.next()? {
+            Some(s) => parse_integer::<T, _>(
+                String::from_utf8_lossy(s),
+                10,
+                &self.number_format,
+                self.tracker.position(),
+            )
+            .is_ok(),
+            None => false,
+        };
+
+        self.position = position;
+        self.tracker = tracker;
+
+        Ok(result)
+    }
+
+    /// Check whether the next token separated by delimiters can be parsed to a `u8` value without consuming it.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("12 x".as_bytes());
+    ///
+    /// assert_eq!(true, sc.has_next_u8().unwrap());
+    /// assert_eq!(Some(12), sc.next_u8().unwrap());
+    /// assert_eq!(false, sc.has_next_u8().unwrap());
+    /// ```
+    #[inline]
+    pub fn has_next_u8(&mut self) -> Result<bool, ScannerError> {
+        self.has_next_integer::<u8>()
+    }
+
+    /// Check whether the next token separated by delimiters can be parsed to a `u16` value without consuming it.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("12 x".as_bytes());
+    ///
+    /// assert_eq!(true, sc.has_next_u16().unwrap());
+    /// assert_eq!(Some(12), sc.next_u16().unwrap());
+    /// assert_eq!(false, sc.has_next_u16().unwrap());
+    /// ```
+    #[inline]
+    pub fn has_next_u16(&mut self) -> Result<bool, ScannerError> {
+        self.has_next_integer::<u16>()
+    }
+
+    /// Check whether the next token separated by delimiters can be parsed to a `u32` value without consuming it.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("12 x".as_bytes());
+    ///
+    /// assert_eq!(true, sc.has_next_u32().unwrap());
+    /// assert_eq!(Some(12), sc.next_u32().unwrap());
+    /// assert_eq!(false, sc.has_next_u32().unwrap());
+    /// ```
+    #[inline]
+    pub fn has_next_u32(&mut self) -> Result<bool, ScannerError> {
+        self.has_next_integer::<u32>()
+    }
+
+    /// Check whether the next token separated by delimiters can be parsed to a `u64` value without consuming it.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("12 x".as_bytes());
+    ///
+    /// assert_eq!(true, sc.has_next_u64().unwrap());
+    /// assert_eq!(Some(12), sc.next_u64().unwrap());
+    /// assert_eq!(false, sc.has_next_u64().unwrap());
+    /// ```
+    #[inline]
+    pub fn has_next_u64(&mut self) -> Result<bool, ScannerError> {
+        self.has_next_integer::<u64>()
+    }
+
+    /// Check whether the next token separated by delimiters can be parsed to a `u128` value without consuming it.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("12 x".as_bytes());
+    ///
+    /// assert_eq!(true, sc.has_next_u128().unwrap());
+    /// assert_eq!(Some(12), sc.next_u128().unwrap());
+    /// assert_eq!(false, sc.has_next_u128().unwrap());
+    /// ```
+    #[inline]
+    pub fn has_next_u128(&mut self) -> Result<bool, ScannerError> {
+        self.has_next_integer::<u128>()
+    }
+
+    /// Check whether the next token separated by delimiters can be parsed to a `usize` value without consuming it.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("12 x".as_bytes());
+    ///
+    /// assert_eq!(true, sc.has_next_usize().unwrap());
+    /// assert_eq!(Some(12), sc.next_usize().unwrap());
+    /// assert_eq!(false, sc.has_next_usize().unwrap());
+    /// ```
+    #[inline]
+    pub fn has_next_usize(&mut self) -> Result<bool, ScannerError> {
+        self.has_next_integer::<usize>()
+    }
+
+    /// Check whether the next token separated by delimiters can be parsed to a `i8` value without consuming it.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("12 x".as_bytes());
+    ///
+    /// assert_eq!(true, sc.has_next_i8().unwrap());
+    /// assert_eq!(Some(12), sc.next_i8().unwrap());
+    /// assert_eq!(false, sc.has_next_i8().unwrap());
+    /// ```
+    #[inline]
+    pub fn has_next_i8(&mut self) -> Result<bool, ScannerError> {
+        self.has_next_integer::<i8>()
+    }
+
+    /// Check whether the next token separated by delimiters can be parsed to a `i16` value without consuming it.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("12 x".as_bytes());
+    ///
+    /// assert_eq!(true, sc.has_next_i16().unwrap());
+    /// assert_eq!(Some(12), sc.next_i16().unwrap());
+    /// assert_eq!(false, sc.has_next_i16().unwrap());
+    /// ```
+    #[inline]
+    pub fn has_next_i16(&mut self) -> Result<bool, ScannerError> {
+        self.has_next_integer::<i16>()
+    }
+
+    /// Check whether the next token separated by delimiters can be parsed to a `i32` value without consuming it.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("12 x".as_bytes());
+    ///
+    /// assert_eq!(true, sc.has_next_i32().unwrap());
+    /// assert_eq!(Some(12), sc.next_i32().unwrap());
+    /// assert_eq!(false, sc.has_next_i32().unwrap());
+    /// ```
+    #[inline]
+    pub fn has_next_i32(&mut self) -> Result<bool, ScannerError> {
+        self.has_next_integer::<i32>()
+    }
+
+    /// Check whether the next token separated by delimiters can be parsed to a `i64` value without consuming it.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("12 x".as_bytes());
+    ///
+    /// assert_eq!(true, sc.has_next_i64().unwrap());
+    /// assert_eq!(Some(12), sc.next_i64().unwrap());
+    /// assert_eq!(false, sc.has_next_i64().unwrap());
+    /// ```
+    #[inline]
+    pub fn has_next_i64(&mut self) -> Result<bool, ScannerError> {
+        self.has_next_integer::<i64>()
+    }
+
+    /// Check whether the next token separated by delimiters can be parsed to a `i128` value without consuming it.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("12 x".as_bytes());
+    ///
+    /// assert_eq!(true, sc.has_next_i128().unwrap());
+    /// assert_eq!(Some(12), sc.next_i128().unwrap());
+    /// assert_eq!(false, sc.has_next_i128().unwrap());
+    /// ```
+    #[inline]
+    pub fn has_next_i128(&mut self) -> Result<bool, ScannerError> {
+        self.has_next_integer::<i128>()
+    }
+
+    /// Check whether the next token separated by delimiters can be parsed to a `isize` value without consuming it.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("12 x".as_bytes());
+    ///
+    /// assert_eq!(true, sc.has_next_isize().unwrap());
+    /// assert_eq!(Some(12), sc.next_isize().unwrap());
+    /// assert_eq!(false, sc.has_next_isize().unwrap());
+    /// ```
+    #[inline]
+    pub fn has_next_isize(&mut self) -> Result<bool, ScannerError> {
+        self.has_next_integer::<isize>()
+    }
+
+    /// Check whether the next token separated by delimiters can be parsed to a `f32` value without consuming it.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("1.5 x".as_bytes());
+    ///
+    /// assert_eq!(true, sc.has_next_f32().unwrap());
+    /// assert_eq!(Some(1.5), sc.next_f32().unwrap());
+    /// assert_eq!(false, sc.has_next_f32().unwrap());
+    /// ```
+    #[inline]
+    pub fn has_next_f32(&mut self) -> Result<bool, ScannerError> {
+        self.has_next_number::<f32>()
+    }
+
+    /// Check whether the next token separated by delimiters can be parsed to a `f64` value without consuming it.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("1.5 x".as_bytes());
+    ///
+    /// assert_eq!(true, sc.has_next_f64().unwrap());
+    /// assert_eq!(Some(1.5), sc.next_f64().unwrap());
+    /// assert_eq!(false, sc.has_next_f64().unwrap());
+    /// ```
+    #[inline]
+    pub fn has_next_f64(&mut self) -> Result<bool, ScannerError> {
+        self.has_next_number::<f64>()
+    }
+}
+
+impl<'a> ScannerU8Slice<'a> {
+    /// Read the next text until it reaches a specific boundary and parse it to a number in the number format.
+    #[inline]
+    fn next_number_until<T: FromStr, D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+    ) -> Result<Option<T>, ScannerError>
+    where
+        <T as FromStr>::Err: Error + Send + Sync + 'static, {
+        let position = self.tracker.position();
+
+        let result = self.next_until(boundary)?;
+
+        match result {
+            Some(s) => Ok(Some(parse_number(
+                decode_utf8(s, position.offset, self.strict_utf8)?,
+                &self.number_format,
+                position,
+            )?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Read the next text until it reaches a specific boundary and parse it to a value of any type which implements `FromStr`. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use std::net::IpAddr;
+    ///
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("true,127.0.0.1".as_bytes());
+    ///
+    /// assert_eq!(Some(true), sc.next_parse_until(",").unwrap());
+    /// assert_eq!(
+    ///     Some(IpAddr::from([127, 0, 0, 1])),
+    ///     sc.next_parse_until(",").unwrap()
+    /// );
+    /// ```
+    #[inline]
+    pub fn next_parse_until<T: FromStr, D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+    ) -> Result<Option<T>, ScannerError>
+    where
+        <T as FromStr>::Err: Error + Send + Sync + 'static, {
+        let position = self.tracker.position();
+
+        let result = self.next_until(boundary)?;
+
+        match result {
+            Some(s) => {
+                Ok(Some(parse_token(decode_utf8(s, position.offset, self.strict_utf8)?, position)?))
+            },
+            None => Ok(None),
+        }
+    }
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `u8` value. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("1 2".as_bytes());
+    ///
+    /// assert_eq!(Some(1), sc.next_u8_until(" ").unwrap());
+    /// assert_eq!(Some(2), sc.next_u8_until(" ").unwrap());
+    /// ```
+    #[inline]
+    pub fn next_u8_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+    ) -> Result<Option<u8>, ScannerError> {
+        self.next_parse_radix_until(boundary, 10)
+    }
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `u16` value. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("1 2".as_bytes());
+    ///
+    /// assert_eq!(Some(1), sc.next_u16_until(" ").unwrap());
+    /// assert_eq!(Some(2), sc.next_u16_until(" ").unwrap());
+    /// ```
+    #[inline]
+    pub fn next_u16_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+    ) -> Result<Option<u16>, ScannerError> {
+        self.next_parse_radix_until(boundary, 10)
+    }
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `u32` value. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("1 2".as_bytes());
+    ///
+    /// assert_eq!(Some(1), sc.next_u32_until(" ").unwrap());
+    /// assert_eq!(Some(2), sc.next_u32_until(" ").unwrap());
+    /// ```
+    #[inline]
+    pub fn next_u32_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+    ) -> Result<Option<u32>, ScannerError> {
+        self.next_parse_radix_until(boundary, 10)
+    }
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `u64` value. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("1 2".as_bytes());
+    ///
+    /// assert_eq!(Some(1), sc.next_u64_until(" ").unwrap());
+    /// assert_eq!(Some(2), sc.next_u64_until(" ").unwrap());
+    /// ```
+    #[inline]
+    pub fn next_u64_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+    ) -> Result<Option<u64>, ScannerError> {
+        self.next_parse_radix_until(boundary, 10)
+    }
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `u128` value. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("1 2".as_bytes());
+    ///
+    /// assert_eq!(Some(1), sc.next_u128_until(" ").unwrap());
+    /// assert_eq!(Some(2), sc.next_u128_until(" ").unwrap());
+    /// ```
+    #[inline]
+    pub fn next_u128_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+    ) -> Result<Option<u128>, ScannerError> {
+        self.next_parse_radix_until(boundary, 10)
+    }
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `usize` value. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("1 2".as_bytes());
+    ///
+    /// assert_eq!(Some(1), sc.next_usize_until(" ").unwrap());
+    /// assert_eq!(Some(2), sc.next_usize_until(" ").unwrap());
+    /// ```
+    #[inline]
+    pub fn next_usize_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+    ) -> Result<Option<usize>, ScannerError> {
+        self.next_parse_radix_until(boundary, 10)
+    }
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `i8` value. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("1 2".as_bytes());
+    ///
+    /// assert_eq!(Some(1), sc.next_i8_until(" ").unwrap());
+    /// assert_eq!(Some(2), sc.next_i8_until(" ").unwrap());
+    /// ```
+    #[inline]
+    pub fn next_i8_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+    ) -> Result<Option<i8>, ScannerError> {
+        self.next_parse_radix_until(boundary, 10)
+    }
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `i16` value. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("1 2".as_bytes());
+    ///
+    /// assert_eq!(Some(1), sc.next_i16_until(" ").unwrap());
+    /// assert_eq!(Some(2), sc.next_i16_until(" ").unwrap());
+    /// ```
+    #[inline]
+    pub fn next_i16_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+    ) -> Result<Option<i16>, ScannerError> {
+        self.next_parse_radix_until(boundary, 10)
+    }
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `i32` value. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("1 2".as_bytes());
+    ///
+    /// assert_eq!(Some(1), sc.next_i32_until(" ").unwrap());
+    /// assert_eq!(Some(2), sc.next_i32_until(" ").unwrap());
+    /// ```
+    #[inline]
+    pub fn next_i32_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+    ) -> Result<Option<i32>, ScannerError> {
+        self.next_parse_radix_until(boundary, 10)
+    }
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `i64` value. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("1 2".as_bytes());
+    ///
+    /// assert_eq!(Some(1), sc.next_i64_until(" ").unwrap());
+    /// assert_eq!(Some(2), sc.next_i64_until(" ").unwrap());
+    /// ```
+    #[inline]
+    pub fn next_i64_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+    ) -> Result<Option<i64>, ScannerError> {
+        self.next_parse_radix_until(boundary, 10)
+    }
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `i128` value. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("1 2".as_bytes());
+    ///
+    /// assert_eq!(Some(1), sc.next_i128_until(" ").unwrap());
+    /// assert_eq!(Some(2), sc.next_i128_until(" ").unwrap());
+    /// ```
+    #[inline]
+    pub fn next_i128_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+    ) -> Result<Option<i128>, ScannerError> {
+        self.next_parse_radix_until(boundary, 10)
+    }
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `isize` value. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("1 2".as_bytes());
+    ///
+    /// assert_eq!(Some(1), sc.next_isize_until(" ").unwrap());
+    /// assert_eq!(Some(2), sc.next_isize_until(" ").unwrap());
+    /// ```
+    #[inline]
+    pub fn next_isize_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+    ) -> Result<Option<isize>, ScannerError> {
+        self.next_parse_radix_until(boundary, 10)
+    }
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `f32` value. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("1 2.5".as_bytes());
+    ///
+    /// assert_eq!(Some(1.0), sc.next_f32_until(" ").unwrap());
+    /// assert_eq!(Some(2.5), sc.next_f32_until(" ").unwrap());
+    /// ```
+    #[inline]
+    pub fn next_f32_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+    ) -> Result<Option<f32>, ScannerError> {
+        self.next_number_until(boundary)
+    }
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `f64` value. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("1 2.5".as_bytes());
+    ///
+    /// assert_eq!(Some(1.0), sc.next_f64_until(" ").unwrap());
+    /// assert_eq!(Some(2.5), sc.next_f64_until(" ").unwrap());
+    /// ```
+    #[inline]
+    pub fn next_f64_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+    ) -> Result<Option<f64>, ScannerError> {
+        self.next_number_until(boundary)
+    }
+}
+
+impl<'a> ScannerU8Slice<'a> {
+    #[inline]
+    fn next_parse_radix<T: Integer>(&mut self, radix: u32) -> Result<Option<T>, ScannerError> {
+        check_radix(radix)?;
+
+        self.skip_whitespaces()?;
+
+        let position = self.tracker.position();
+
+        let result = self.next()?;
+
+        match result {
+            Some(s) => Ok(Some(parse_integer(
+                decode_utf8(s, position.offset, self.strict_utf8)?,
+                radix,
+                &self.number_format,
+                position,
+            )?)),
+            None => Ok(None),
+        }
+    }
+
+    #[inline]
+    fn next_parse_radix_until<T: Integer, D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+        radix: u32,
+    ) -> Result<Option<T>, ScannerError> {
+        check_radix(radix)?;
+
+        let position = self.tracker.position();
+
+        let result = self.next_until(boundary)?;
+
+        match result {
+            Some(s) => Ok(Some(parse_integer(
+                decode_utf8(s, position.offset, self.strict_utf8)?,
+                radix,
+                &self.number_format,
+                position,
+            )?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Read the next token separated by the delimiter and parse it to a `u8` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("7f 0b101".as_bytes());
+    ///
+    /// assert_eq!(Some(127), sc.next_u8_radix(16).unwrap());
+    /// assert_eq!(Some(5), sc.next_u8_radix(0).unwrap());
+    /// ```
+    #[inline]
+    pub fn next_u8_radix(&mut self, radix: u32) -> Result<Option<u8>, ScannerError> {
+        self.next_parse_radix(radix)
+    }
+
+    /// Read the next token separated by the delimiter and parse it to a `u16` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("7f 0b101".as_bytes());
+    ///
+    /// assert_eq!(Some(127), sc.next_u16_radix(16).unwrap());
+    /// assert_eq!(Some(5), sc.next_u16_radix(0).unwrap());
+    /// ```
+    #[inline]
+    pub fn next_u16_radix(&mut self, radix: u32) -> Result<Option<u16>, ScannerError> {
+        self.next_parse_radix(radix)
+    }
+
+    /// Read the next token separated by the delimiter and parse it to a `u32` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("7f 0b101".as_bytes());
+    ///
+    /// assert_eq!(Some(127), sc.next_u32_radix(16).unwrap());
+    /// assert_eq!(Some(5), sc.next_u32_radix(0).unwrap());
+    /// ```
+    #[inline]
+    pub fn next_u32_radix(&mut self, radix: u32) -> Result<Option<u32>, ScannerError> {
+        self.next_parse_radix(radix)
+    }
+
+    /// Read the next token separated by the delimiter and parse it to a `u64` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("7f 0b101".as_bytes());
+    ///
+    /// assert_eq!(Some(127), sc.next_u64_radix(16).unwrap());
+    /// assert_eq!(Some(5), sc.next_u64_radix(0).unwrap());
+    /// ```
+    #[inline]
+    pub fn next_u64_radix(&mut self, radix: u32) -> Result<Option<u64>, ScannerError> {
+        self.next_parse_radix(radix)
+    }
+
+    /// Read the next token separated by the delimiter and parse it to a `u128` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("7f 0b101".as_bytes());
+    ///
+    /// assert_eq!(Some(127), sc.next_u128_radix(16).unwrap());
+    /// assert_eq!(Some(5), sc.next_u128_radix(0).unwrap());
+    /// ```
+    #[inline]
+    pub fn next_u128_radix(&mut self, radix: u32) -> Result<Option<u128>, ScannerError> {
+        self.next_parse_radix(radix)
+    }
+
+    /// Read the next token separated by the delimiter and parse it to a `usize` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("7f 0b101".as_bytes());
+    ///
+    /// assert_eq!(Some(127), sc.next_usize_radix(16).unwrap());
+    /// assert_eq!(Some(5), sc.next_usize_radix(0).unwrap());
+    /// ```
+    #[inline]
+    pub fn next_usize_radix(&mut self, radix: u32) -> Result<Option<usize>, ScannerError> {
+        self.next_parse_radix(radix)
+    }
+
+    /// Read the next token separated by the delimiter and parse it to a `i8` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("-7f 0b101".as_bytes());
+    ///
+    /// assert_eq!(Some(-127), sc.next_i8_radix(16).unwrap());
+    /// assert_eq!(Some(5), sc.next_i8_radix(0).unwrap());
+    /// ```
+    #[inline]
+    pub fn next_i8_radix(&mut self, radix: u32) -> Result<Option<i8>, ScannerError> {
+        self.next_parse_radix(radix)
+    }
+
+    /// Read the next token separated by the delimiter and parse it to a `i16` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("-7f 0b101".as_bytes());
+    ///
+    /// assert_eq!(Some(-127), sc.next_i16_radix(16).unwrap());
+    /// assert_eq!(Some(5), sc.next_i16_radix(0).unwrap());
+    /// ```
+    #[inline]
+    pub fn next_i16_radix(&mut self, radix: u32) -> Result<Option<i16>, ScannerError> {
+        self.next_parse_radix(radix)
+    }
+
+    /// Read the next token separated by the delimiter and parse it to a `i32` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("-7f 0b101".as_bytes());
+    ///
+    /// assert_eq!(Some(-127), sc.next_i32_radix(16).unwrap());
+    /// assert_eq!(Some(5), sc.next_i32_radix(0).unwrap());
+    /// ```
+    #[inline]
+    pub fn next_i32_radix(&mut self, radix: u32) -> Result<Option<i32>, ScannerError> {
+        self.next_parse_radix(radix)
+    }
+
+    /// Read the next token separated by the delimiter and parse it to a `i64` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("-7f 0b101".as_bytes());
+    ///
+    /// assert_eq!(Some(-127), sc.next_i64_radix(16).unwrap());
+    /// assert_eq!(Some(5), sc.next_i64_radix(0).unwrap());
+    /// ```
+    #[inline]
+    pub fn next_i64_radix(&mut self, radix: u32) -> Result<Option<i64>, ScannerError> {
+        self.next_parse_radix(radix)
+    }
+
+    /// Read the next token separated by the delimiter and parse it to a `i128` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("-7f 0b101".as_bytes());
+    ///
+    /// assert_eq!(Some(-127), sc.next_i128_radix(16).unwrap());
+    /// assert_eq!(Some(5), sc.next_i128_radix(0).unwrap());
+    /// ```
+    #[inline]
+    pub fn next_i128_radix(&mut self, radix: u32) -> Result<Option<i128>, ScannerError> {
+        self.next_parse_radix(radix)
+    }
+
+    /// Read the next token separated by the delimiter and parse it to a `isize` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("-7f 0b101".as_bytes());
+    ///
+    /// assert_eq!(Some(-127), sc.next_isize_radix(16).unwrap());
+    /// assert_eq!(Some(5), sc.next_isize_radix(0).unwrap());
+    /// ```
+    #[inline]
+    pub fn next_isize_radix(&mut self, radix: u32) -> Result<Option<isize>, ScannerError> {
+        self.next_parse_radix(radix)
+    }
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `u8` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8Slice;
+    ///
+    /// let mut sc = ScannerU8Slice::new("7f,0b101".as_bytes());
+    ///
+    /// assert_eq!(Some(127), sc.next_u8_radix_until(",", 16).unwrap());
+    /// assert_eq!(Some(5), sc.next_u8_radix_until(",", 0).unwrap());
+    /// ```
+    #[inline]
+    pub fn next_u8_radix_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+        radix: u32,
+    ) -> Result<Option<u8>, ScannerError> {
+        self.next_parse_radix_until(boundary, radix)
     }
 
-    /// Read the next token separated by whitespaces and parse it to a `u64` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Read the next text until it reaches a specific boundary and parse it to a `u16` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
     ///
     /// ```rust
     /// use scanner_rust::ScannerU8Slice;
     ///
-    /// let mut sc = ScannerU8Slice::new("1 2".as_bytes());
+    /// let mut sc = ScannerU8Slice::new("7f,0b101".as_bytes());
     ///
-    /// assert_eq!(Some(1), sc.next_u64().unwrap());
-    /// assert_eq!(Some(2), sc.next_u64().unwrap());
+    /// assert_eq!(Some(127), sc.next_u16_radix_until(",", 16).unwrap());
+    /// assert_eq!(Some(5), sc.next_u16_radix_until(",", 0).unwrap());
     /// ```
     #[inline]
-    pub fn next_u64(&mut self) -> Result<Option<u64>, ScannerError> {
-        self.next_parse()
+    pub fn next_u16_radix_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+        radix: u32,
+    ) -> Result<Option<u16>, ScannerError> {
+        self.next_parse_radix_until(boundary, radix)
     }
 
-    /// Read the next token separated by whitespaces and parse it to a `u128` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Read the next text until it reaches a specific boundary and parse it to a `u32` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
     ///
     /// ```rust
     /// use scanner_rust::ScannerU8Slice;
     ///
-    /// let mut sc = ScannerU8Slice::new("1 2".as_bytes());
+    /// let mut sc = ScannerU8Slice::new("7f,0b101".as_bytes());
     ///
-    /// assert_eq!(Some(1), sc.next_u128().unwrap());
-    /// assert_eq!(Some(2), sc.next_u128().unwrap());
+    /// assert_eq!(Some(127), sc.next_u32_radix_until(",", 16).unwrap());
+    /// assert_eq!(Some(5), sc.next_u32_radix_until(",", 0).unwrap());
     /// ```
     #[inline]
-    pub fn next_u128(&mut self) -> Result<Option<u128>, ScannerError> {
-        self.next_parse()
+    pub fn next_u32_radix_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+        radix: u32,
+    ) -> Result<Option<u32>, ScannerError> {
+        self.next_parse_radix_until(boundary, radix)
     }
 
-    /// Read the next token separated by whitespaces and parse it to a `usize` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Read the next text until it reaches a specific boundary and parse it to a `u64` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
     ///
     /// ```rust
     /// use scanner_rust::ScannerU8Slice;
     ///
-    /// let mut sc = ScannerU8Slice::new("1 2".as_bytes());
+    /// let mut sc = ScannerU8Slice::new("7f,0b101".as_bytes());
     ///
-    /// assert_eq!(Some(1), sc.next_usize().unwrap());
-    /// assert_eq!(Some(2), sc.next_usize().unwrap());
+    /// assert_eq!(Some(127), sc.next_u64_radix_until(",", 16).unwrap());
+    /// assert_eq!(Some(5), sc.next_u64_radix_until(",", 0).unwrap());
     /// ```
     #[inline]
-    pub fn next_usize(&mut self) -> Result<Option<usize>, ScannerError> {
-        self.next_parse()
+    pub fn next_u64_radix_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+        radix: u32,
+    ) -> Result<Option<u64>, ScannerError> {
+        self.next_parse_radix_until(boundary, radix)
     }
 
-    /// Read the next token separated by whitespaces and parse it to a `i8` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Read the next text until it reaches a specific boundary and parse it to a `u128` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
     ///
     /// ```rust
     /// use scanner_rust::ScannerU8Slice;
     ///
-    /// let mut sc = ScannerU8Slice::new("1 2".as_bytes());
+    /// let mut sc = ScannerU8Slice::new("7f,0b101".as_bytes());
     ///
-    /// assert_eq!(Some(1), sc.next_i8().unwrap());
-    /// assert_eq!(Some(2), sc.next_i8().unwrap());
+    /// assert_eq!(Some(127), sc.next_u128_radix_until(",", 16).unwrap());
+    /// assert_eq!(Some(5), sc.next_u128_radix_until(",", 0).unwrap());
     /// ```
     #[inline]
-    pub fn next_i8(&mut self) -> Result<Option<i8>, ScannerError> {
-        self.next_parse()
+    pub fn next_u128_radix_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+        radix: u32,
+    ) -> Result<Option<u128>, ScannerError> {
+        self.next_parse_radix_until(boundary, radix)
     }
 
-    /// Read the next token separated by whitespaces and parse it to a `i16` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Read the next text until it reaches a specific boundary and parse it to a `usize` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
     ///
     /// ```rust
     /// use scanner_rust::ScannerU8Slice;
     ///
-    /// let mut sc = ScannerU8Slice::new("1 2".as_bytes());
+    /// let mut sc = ScannerU8Slice::new("7f,0b101".as_bytes());
     ///
-    /// assert_eq!(Some(1), sc.next_i16().unwrap());
-    /// assert_eq!(Some(2), sc.next_i16().unwrap());
+    /// assert_eq!(Some(127), sc.next_usize_radix_until(",", 16).unwrap());
+    /// assert_eq!(Some(5), sc.next_usize_radix_until(",", 0).unwrap());
     /// ```
     #[inline]
-    pub fn next_i16(&mut self) -> Result<Option<i16>, ScannerError> {
-        self.next_parse()
+    pub fn next_usize_radix_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+        radix: u32,
+    ) -> Result<Option<usize>, ScannerError> {
+        self.next_parse_radix_until(boundary, radix)
     }
 
-    /// Read the next token separated by whitespaces and parse it to a `i32` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Read the next text until it reaches a specific boundary and parse it to a `i8` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
     ///
     /// ```rust
     /// use scanner_rust::ScannerU8Slice;
     ///
-    /// let mut sc = ScannerU8Slice::new("1 2".as_bytes());
+    /// let mut sc = ScannerU8Slice::new("-7f,0b101".as_bytes());
     ///
-    /// assert_eq!(Some(1), sc.next_i32().unwrap());
-    /// assert_eq!(Some(2), sc.next_i32().unwrap());
+    /// assert_eq!(Some(-127), sc.next_i8_radix_until(",", 16).unwrap());
+    /// assert_eq!(Some(5), sc.next_i8_radix_until(",", 0).unwrap());
     /// ```
     #[inline]
-    pub fn next_i32(&mut self) -> Result<Option<i32>, ScannerError> {
-        self.next_parse()
+    pub fn next_i8_radix_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+        radix: u32,
+    ) -> Result<Option<i8>, ScannerError> {
+        self.next_parse_radix_until(boundary, radix)
     }
 
-    /// Read the next token separated by whitespaces and parse it to a `i64` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Read the next text until it reaches a specific boundary and parse it to a `i16` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
     ///
     /// ```rust
     /// use scanner_rust::ScannerU8Slice;
     ///
-    /// let mut sc = ScannerU8Slice::new("1 2".as_bytes());
+    /// let mut sc = ScannerU8Slice::new("-7f,0b101".as_bytes());
     ///
-    /// assert_eq!(Some(1), sc.next_i64().unwrap());
-    /// assert_eq!(Some(2), sc.next_i64().unwrap());
+    /// assert_eq!(Some(-127), sc.next_i16_radix_until(",", 16).unwrap());
+    /// assert_eq!(Some(5), sc.next_i16_radix_until(",", 0).unwrap());
     /// ```
     #[inline]
-    pub fn next_i64(&mut self) -> Result<Option<i64>, ScannerError> {
-        self.next_parse()
+    pub fn next_i16_radix_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+        radix: u32,
+    ) -> Result<Option<i16>, ScannerError> {
+        self.next_parse_radix_until(boundary, radix)
     }
 
-    /// Read the next token separated by whitespaces and parse it to a `i128` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Read the next text until it reaches a specific boundary and parse it to a `i32` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
     ///
     /// ```rust
     /// use scanner_rust::ScannerU8Slice;
     ///
-    /// let mut sc = ScannerU8Slice::new("1 2".as_bytes());
+    /// let mut sc = ScannerU8Slice::new("-7f,0b101".as_bytes());
     ///
-    /// assert_eq!(Some(1), sc.next_i128().unwrap());
-    /// assert_eq!(Some(2), sc.next_i128().unwrap());
+    /// assert_eq!(Some(-127), sc.next_i32_radix_until(",", 16).unwrap());
+    /// assert_eq!(Some(5), sc.next_i32_radix_until(",", 0).unwrap());
     /// ```
     #[inline]
-    pub fn next_i128(&mut self) -> Result<Option<i128>, ScannerError> {
-        self.next_parse()
+    pub fn next_i32_radix_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+        radix: u32,
+    ) -> Result<Option<i32>, ScannerError> {
+        self.next_parse_radix_until(boundary, radix)
     }
 
-    /// Read the next token separated by whitespaces and parse it to a `isize` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Read the next text until it reaches a specific boundary and parse it to a `i64` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
     ///
     /// ```rust
     /// use scanner_rust::ScannerU8Slice;
     ///
-    /// let mut sc = ScannerU8Slice::new("1 2".as_bytes());
+    /// let mut sc = ScannerU8Slice::new("-7f,0b101".as_bytes());
     ///
-    /// assert_eq!(Some(1), sc.next_isize().unwrap());
-    /// assert_eq!(Some(2), sc.next_isize().unwrap());
+    /// assert_eq!(Some(-127), sc.next_i64_radix_until(",", 16).unwrap());
+    /// assert_eq!(Some(5), sc.next_i64_radix_until(",", 0).unwrap());
     /// ```
     #[inline]
-    pub fn next_isize(&mut self) -> Result<Option<isize>, ScannerError> {
-        self.next_parse()
+    pub fn next_i64_radix_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+        radix: u32,
+    ) -> Result<Option<i64>, ScannerError> {
+        self.next_parse_radix_until(boundary, radix)
     }
 
-    /// Read the next token separated by whitespaces and parse it to a `f32` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Read the next text until it reaches a specific boundary and parse it to a `i128` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
     ///
     /// ```rust
     /// use scanner_rust::ScannerU8Slice;
     ///
-    /// let mut sc = ScannerU8Slice::new("1 2.5".as_bytes());
+    /// let mut sc = ScannerU8Slice::new("-7f,0b101".as_bytes());
     ///
-    /// assert_eq!(Some(1.0), sc.next_f32().unwrap());
-    /// assert_eq!(Some(2.5), sc.next_f32().unwrap());
+    /// assert_eq!(Some(-127), sc.next_i128_radix_until(",", 16).unwrap());
+    /// assert_eq!(Some(5), sc.next_i128_radix_until(",", 0).unwrap());
     /// ```
     #[inline]
-    pub fn next_f32(&mut self) -> Result<Option<f32>, ScannerError> {
-        self.next_parse()
+    pub fn next_i128_radix_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+        radix: u32,
+    ) -> Result<Option<i128>, ScannerError> {
+        self.next_parse_radix_until(boundary, radix)
     }
 
-    /// Read the next token separated by whitespaces and parse it to a `f64` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Read the next text until it reaches a specific boundary and parse it to a `isize` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
     ///
     /// ```rust
     /// use scanner_rust::ScannerU8Slice;
     ///
-    /// let mut sc = ScannerU8Slice::new("1 2.5".as_bytes());
+    /// let mut sc = ScannerU8Slice::new("-7f,0b101".as_bytes());
     ///
-    /// assert_eq!(Some(1.0), sc.next_f64().unwrap());
-    /// assert_eq!(Some(2.5), sc.next_f64().unwrap());
+    /// assert_eq!(Some(-127), sc.next_isize_radix_until(",", 16).unwrap());
+    /// assert_eq!(Some(5), sc.next_isize_radix_until(",", 0).unwrap());
     /// ```
     #[inline]
-    pub fn next_f64(&mut self) -> Result<Option<f64>, ScannerError> {
-        self.next_parse()
+    pub fn next_isize_radix_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+        radix: u32,
+    ) -> Result<Option<isize>, ScannerError> {
+        self.next_parse_radix_until(boundary, radix)
     }
 }
 
 impl<'a> ScannerU8Slice<'a> {
+    /// Read the next token separated by the delimiter and parse it to a `bool` value with the boolean vocabulary, which only has `true` and `false` by default. If the token is not in the vocabulary, it will return a `ParseBoolError` error. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::{BoolVocabulary, ScannerU8Slice};
+    ///
+    /// let mut sc = ScannerU8Slice::new("true Off".as_bytes());
+    ///
+    /// assert_eq!(Some(true), sc.next_bool().unwrap());
+    ///
+    /// sc.set_bool_vocabulary(BoolVocabulary::lenient());
+    ///
+    /// assert_eq!(Some(false), sc.next_bool().unwrap());
+    /// ```
     #[inline]
-    fn next_until_parse<T: FromStr, D: ?Sized + AsRef<[u8]>>(
-        &mut self,
-        boundary: &D,
-    ) -> Result<Option<T>, ScannerError>
-    where
-        ScannerError: From<<T as FromStr>::Err>, {
-        let result = self.next_until(boundary)?;
+    pub fn next_bool(&mut self) -> Result<Option<bool>, ScannerError> {
+        self.skip_whitespaces()?;
+
+        let position = self.tracker.position();
+
+        let result = self.next()?;
 
         match result {
-            Some(s) => Ok(Some(unsafe { from_utf8_unchecked(s) }.parse()?)),
+            Some(s) => Ok(Some(parse_bool(
+                decode_utf8(s, position.offset, self.strict_utf8)?,
+                &self.bool_vocabulary,
+                position,
+            )?)),
             None => Ok(None),
         }
     }
 
-    /// Read the next text until it reaches a specific boundary and parse it to a `u8` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Read the next text until it reaches a specific boundary and parse it to a `bool` value with the boolean vocabulary, which only has `true` and `false` by default. If the text is not in the vocabulary, it will return a `ParseBoolError` error. If there is nothing to read, it will return `Ok(None)`.
     ///
     /// ```rust
-    /// use scanner_rust::ScannerU8Slice;
+    /// use scanner_rust::{BoolVocabulary, ScannerU8Slice};
     ///
-    /// let mut sc = ScannerU8Slice::new("1 2".as_bytes());
+    /// let mut sc = ScannerU8Slice::new("false,y".as_bytes());
     ///
-    /// assert_eq!(Some(1), sc.next_u8_until(" ").unwrap());
-    /// assert_eq!(Some(2), sc.next_u8_until(" ").unwrap());
+    /// assert_eq!(Some(false), sc.next_bool_until(",").unwrap());
+    ///
+    /// sc.set_bool_vocabulary(BoolVocabulary::lenient());
+    ///
+    /// assert_eq!(Some(true), sc.next_bool_until(",").unwrap());
     /// ```
     #[inline]
-    pub fn next_u8_until<D: ?Sized + AsRef<[u8]>>(
+    pub fn next_bool_until<D: ?Sized + AsRef<[u8]>>(
         &mut self,
         boundary: &D,
-    ) -> Result<Option<u8>, ScannerError> {
-        self.next_until_parse(boundary)
+    ) -> Result<Option<bool>, ScannerError> {
+        let position = self.tracker.position();
+
+        let result = self.next_until(boundary)?;
+
+        match result {
+            Some(s) => Ok(Some(parse_bool(
+                decode_utf8(s, position.offset, self.strict_utf8)?,
+                &self.bool_vocabulary,
+                position,
+            )?)),
+            None => Ok(None),
+        }
     }
+}
 
-    /// Read the next text until it reaches a specific boundary and parse it to a `u16` value. If there is nothing to read, it will return `Ok(None)`.
+#[cfg(feature = "num-bigint")]
+impl<'a> ScannerU8Slice<'a> {
+    /// Read the next token separated by the delimiter and parse it to a `BigInt` value without losing precision. If there is nothing to read, it will return `Ok(None)`.
     ///
     /// ```rust
+    /// use num_bigint::BigInt;
     /// use scanner_rust::ScannerU8Slice;
     ///
-    /// let mut sc = ScannerU8Slice::new("1 2".as_bytes());
+    /// let mut sc = ScannerU8Slice::new(
+    ///     "-340282366920938463463374607431768211456 2".as_bytes(),
+    /// );
     ///
-    /// assert_eq!(Some(1), sc.next_u16_until(" ").unwrap());
-    /// assert_eq!(Some(2), sc.next_u16_until(" ").unwrap());
+    /// assert_eq!(
+    ///     Some(-(BigInt::from(u128::MAX) + 1u8)),
+    ///     sc.next_bigint().unwrap()
+    /// );
+    /// assert_eq!(Some(BigInt::from(2)), sc.next_bigint().unwrap());
     /// ```
     #[inline]
-    pub fn next_u16_until<D: ?Sized + AsRef<[u8]>>(
-        &mut self,
-        boundary: &D,
-    ) -> Result<Option<u16>, ScannerError> {
-        self.next_until_parse(boundary)
+    pub fn next_bigint(&mut self) -> Result<Option<BigInt>, ScannerError> {
+        self.next_number()
     }
 
-    /// Read the next text until it reaches a specific boundary and parse it to a `u32` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Read the next token separated by the delimiter and parse it to a `BigUint` value without losing precision. If there is nothing to read, it will return `Ok(None)`.
     ///
     /// ```rust
+    /// use num_bigint::BigUint;
     /// use scanner_rust::ScannerU8Slice;
     ///
-    /// let mut sc = ScannerU8Slice::new("1 2".as_bytes());
+    /// let mut sc = ScannerU8Slice::new(
+    ///     "340282366920938463463374607431768211456 2".as_bytes(),
+    /// );
     ///
-    /// assert_eq!(Some(1), sc.next_u32_until(" ").unwrap());
-    /// assert_eq!(Some(2), sc.next_u32_until(" ").unwrap());
+    /// assert_eq!(
+    ///     Some(BigUint::from(u128::MAX) + 1u8),
+    ///     sc.next_biguint().unwrap()
+    /// );
+    /// assert_eq!(Some(BigUint::from(2u8)), sc.next_biguint().unwrap());
     /// ```
     #[inline]
-    pub fn next_u32_until<D: ?Sized + AsRef<[u8]>>(
-        &mut self,
-        boundary: &D,
-    ) -> Result<Option<u32>, ScannerError> {
-        self.next_until_parse(boundary)
+    pub fn next_biguint(&mut self) -> Result<Option<BigUint>, ScannerError> {
+        self.next_number()
     }
 
-    /// Read the next text until it reaches a specific boundary and parse it to a `u64` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Read the next text until it reaches a specific boundary and parse it to a `BigInt` value without losing precision. If there is nothing to read, it will return `Ok(None)`.
     ///
     /// ```rust
+    /// use num_bigint::BigInt;
     /// use scanner_rust::ScannerU8Slice;
     ///
-    /// let mut sc = ScannerU8Slice::new("1 2".as_bytes());
+    /// let mut sc = ScannerU8Slice::new(
+    ///     "-340282366920938463463374607431768211456,2".as_bytes(),
+    /// );
     ///
-    /// assert_eq!(Some(1), sc.next_u64_until(" ").unwrap());
-    /// assert_eq!(Some(2), sc.next_u64_until(" ").unwrap());
+    /// assert_eq!(
+    ///     Some(-(BigInt::from(u128::MAX) + 1u8)),
+    ///     sc.next_bigint_until(",").unwrap()
+    /// );
+    /// assert_eq!(Some(BigInt::from(2)), sc.next_bigint_until(",").unwrap());
     /// ```
     #[inline]
-    pub fn next_u64_until<D: ?Sized + AsRef<[u8]>>(
+    pub fn next_bigint_until<D: ?Sized + AsRef<[u8]>>(
         &mut self,
         boundary: &D,
-    ) -> Result<Option<u64>, ScannerError> {
-        self.next_until_parse(boundary)
+    ) -> Result<Option<BigInt>, ScannerError> {
+        self.next_number_until(boundary)
     }
 
-    /// Read the next text until it reaches a specific boundary and parse it to a `u128` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Read the next text until it reaches a specific boundary and parse it to a `BigUint` value without losing precision. If there is nothing to read, it will return `Ok(None)`.
     ///
     /// ```rust
+    /// use num_bigint::BigUint;
     /// use scanner_rust::ScannerU8Slice;
     ///
-    /// let mut sc = ScannerU8Slice::new("1 2".as_bytes());
+    /// let mut sc = ScannerU8Slice::new(
+    ///     "340282366920938463463374607431768211456,2".as_bytes(),
+    /// );
     ///
-    /// assert_eq!(Some(1), sc.next_u128_until(" ").unwrap());
-    /// assert_eq!(Some(2), sc.next_u128_until(" ").unwrap());
+    /// assert_eq!(
+    ///     Some(BigUint::from(u128::MAX) + 1u8),
+    ///     sc.next_biguint_until(",").unwrap()
+    /// );
+    /// assert_eq!(Some(BigUint::from(2u8)), sc.next_biguint_until(",").unwrap());
     /// ```
     #[inline]
-    pub fn next_u128_until<D: ?Sized + AsRef<[u8]>>(
+    pub fn next_biguint_until<D: ?Sized + AsRef<[u8]>>(
         &mut self,
         boundary: &D,
-    ) -> Result<Option<u128>, ScannerError> {
-        self.next_until_parse(boundary)
+    ) -> Result<Option<BigUint>, ScannerError> {
+        self.next_number_until(boundary)
     }
+}
 
-    /// Read the next text until it reaches a specific boundary and parse it to a `usize` value. If there is nothing to read, it will return `Ok(None)`.
+#[cfg(feature = "rust_decimal")]
+impl<'a> ScannerU8Slice<'a> {
+    /// Read the next token separated by the delimiter and parse it to a `Decimal` value without going through a floating-point number, so that no precision is lost. If there is nothing to read, it will return `Ok(None)`.
     ///
     /// ```rust
+    /// use rust_decimal::Decimal;
     /// use scanner_rust::ScannerU8Slice;
     ///
-    /// let mut sc = ScannerU8Slice::new("1 2".as_bytes());
+    /// let mut sc = ScannerU8Slice::new("12345678901234567890.12 0.1".as_bytes());
     ///
-    /// assert_eq!(Some(1), sc.next_usize_until(" ").unwrap());
-    /// assert_eq!(Some(2), sc.next_usize_until(" ").unwrap());
+    /// assert_eq!(
+    ///     Some(Decimal::from_i128_with_scale(1234567890123456789012, 2)),
+    ///     sc.next_decimal().unwrap()
+    /// );
+    /// assert_eq!(Some(Decimal::new(1, 1)), sc.next_decimal().unwrap());
     /// ```
     #[inline]
-    pub fn next_usize_until<D: ?Sized + AsRef<[u8]>>(
-        &mut self,
-        boundary: &D,
-    ) -> Result<Option<usize>, ScannerError> {
-        self.next_until_parse(boundary)
+    pub fn next_decimal(&mut self) -> Result<Option<Decimal>, ScannerError> {
+        self.next_number()
     }
 
-    /// Read the next text until it reaches a specific boundary and parse it to a `i8` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Read the next text until it reaches a specific boundary and parse it to a `Decimal` value without going through a floating-point number, so that no precision is lost. If there is nothing to read, it will return `Ok(None)`.
     ///
     /// ```rust
+    /// use rust_decimal::Decimal;
     /// use scanner_rust::ScannerU8Slice;
     ///
-    /// let mut sc = ScannerU8Slice::new("1 2".as_bytes());
+    /// let mut sc = ScannerU8Slice::new("12345678901234567890.12,0.1".as_bytes());
     ///
-    /// assert_eq!(Some(1), sc.next_i8_until(" ").unwrap());
-    /// assert_eq!(Some(2), sc.next_i8_until(" ").unwrap());
+    /// assert_eq!(
+    ///     Some(Decimal::from_i128_with_scale(1234567890123456789012, 2)),
+    ///     sc.next_decimal_until(",").unwrap()
+    /// );
+    /// assert_eq!(Some(Decimal::new(1, 1)), sc.next_decimal_until(",").unwrap());
     /// ```
     #[inline]
-    pub fn next_i8_until<D: ?Sized + AsRef<[u8]>>(
+    pub fn next_decimal_until<D: ?Sized + AsRef<[u8]>>(
         &mut self,
         boundary: &D,
-    ) -> Result<Option<i8>, ScannerError> {
-        self.next_until_parse(boundary)
+    ) -> Result<Option<Decimal>, ScannerError> {
+        self.next_number_until(boundary)
     }
+}
 
-    /// Read the next text until it reaches a specific boundary and parse it to a `i16` value. If there is nothing to read, it will return `Ok(None)`.
+impl<'a> ScannerU8Slice<'a> {
+    /// Read the next token separated by delimiters, or a quoted string which can contain delimiters. A quoted string starts and ends with the same `"` or `'`, and its value is returned without the quotes. Inside it, a doubled quote stands for the quote itself, and the escape sequences `\n`, `\t`, `\r`, `\0`, `\\`, `\'`, `\"` and `\u{...}` are supported. If the data ends before the closing quote, it will return an `UnterminatedQuote` error. If there is nothing to read, it will return `Ok(None)`.
     ///
     /// ```rust
     /// use scanner_rust::ScannerU8Slice;
     ///
-    /// let mut sc = ScannerU8Slice::new("1 2".as_bytes());
+    /// let mut sc = ScannerU8Slice::new(
+    ///     r#"name "John Smith" 42 'it''s' "a\tb\u{41}""#.as_bytes(),
+    /// );
     ///
-    /// assert_eq!(Some(1), sc.next_i16_until(" ").unwrap());
-    /// assert_eq!(Some(2), sc.next_i16_until(" ").unwrap());
+    /// assert_eq!(Some("name".into()), sc.next_quoted().unwrap());
+    /// assert_eq!(Some("John Smith".into()), sc.next_quoted().unwrap());
+    /// assert_eq!(Some(42), sc.next_u8().unwrap());
+    /// assert_eq!(Some("it's".into()), sc.next_quoted().unwrap());
+    /// assert_eq!(Some("a\tbA".into()), sc.next_quoted().unwrap());
+    /// assert_eq!(None, sc.next_quoted().unwrap());
     /// ```
     #[inline]
-    pub fn next_i16_until<D: ?Sized + AsRef<[u8]>>(
-        &mut self,
-        boundary: &D,
-    ) -> Result<Option<i16>, ScannerError> {
-        self.next_until_parse(boundary)
+    pub fn next_quoted(&mut self) -> Result<Option<String>, ScannerError> {
+        next_quoted(self)
     }
+}
 
-    /// Read the next text until it reaches a specific boundary and parse it to a `i32` value. If there is nothing to read, it will return `Ok(None)`.
+impl<'a> ScannerU8Slice<'a> {
+    /// Get an iterator over the next tokens separated by delimiters. Unlike iterating the scanner itself, an error is yielded instead of ending the iteration silently.
     ///
     /// ```rust
     /// use scanner_rust::ScannerU8Slice;
     ///
-    /// let mut sc = ScannerU8Slice::new("1 2".as_bytes());
+    /// let mut sc = ScannerU8Slice::new("123 456\n789".as_bytes());
     ///
-    /// assert_eq!(Some(1), sc.next_i32_until(" ").unwrap());
-    /// assert_eq!(Some(2), sc.next_i32_until(" ").unwrap());
+    /// let tokens = sc.tokens().collect::<Result<Vec<_>, _>>().unwrap();
+    ///
+    /// assert_eq!(
+    ///     vec!["123".as_bytes(), "456".as_bytes(), "789".as_bytes()],
+    ///     tokens
+    /// );
     /// ```
     #[inline]
-    pub fn next_i32_until<D: ?Sized + AsRef<[u8]>>(
-        &mut self,
-        boundary: &D,
-    ) -> Result<Option<i32>, ScannerError> {
-        self.next_until_parse(boundary)
+    pub fn tokens(&mut self) -> Tokens<'_, Self> {
+        Tokens::new(self)
     }
 
-    /// Read the next text until it reaches a specific boundary and parse it to a `i64` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Get an iterator over the next tokens or quoted strings read by `next_quoted`.
     ///
     /// ```rust
     /// use scanner_rust::ScannerU8Slice;
     ///
-    /// let mut sc = ScannerU8Slice::new("1 2".as_bytes());
+    /// let mut sc = ScannerU8Slice::new(r#"a "b c" d"#.as_bytes());
     ///
-    /// assert_eq!(Some(1), sc.next_i64_until(" ").unwrap());
-    /// assert_eq!(Some(2), sc.next_i64_until(" ").unwrap());
+    /// let tokens = sc.quoted_tokens().collect::<Result<Vec<_>, _>>().unwrap();
+    ///
+    /// assert_eq!(vec!["a", "b c", "d"], tokens);
     /// ```
     #[inline]
-    pub fn next_i64_until<D: ?Sized + AsRef<[u8]>>(
-        &mut self,
-        boundary: &D,
-    ) -> Result<Option<i64>, ScannerError> {
-        self.next_until_parse(boundary)
+    pub fn quoted_tokens(&mut self) -> QuotedTokens<'_, Self> {
+        QuotedTokens::new(self)
     }
 
-    /// Read the next text until it reaches a specific boundary and parse it to a `i128` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Get an iterator over the next lines.
     ///
     /// ```rust
     /// use scanner_rust::ScannerU8Slice;
     ///
-    /// let mut sc = ScannerU8Slice::new("1 2".as_bytes());
+    /// let mut sc = ScannerU8Slice::new("123 456\r\n\n789".as_bytes());
     ///
-    /// assert_eq!(Some(1), sc.next_i128_until(" ").unwrap());
-    /// assert_eq!(Some(2), sc.next_i128_until(" ").unwrap());
+    /// let lines = sc.lines().collect::<Result<Vec<_>, _>>().unwrap();
+    ///
+    /// assert_eq!(
+    ///     vec!["123 456".as_bytes(), "".as_bytes(), "789".as_bytes()],
+    ///     lines
+    /// );
     /// ```
     #[inline]
-    pub fn next_i128_until<D: ?Sized + AsRef<[u8]>>(
-        &mut self,
-        boundary: &D,
-    ) -> Result<Option<i128>, ScannerError> {
-        self.next_until_parse(boundary)
+    pub fn lines(&mut self) -> Lines<'_, Self> {
+        Lines::new(self)
     }
 
-    /// Read the next text until it reaches a specific boundary and parse it to a `isize` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Get an iterator over the next chars.
     ///
     /// ```rust
     /// use scanner_rust::ScannerU8Slice;
     ///
-    /// let mut sc = ScannerU8Slice::new("1 2".as_bytes());
+    /// let mut sc = ScannerU8Slice::new("ab 中文".as_bytes());
     ///
-    /// assert_eq!(Some(1), sc.next_isize_until(" ").unwrap());
-    /// assert_eq!(Some(2), sc.next_isize_until(" ").unwrap());
+    /// assert_eq!("ab 中文", sc.chars().collect::<Result<String, _>>().unwrap());
     /// ```
     #[inline]
-    pub fn next_isize_until<D: ?Sized + AsRef<[u8]>>(
-        &mut self,
-        boundary: &D,
-    ) -> Result<Option<isize>, ScannerError> {
-        self.next_until_parse(boundary)
+    pub fn chars(&mut self) -> Chars<'_, Self> {
+        Chars::new(self)
     }
 
-    /// Read the next text until it reaches a specific boundary and parse it to a `f32` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Get an iterator which parses the next tokens separated by delimiters to values of any type which implements `FromStr`. A token which cannot be parsed is yielded as an error and skipped.
     ///
     /// ```rust
     /// use scanner_rust::ScannerU8Slice;
     ///
-    /// let mut sc = ScannerU8Slice::new("1 2.5".as_bytes());
+    /// let mut sc = ScannerU8Slice::new("1 2 x 4".as_bytes());
     ///
-    /// assert_eq!(Some(1.0), sc.next_f32_until(" ").unwrap());
-    /// assert_eq!(Some(2.5), sc.next_f32_until(" ").unwrap());
-    /// ```
-    #[inline]
-    pub fn next_f32_until<D: ?Sized + AsRef<[u8]>>(
-        &mut self,
-        boundary: &D,
-    ) -> Result<Option<f32>, ScannerError> {
-        self.next_until_parse(boundary)
-    }
-
-    /// Read the next text until it reaches a specific boundary and parse it to a `f64` value. If there is nothing to read, it will return `Ok(None)`.
+    /// let numbers =
+    ///     sc.parse_iter::<u32>().take(2).collect::<Result<Vec<_>, _>>().unwrap();
     ///
-    /// ```rust
-    /// use scanner_rust::ScannerU8Slice;
+    /// assert_eq!(vec![1, 2], numbers);
     ///
-    /// let mut sc = ScannerU8Slice::new("1 2.5".as_bytes());
+    /// let mut numbers = sc.parse_iter::<u32>();
     ///
-    /// assert_eq!(Some(1.0), sc.next_f64_until(" ").unwrap());
-    /// assert_eq!(Some(2.5), sc.next_f64_until(" ").unwrap());
+    /// assert!(numbers.next().unwrap().is_err());
+    /// assert_eq!(4, numbers.next().unwrap().unwrap());
+    /// assert!(numbers.next().is_none());
     /// ```
     #[inline]
-    pub fn next_f64_until<D: ?Sized + AsRef<[u8]>>(
-        &mut self,
-        boundary: &D,
-    ) -> Result<Option<f64>, ScannerError> {
-        self.next_until_parse(boundary)
+    pub fn parse_iter<T: FromStr>(&mut self) -> ParseIter<'_, Self, T>
+    where
+        <T as FromStr>::Err: Error + Send + Sync + 'static, {
+        ParseIter::new(self)
+    }
+}
+
+impl<'a> Scan for ScannerU8Slice<'a> {
+    type Line = &'a [u8];
+    type Token = &'a [u8];
+
+    impl_scan_delegate!();
+
+    #[inline]
+    fn next_char(&mut self) -> Result<Option<char>, ScannerError> {
+        Self::next_char(self)
+    }
+
+    #[inline]
+    fn next_line(&mut self) -> Result<Option<&'a [u8]>, ScannerError> {
+        Self::next_line(self)
+    }
+
+    #[inline]
+    fn skip_whitespaces(&mut self) -> Result<bool, ScannerError> {
+        Self::skip_whitespaces(self)
+    }
+
+    #[inline]
+    fn next(&mut self) -> Result<Option<&'a [u8]>, ScannerError> {
+        Self::next(self)
+    }
+
+    #[inline]
+    fn next_until<S: AsRef<str>>(&mut self, boundary: S) -> Result<Option<&'a [u8]>, ScannerError> {
+        Self::next_until(self, boundary.as_ref())
     }
 }
 
@@ -1,16 +1,31 @@
-use std::{
-    char::REPLACEMENT_CHARACTER,
-    str::{from_utf8_unchecked, FromStr},
+use std::{char::REPLACEMENT_CHARACTER, error::Error, str::FromStr};
+
+#[cfg(feature = "num-bigint")]
+use num_bigint::{BigInt, BigUint};
+#[cfg(feature = "rust_decimal")]
+use rust_decimal::Decimal;
+
+use crate::{
+    bool_vocabulary::parse_bool,
+    integer::{check_radix, parse_integer, Integer},
+    number_format::parse_number,
+    position::{line_break_length, PositionTracker},
+    quoted::next_quoted,
+    scanner_error::parse_token,
+    BoolVocabulary, Chars, Checkpoint, Delimiter, Lines, NumberFormat, ParseIter, Position,
+    QuotedTokens, Scan, ScannerError, Tokens,
 };
 
-use crate::{whitespaces::*, ScannerError};
-
 /// A simple text scanner which can in-memory-ly parse primitive types and strings using ASCII from a byte slice.
 #[derive(Debug)]
 pub struct ScannerU8SliceAscii<'a> {
-    data:        &'a [u8],
-    data_length: usize,
-    position:    usize,
+    data:            &'a [u8],
+    data_length:     usize,
+    position:        usize,
+    delimiter:       Delimiter,
+    number_format:   NumberFormat,
+    bool_vocabulary: BoolVocabulary,
+    tracker:         PositionTracker,
 }
 
 impl<'a> ScannerU8SliceAscii<'a> {
@@ -24,14 +39,347 @@ impl<'a> ScannerU8SliceAscii<'a> {
     /// let mut sc = ScannerU8SliceAscii::new(b"123 456");
     /// ```
     #[inline]
-    pub fn new<D: ?Sized + AsRef<[u8]>>(data: &D) -> ScannerU8SliceAscii {
+    pub fn new<D: ?Sized + AsRef<[u8]>>(data: &D) -> ScannerU8SliceAscii<'_> {
         let data = data.as_ref();
 
         ScannerU8SliceAscii {
             data,
             data_length: data.len(),
             position: 0,
+            delimiter: Delimiter::Whitespaces,
+            number_format: NumberFormat::default(),
+            bool_vocabulary: BoolVocabulary::default(),
+            tracker: PositionTracker::default(),
+        }
+    }
+}
+
+impl<'a> ScannerU8SliceAscii<'a> {
+    /// Set the delimiter used by `next`, `skip_whitespaces` and the methods which parse the next token to a primitive type.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("1, 2;3".as_bytes());
+    ///
+    /// sc.set_delimiter([',', ';', ' ']);
+    ///
+    /// assert_eq!(Some(1), sc.next_u8().unwrap());
+    /// assert_eq!(Some(2), sc.next_u8().unwrap());
+    /// assert_eq!(Some(3), sc.next_u8().unwrap());
+    /// assert_eq!(None, sc.next_u8().unwrap());
+    /// ```
+    #[inline]
+    pub fn set_delimiter<D: Into<Delimiter>>(&mut self, delimiter: D) {
+        self.delimiter = delimiter.into();
+    }
+
+    /// Get the delimiter used by `next`, `skip_whitespaces` and the methods which parse the next token to a primitive type.
+    ///
+    /// ```rust
+    /// use scanner_rust::{Delimiter, ScannerU8SliceAscii};
+    ///
+    /// let sc = ScannerU8SliceAscii::new("1 2 3".as_bytes());
+    ///
+    /// assert_eq!(&Delimiter::Whitespaces, sc.delimiter());
+    /// ```
+    #[inline]
+    pub fn delimiter(&self) -> &Delimiter {
+        &self.delimiter
+    }
+
+    /// Set the number format used by the methods which parse the next token to an integer or a floating-point number.
+    ///
+    /// ```rust
+    /// use scanner_rust::{NumberFormat, ScannerU8SliceAscii};
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("1_000 1,5".as_bytes());
+    ///
+    /// sc.set_number_format(NumberFormat {
+    ///     digit_separators: vec!['_'],
+    ///     decimal_point: ',',
+    ///     ..NumberFormat::default()
+    /// });
+    ///
+    /// assert_eq!(Some(1000), sc.next_u16().unwrap());
+    /// assert_eq!(Some(1.5), sc.next_f64().unwrap());
+    /// ```
+    #[inline]
+    pub fn set_number_format(&mut self, number_format: NumberFormat) {
+        self.number_format = number_format;
+    }
+
+    /// Get the number format used by the methods which parse the next token to an integer or a floating-point number.
+    ///
+    /// ```rust
+    /// use scanner_rust::{NumberFormat, ScannerU8SliceAscii};
+    ///
+    /// let sc = ScannerU8SliceAscii::new("1_000 1,5".as_bytes());
+    ///
+    /// assert_eq!(&NumberFormat::default(), sc.number_format());
+    /// ```
+    #[inline]
+    pub fn number_format(&self) -> &NumberFormat {
+        &self.number_format
+    }
+
+    /// Set the words read as `true` or `false` by the `next_bool` and `next_bool_until` methods.
+    ///
+    /// ```rust
+    /// use scanner_rust::{BoolVocabulary, ScannerU8SliceAscii};
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("yes N".as_bytes());
+    ///
+    /// sc.set_bool_vocabulary(BoolVocabulary::lenient());
+    ///
+    /// assert_eq!(Some(true), sc.next_bool().unwrap());
+    /// assert_eq!(Some(false), sc.next_bool().unwrap());
+    /// ```
+    #[inline]
+    pub fn set_bool_vocabulary(&mut self, bool_vocabulary: BoolVocabulary) {
+        self.bool_vocabulary = bool_vocabulary;
+    }
+
+    /// Get the words read as `true` or `false` by the `next_bool` and `next_bool_until` methods.
+    ///
+    /// ```rust
+    /// use scanner_rust::{BoolVocabulary, ScannerU8SliceAscii};
+    ///
+    /// let sc = ScannerU8SliceAscii::new("true".as_bytes());
+    ///
+    /// assert_eq!(&BoolVocabulary::default(), sc.bool_vocabulary());
+    /// ```
+    #[inline]
+    pub fn bool_vocabulary(&self) -> &BoolVocabulary {
+        &self.bool_vocabulary
+    }
+
+    /// Get the position of the data which will be read next.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("123 456\r\n789".as_bytes());
+    ///
+    /// sc.next_line().unwrap();
+    /// sc.next().unwrap();
+    ///
+    /// let position = sc.position();
+    ///
+    /// assert_eq!(12, position.offset);
+    /// assert_eq!(2, position.line);
+    /// assert_eq!(4, position.column);
+    /// ```
+    #[inline]
+    pub fn position(&self) -> Position {
+        self.tracker.position()
+    }
+
+    /// Move forward to the byte index `p` and keep the position up to date.
+    #[inline]
+    fn advance_to(&mut self, p: usize) {
+        self.tracker.advance(&self.data[self.position..p], true);
+
+        self.position = p;
+    }
+}
+
+impl<'a> ScannerU8SliceAscii<'a> {
+    /// Save the current state, so that the scanner can go back to it later by calling `restore`. It is as cheap as copying the position.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("123 456".as_bytes());
+    ///
+    /// sc.next().unwrap();
+    ///
+    /// let checkpoint = sc.checkpoint();
+    ///
+    /// assert_eq!(3, checkpoint.position().offset);
+    /// ```
+    #[inline]
+    pub fn checkpoint(&self) -> Checkpoint {
+        Checkpoint::new(self.tracker)
+    }
+
+    /// Go back (or forward) to a checkpoint created by this scanner. The delimiter is not restored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the checkpoint is beyond the end of the data, which means that it was created by another scanner.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("123 456".as_bytes());
+    ///
+    /// let checkpoint = sc.checkpoint();
+    ///
+    /// assert_eq!(Some("123".as_bytes()), sc.next().unwrap());
+    /// assert_eq!(Some("456".as_bytes()), sc.next().unwrap());
+    ///
+    /// sc.restore(checkpoint);
+    ///
+    /// assert_eq!(Some("123".as_bytes()), sc.next().unwrap());
+    /// ```
+    #[inline]
+    pub fn restore(&mut self, checkpoint: Checkpoint) {
+        let tracker = checkpoint.tracker();
+        let offset = tracker.position().offset;
+
+        assert!(
+            offset <= self.data_length,
+            "the checkpoint at {offset} is beyond the end of the data"
+        );
+
+        self.tracker = tracker;
+        self.position = offset;
+    }
+
+    /// Call `f` with this scanner, and go back to where the scanner was if `f` returns an error.
+    ///
+    /// ```rust
+    /// use scanner_rust::{ScannerError, ScannerU8SliceAscii};
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("1 2 x".as_bytes());
+    ///
+    /// let pair =
+    ///     |sc: &mut ScannerU8SliceAscii| -> Result<(u8, u8), ScannerError> {
+    ///         Ok((sc.next_u8()?.unwrap_or(0), sc.next_u8()?.unwrap_or(0)))
+    ///     };
+    ///
+    /// assert_eq!((1, 2), sc.try_scan(pair).unwrap());
+    /// assert!(sc.try_scan(pair).is_err());
+    /// assert_eq!(Some("x".as_bytes()), sc.next().unwrap());
+    /// ```
+    #[inline]
+    pub fn try_scan<T, E, F: FnOnce(&mut Self) -> Result<T, E>>(&mut self, f: F) -> Result<T, E> {
+        let checkpoint = self.checkpoint();
+
+        let result = f(self);
+
+        if result.is_err() {
+            self.restore(checkpoint);
         }
+
+        result
+    }
+
+    /// Move to the byte `offset` of the data. The line and column numbers are counted again from the start, so `checkpoint` and `restore` are faster for backtracking.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `offset` is beyond the end of the data.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("123\n456".as_bytes());
+    ///
+    /// sc.set_position(5);
+    ///
+    /// assert_eq!(2, sc.position().line);
+    /// assert_eq!(Some("56".as_bytes()), sc.next().unwrap());
+    /// ```
+    pub fn set_position(&mut self, offset: usize) {
+        assert!(offset <= self.data_length, "the offset {offset} is beyond the end of the data");
+
+        self.tracker = PositionTracker::from_data(&self.data[..offset], true);
+        self.position = offset;
+    }
+
+    /// Get the data which has not been read yet.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("123 456".as_bytes());
+    ///
+    /// sc.next().unwrap();
+    ///
+    /// assert_eq!(" 456".as_bytes(), sc.remaining());
+    /// ```
+    #[inline]
+    pub fn remaining(&self) -> &'a [u8] {
+        &self.data[self.position..]
+    }
+}
+
+impl<'a> ScannerU8SliceAscii<'a> {
+    /// Call `f` and go back to where the scanner was, so that nothing is consumed.
+    #[inline]
+    fn peek_with<T>(&mut self, f: impl FnOnce(&mut Self) -> T) -> T {
+        let checkpoint = self.checkpoint();
+
+        let result = f(self);
+
+        self.restore(checkpoint);
+
+        result
+    }
+
+    /// Read the next char without consuming it. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("ab".as_bytes());
+    ///
+    /// assert_eq!(Some('a'), sc.peek_char().unwrap());
+    /// assert_eq!(Some('a'), sc.next_char().unwrap());
+    /// ```
+    #[inline]
+    pub fn peek_char(&mut self) -> Result<Option<char>, ScannerError> {
+        self.peek_with(Self::next_char)
+    }
+
+    /// Read the next token separated by delimiters without consuming it. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("123 456".as_bytes());
+    ///
+    /// assert_eq!(Some("123".as_bytes()), sc.peek_token().unwrap());
+    /// assert_eq!(Some(123), sc.next_u16().unwrap());
+    /// ```
+    #[inline]
+    pub fn peek_token(&mut self) -> Result<Option<&'a [u8]>, ScannerError> {
+        self.peek_with(Self::next)
+    }
+
+    /// Read the next line without consuming it. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("123 456\r\n789".as_bytes());
+    ///
+    /// assert_eq!(Some("123 456".as_bytes()), sc.peek_line().unwrap());
+    /// assert_eq!(Some("123".as_bytes()), sc.next().unwrap());
+    /// ```
+    #[inline]
+    pub fn peek_line(&mut self) -> Result<Option<&'a [u8]>, ScannerError> {
+        self.peek_with(Self::next_line)
+    }
+
+    /// Read the next bytes without consuming them. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("123 456".as_bytes());
+    ///
+    /// assert_eq!(Some("123 4".as_bytes()), sc.peek_bytes(5).unwrap());
+    /// assert_eq!(Some("123".as_bytes()), sc.next().unwrap());
+    /// ```
+    #[inline]
+    pub fn peek_bytes(
+        &mut self,
+        max_number_of_bytes: usize,
+    ) -> Result<Option<&'a [u8]>, ScannerError> {
+        self.peek_with(|sc| sc.next_bytes(max_number_of_bytes))
     }
 }
 
@@ -58,7 +406,7 @@ impl<'a> ScannerU8SliceAscii<'a> {
 
         let e = self.data[self.position];
 
-        self.position += 1;
+        self.advance_to(self.position + 1);
 
         if e >= 128 {
             Ok(Some(REPLACEMENT_CHARACTER))
@@ -90,25 +438,17 @@ impl<'a> ScannerU8SliceAscii<'a> {
             let e = self.data[p];
 
             match e {
-                b'\n' => {
+                b'\n' | b'\r' => {
                     let data = &self.data[self.position..p];
 
-                    if p + 1 < self.data_length && self.data[p + 1] == b'\r' {
-                        self.position = p + 2;
+                    // the second byte of the line break consumed right before is not paired again
+                    let length = if p == self.position && self.tracker.completes_line_break(e) {
+                        1
                     } else {
-                        self.position = p + 1;
-                    }
-
-                    return Ok(Some(data));
-                },
-                b'\r' => {
-                    let data = &self.data[self.position..p];
+                        line_break_length(&self.data[p..])
+                    };
 
-                    if p + 1 < self.data_length && self.data[p + 1] == b'\n' {
-                        self.position = p + 2;
-                    } else {
-                        self.position = p + 1;
-                    }
+                    self.advance_to(p + length);
 
                     return Ok(Some(data));
                 },
@@ -124,14 +464,14 @@ impl<'a> ScannerU8SliceAscii<'a> {
 
         let data = &self.data[self.position..p];
 
-        self.position = p;
+        self.advance_to(p);
 
         Ok(Some(data))
     }
 }
 
 impl<'a> ScannerU8SliceAscii<'a> {
-    /// Skip the next whitespaces (`javaWhitespace`). If there is nothing to read, it will return `Ok(false)`.
+    /// Skip the next delimiters, which are whitespaces (`javaWhitespace`) by default. If there is nothing to read, it will return `Ok(false)`.
     ///
     /// ```rust
     /// use scanner_rust::ScannerU8SliceAscii;
@@ -151,11 +491,13 @@ impl<'a> ScannerU8SliceAscii<'a> {
         }
 
         loop {
-            if !is_whitespace_1(self.data[self.position]) {
+            let length = self.delimiter.match_length(&self.data[self.position..], true);
+
+            if length == 0 {
                 break;
             }
 
-            self.position += 1;
+            self.advance_to(self.position + length);
 
             if self.position == self.data_length {
                 break;
@@ -165,7 +507,7 @@ impl<'a> ScannerU8SliceAscii<'a> {
         Ok(true)
     }
 
-    /// Read the next token separated by whitespaces. If there is nothing to read, it will return `Ok(None)`.
+    /// Read the next token separated by delimiters. If there is nothing to read, it will return `Ok(None)`.
     ///
     /// ```rust
     /// use scanner_rust::ScannerU8SliceAscii;
@@ -191,10 +533,10 @@ impl<'a> ScannerU8SliceAscii<'a> {
         let mut p = self.position;
 
         loop {
-            if is_whitespace_1(self.data[p]) {
+            if self.delimiter.match_length(&self.data[p..], true) > 0 {
                 let data = &self.data[self.position..p];
 
-                self.position = p;
+                self.advance_to(p);
 
                 return Ok(Some(data));
             }
@@ -208,7 +550,7 @@ impl<'a> ScannerU8SliceAscii<'a> {
 
         let data = &self.data[self.position..p];
 
-        self.position = p;
+        self.advance_to(p);
 
         Ok(Some(data))
     }
@@ -241,7 +583,7 @@ impl<'a> ScannerU8SliceAscii<'a> {
 
         let data = &self.data[self.position..(self.position + dropping_bytes)];
 
-        self.position += dropping_bytes;
+        self.advance_to(self.position + dropping_bytes);
 
         Ok(Some(data))
     }
@@ -270,7 +612,7 @@ impl<'a> ScannerU8SliceAscii<'a> {
 
         let dropping_bytes = max_number_of_bytes.min(self.data_length - self.position);
 
-        self.position += dropping_bytes;
+        self.advance_to(self.position + dropping_bytes);
 
         Ok(Some(dropping_bytes))
     }
@@ -301,21 +643,21 @@ impl<'a> ScannerU8SliceAscii<'a> {
         let boundary = boundary.as_ref();
         let boundary_length = boundary.len();
 
-        if boundary_length == 0 || boundary_length >= self.data_length - self.position {
+        if boundary_length == 0 || boundary_length > self.data_length - self.position {
             let data = &self.data[self.position..];
 
-            self.position = self.data_length;
+            self.advance_to(self.data_length);
 
             return Ok(Some(data));
         }
 
-        for i in self.position..(self.data_length - boundary_length) {
+        for i in self.position..=(self.data_length - boundary_length) {
             let e = i + boundary_length;
 
             if &self.data[i..e] == boundary {
                 let data = &self.data[self.position..i];
 
-                self.position = e;
+                self.advance_to(e);
 
                 return Ok(Some(data));
             }
@@ -323,26 +665,61 @@ impl<'a> ScannerU8SliceAscii<'a> {
 
         let data = &self.data[self.position..];
 
-        self.position = self.data_length;
+        self.advance_to(self.data_length);
 
         Ok(Some(data))
     }
 }
 
 impl<'a> ScannerU8SliceAscii<'a> {
+    /// Read the next token and parse it to a number in the number format.
+    #[inline]
+    fn next_number<T: FromStr>(&mut self) -> Result<Option<T>, ScannerError>
+    where
+        <T as FromStr>::Err: Error + Send + Sync + 'static, {
+        self.skip_whitespaces()?;
+
+        let position = self.tracker.position();
+
+        let result = self.next()?;
+
+        match result {
+            Some(s) => {
+                Ok(Some(parse_number(String::from_utf8_lossy(s), &self.number_format, position)?))
+            },
+            None => Ok(None),
+        }
+    }
+
+    /// Read the next token separated by the delimiter and parse it to a value of any type which implements `FromStr`. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use std::net::IpAddr;
+    ///
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("true 127.0.0.1".as_bytes());
+    ///
+    /// assert_eq!(Some(true), sc.next_parse().unwrap());
+    /// assert_eq!(Some(IpAddr::from([127, 0, 0, 1])), sc.next_parse().unwrap());
+    /// ```
     #[inline]
-    fn next_parse<T: FromStr>(&mut self) -> Result<Option<T>, ScannerError>
+    pub fn next_parse<T: FromStr>(&mut self) -> Result<Option<T>, ScannerError>
     where
-        ScannerError: From<<T as FromStr>::Err>, {
+        <T as FromStr>::Err: Error + Send + Sync + 'static, {
+        self.skip_whitespaces()?;
+
+        let position = self.tracker.position();
+
         let result = self.next()?;
 
         match result {
-            Some(s) => Ok(Some(unsafe { from_utf8_unchecked(s) }.parse()?)),
+            Some(s) => Ok(Some(parse_token(String::from_utf8_lossy(s), position)?)),
             None => Ok(None),
         }
     }
 
-    /// Read the next token separated by whitespaces and parse it to a `u8` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Read the next token separated by the delimiter and parse it to a `u8` value. If there is nothing to read, it will return `Ok(None)`.
     ///
     /// ```rust
     /// use scanner_rust::ScannerU8SliceAscii;
@@ -354,10 +731,10 @@ impl<'a> ScannerU8SliceAscii<'a> {
     /// ```
     #[inline]
     pub fn next_u8(&mut self) -> Result<Option<u8>, ScannerError> {
-        self.next_parse()
+        self.next_parse_radix(10)
     }
 
-    /// Read the next token separated by whitespaces and parse it to a `u16` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Read the next token separated by the delimiter and parse it to a `u16` value. If there is nothing to read, it will return `Ok(None)`.
     ///
     /// ```rust
     /// use scanner_rust::ScannerU8SliceAscii;
@@ -369,10 +746,10 @@ impl<'a> ScannerU8SliceAscii<'a> {
     /// ```
     #[inline]
     pub fn next_u16(&mut self) -> Result<Option<u16>, ScannerError> {
-        self.next_parse()
+        self.next_parse_radix(10)
     }
 
-    /// Read the next token separated by whitespaces and parse it to a `u32` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Read the next token separated by the delimiter and parse it to a `u32` value. If there is nothing to read, it will return `Ok(None)`.
     ///
     /// ```rust
     /// use scanner_rust::ScannerU8SliceAscii;
@@ -384,10 +761,10 @@ impl<'a> ScannerU8SliceAscii<'a> {
     /// ```
     #[inline]
     pub fn next_u32(&mut self) -> Result<Option<u32>, ScannerError> {
-        self.next_parse()
+        self.next_parse_radix(10)
     }
 
-    /// Read the next token separated by whitespaces and parse it to a `u64` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Read the next token separated by the delimiter and parse it to a `u64` value. If there is nothing to read, it will return `Ok(None)`.
     ///
     /// ```rust
     /// use scanner_rust::ScannerU8SliceAscii;
@@ -399,10 +776,10 @@ impl<'a> ScannerU8SliceAscii<'a> {
     /// ```
     #[inline]
     pub fn next_u64(&mut self) -> Result<Option<u64>, ScannerError> {
-        self.next_parse()
+        self.next_parse_radix(10)
     }
 
-    /// Read the next token separated by whitespaces and parse it to a `u128` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Read the next token separated by the delimiter and parse it to a `u128` value. If there is nothing to read, it will return `Ok(None)`.
     ///
     /// ```rust
     /// use scanner_rust::ScannerU8SliceAscii;
@@ -414,10 +791,10 @@ impl<'a> ScannerU8SliceAscii<'a> {
     /// ```
     #[inline]
     pub fn next_u128(&mut self) -> Result<Option<u128>, ScannerError> {
-        self.next_parse()
+        self.next_parse_radix(10)
     }
 
-    /// Read the next token separated by whitespaces and parse it to a `usize` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Read the next token separated by the delimiter and parse it to a `usize` value. If there is nothing to read, it will return `Ok(None)`.
     ///
     /// ```rust
     /// use scanner_rust::ScannerU8SliceAscii;
@@ -429,10 +806,10 @@ impl<'a> ScannerU8SliceAscii<'a> {
     /// ```
     #[inline]
     pub fn next_usize(&mut self) -> Result<Option<usize>, ScannerError> {
-        self.next_parse()
+        self.next_parse_radix(10)
     }
 
-    /// Read the next token separated by whitespaces and parse it to a `i8` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Read the next token separated by the delimiter and parse it to a `i8` value. If there is nothing to read, it will return `Ok(None)`.
     ///
     /// ```rust
     /// use scanner_rust::ScannerU8SliceAscii;
@@ -444,10 +821,10 @@ impl<'a> ScannerU8SliceAscii<'a> {
     /// ```
     #[inline]
     pub fn next_i8(&mut self) -> Result<Option<i8>, ScannerError> {
-        self.next_parse()
+        self.next_parse_radix(10)
     }
 
-    /// Read the next token separated by whitespaces and parse it to a `i16` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Read the next token separated by the delimiter and parse it to a `i16` value. If there is nothing to read, it will return `Ok(None)`.
     ///
     /// ```rust
     /// use scanner_rust::ScannerU8SliceAscii;
@@ -459,10 +836,10 @@ impl<'a> ScannerU8SliceAscii<'a> {
     /// ```
     #[inline]
     pub fn next_i16(&mut self) -> Result<Option<i16>, ScannerError> {
-        self.next_parse()
+        self.next_parse_radix(10)
     }
 
-    /// Read the next token separated by whitespaces and parse it to a `i32` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Read the next token separated by the delimiter and parse it to a `i32` value. If there is nothing to read, it will return `Ok(None)`.
     ///
     /// ```rust
     /// use scanner_rust::ScannerU8SliceAscii;
@@ -474,10 +851,10 @@ impl<'a> ScannerU8SliceAscii<'a> {
     /// ```
     #[inline]
     pub fn next_i32(&mut self) -> Result<Option<i32>, ScannerError> {
-        self.next_parse()
+        self.next_parse_radix(10)
     }
 
-    /// Read the next token separated by whitespaces and parse it to a `i64` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Read the next token separated by the delimiter and parse it to a `i64` value. If there is nothing to read, it will return `Ok(None)`.
     ///
     /// ```rust
     /// use scanner_rust::ScannerU8SliceAscii;
@@ -489,10 +866,10 @@ impl<'a> ScannerU8SliceAscii<'a> {
     /// ```
     #[inline]
     pub fn next_i64(&mut self) -> Result<Option<i64>, ScannerError> {
-        self.next_parse()
+        self.next_parse_radix(10)
     }
 
-    /// Read the next token separated by whitespaces and parse it to a `i128` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Read the next token separated by the delimiter and parse it to a `i128` value. If there is nothing to read, it will return `Ok(None)`.
     ///
     /// ```rust
     /// use scanner_rust::ScannerU8SliceAscii;
@@ -504,10 +881,10 @@ impl<'a> ScannerU8SliceAscii<'a> {
     /// ```
     #[inline]
     pub fn next_i128(&mut self) -> Result<Option<i128>, ScannerError> {
-        self.next_parse()
+        self.next_parse_radix(10)
     }
 
-    /// Read the next token separated by whitespaces and parse it to a `isize` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Read the next token separated by the delimiter and parse it to a `isize` value. If there is nothing to read, it will return `Ok(None)`.
     ///
     /// ```rust
     /// use scanner_rust::ScannerU8SliceAscii;
@@ -519,10 +896,10 @@ impl<'a> ScannerU8SliceAscii<'a> {
     /// ```
     #[inline]
     pub fn next_isize(&mut self) -> Result<Option<isize>, ScannerError> {
-        self.next_parse()
+        self.next_parse_radix(10)
     }
 
-    /// Read the next token separated by whitespaces and parse it to a `f32` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Read the next token separated by the delimiter and parse it to a `f32` value. If there is nothing to read, it will return `Ok(None)`.
     ///
     /// ```rust
     /// use scanner_rust::ScannerU8SliceAscii;
@@ -534,10 +911,10 @@ impl<'a> ScannerU8SliceAscii<'a> {
     /// ```
     #[inline]
     pub fn next_f32(&mut self) -> Result<Option<f32>, ScannerError> {
-        self.next_parse()
+        self.next_number()
     }
 
-    /// Read the next token separated by whitespaces and parse it to a `f64` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Read the next token separated by the delimiter and parse it to a `f64` value. If there is nothing to read, it will return `Ok(None)`.
     ///
     /// ```rust
     /// use scanner_rust::ScannerU8SliceAscii;
@@ -549,80 +926,448 @@ impl<'a> ScannerU8SliceAscii<'a> {
     /// ```
     #[inline]
     pub fn next_f64(&mut self) -> Result<Option<f64>, ScannerError> {
-        self.next_parse()
+        self.next_number()
     }
 }
 
 impl<'a> ScannerU8SliceAscii<'a> {
-    /// Read the next text until it reaches a specific boundary and parse it to a `u8` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Check whether there is a next token separated by delimiters without consuming it.
     ///
     /// ```rust
     /// use scanner_rust::ScannerU8SliceAscii;
     ///
-    /// let mut sc = ScannerU8SliceAscii::new("1 2".as_bytes());
+    /// let mut sc = ScannerU8SliceAscii::new(" 123 ".as_bytes());
     ///
-    /// assert_eq!(Some(1), sc.next_u8_until(" ").unwrap());
-    /// assert_eq!(Some(2), sc.next_u8_until(" ").unwrap());
+    /// assert_eq!(true, sc.has_next().unwrap());
+    /// assert_eq!(Some("123".as_bytes()), sc.next().unwrap());
+    /// assert_eq!(false, sc.has_next().unwrap());
     /// ```
-    #[inline]
-    pub fn next_u8_until<D: ?Sized + AsRef<[u8]>>(
-        &mut self,
-        boundary: &D,
-    ) -> Result<Option<u8>, ScannerError> {
-        let result = self.next_until(boundary)?;
+    pub fn has_next(&mut self) -> Result<bool, ScannerError> {
+        let (position, tracker) = (self.position, self.tracker);
 
-        match result {
-            Some(s) => Ok(Some(unsafe { from_utf8_unchecked(s) }.parse()?)),
-            None => Ok(None),
-        }
+        let result = self.next()?.is_some();
+
+        self.position = position;
+        self.tracker = tracker;
+
+        Ok(result)
     }
 
-    /// Read the next text until it reaches a specific boundary and parse it to a `u16` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Check whether there is a next line, which may be empty, without consuming it.
     ///
     /// ```rust
     /// use scanner_rust::ScannerU8SliceAscii;
     ///
-    /// let mut sc = ScannerU8SliceAscii::new("1 2".as_bytes());
+    /// let mut sc = ScannerU8SliceAscii::new("123\n".as_bytes());
     ///
-    /// assert_eq!(Some(1), sc.next_u16_until(" ").unwrap());
-    /// assert_eq!(Some(2), sc.next_u16_until(" ").unwrap());
+    /// assert_eq!(true, sc.has_next_line().unwrap());
+    /// assert_eq!(Some("123".as_bytes()), sc.next_line().unwrap());
+    /// assert_eq!(false, sc.has_next_line().unwrap());
     /// ```
-    #[inline]
-    pub fn next_u16_until<D: ?Sized + AsRef<[u8]>>(
-        &mut self,
-        boundary: &D,
-    ) -> Result<Option<u16>, ScannerError> {
-        let result = self.next_until(boundary)?;
-
-        match result {
-            Some(s) => Ok(Some(unsafe { from_utf8_unchecked(s) }.parse()?)),
-            None => Ok(None),
-        }
+    pub fn has_next_line(&mut self) -> Result<bool, ScannerError> {
+        Ok(self.position < self.data_length)
     }
 
-    /// Read the next text until it reaches a specific boundary and parse it to a `u32` value. If there is nothing to read, it will return `Ok(None)`.
+    /// Check whether the next token separated by delimiters can be parsed to a value of any type which implements `FromStr`, without consuming it.
     ///
     /// ```rust
+    /// use std::net::IpAddr;
+    ///
     /// use scanner_rust::ScannerU8SliceAscii;
     ///
-    /// let mut sc = ScannerU8SliceAscii::new("1 2".as_bytes());
+    /// let mut sc = ScannerU8SliceAscii::new("127.0.0.1 x".as_bytes());
     ///
-    /// assert_eq!(Some(1), sc.next_u32_until(" ").unwrap());
-    /// assert_eq!(Some(2), sc.next_u32_until(" ").unwrap());
+    /// assert_eq!(true, sc.has_next_parse::<IpAddr>().unwrap());
+    /// assert_eq!(Some(IpAddr::from([127, 0, 0, 1])), sc.next_parse().unwrap());
+    /// assert_eq!(false, sc.has_next_parse::<IpAddr>().unwrap());
+    /// assert_eq!(Some("x".as_bytes()), sc.next().unwrap());
     /// ```
-    #[inline]
-    pub fn next_u32_until<D: ?Sized + AsRef<[u8]>>(
-        &mut self,
+    pub fn has_next_parse<T: FromStr>(&mut self) -> Result<bool, ScannerError> {
+        let (position, tracker) = (self.position, self.tracker);
+
+        let result = match self.next()? {
+            Some(s) => String::from_utf8_lossy(s).parse::<T>().is_ok(),
+            None => false,
+        };
+
+        self.position = position;
+        self.tracker = tracker;
+
+        Ok(result)
+    }
+
+    /// Check whether the next token can be parsed to a number in the number format, without consuming it.
+    #[inline]
+    fn has_next_number<T: FromStr>(&mut self) -> Result<bool, ScannerError> {
+        let (position, tracker) = (self.position, self.tracker);
+
+        let result = match self.next()? {
+            Some(s) => {
+                self.number_format.normalize(&String::from_utf8_lossy(s)).parse::<T>().is_ok()
+            },
+            None => false,
+        };
+
+        self.position = position;
+        self.tracker = tracker;
+
+        Ok(result)
+    }
+
+    /// Check whether the next token can be parsed to an integer in the number format, without consuming it.
+    #[inline]
+    fn has_next_integer<T: Integer>(&mut self) -> Result<bool, ScannerError> {
+        let (position, tracker) = (self.position, self.tracker);
+
+        let result = match self.next()? {
+            Some(s) => parse_integer::<T, _>(
+                String::from_utf8_lossy(s),
+                10,
+                &self.number_format,
+                self.tracker.position(),
+            )
+            .is_ok(),
+            None => false,
+        };
+
+        self.position = position;
+        self.tracker = tracker;
+
+        Ok(result)
+    }
+
+    /// Check whether the next token separated by delimiters can be parsed to a `u8` value without consuming it.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("12 x".as_bytes());
+    ///
+    /// assert_eq!(true, sc.has_next_u8().unwrap());
+    /// assert_eq!(Some(12), sc.next_u8().unwrap());
+    /// assert_eq!(false, sc.has_next_u8().unwrap());
+    /// ```
+    #[inline]
+    pub fn has_next_u8(&mut self) -> Result<bool, ScannerError> {
+        self.has_next_integer::<u8>()
+    }
+
+    /// Check whether the next token separated by delimiters can be parsed to a `u16` value without consuming it.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("12 x".as_bytes());
+    ///
+    /// assert_eq!(true, sc.has_next_u16().unwrap());
+    /// assert_eq!(Some(12), sc.next_u16().unwrap());
+    /// assert_eq!(false, sc.has_next_u16().unwrap());
+    /// ```
+    #[inline]
+    pub fn has_next_u16(&mut self) -> Result<bool, ScannerError> {
+        self.has_next_integer::<u16>()
+    }
+
+    /// Check whether the next token separated by delimiters can be parsed to a `u32` value without consuming it.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("12 x".as_bytes());
+    ///
+    /// assert_eq!(true, sc.has_next_u32().unwrap());
+    /// assert_eq!(Some(12), sc.next_u32().unwrap());
+    /// assert_eq!(false, sc.has_next_u32().unwrap());
+    /// ```
+    #[inline]
+    pub fn has_next_u32(&mut self) -> Result<bool, ScannerError> {
+        self.has_next_integer::<u32>()
+    }
+
+    /// Check whether the next token separated by delimiters can be parsed to a `u64` value without consuming it.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("12 x".as_bytes());
+    ///
+    /// assert_eq!(true, sc.has_next_u64().unwrap());
+    /// assert_eq!(Some(12), sc.next_u64().unwrap());
+    /// assert_eq!(false, sc.has_next_u64().unwrap());
+    /// ```
+    #[inline]
+    pub fn has_next_u64(&mut self) -> Result<bool, ScannerError> {
+        self.has_next_integer::<u64>()
+    }
+
+    /// Check whether the next token separated by delimiters can be parsed to a `u128` value without consuming it.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("12 x".as_bytes());
+    ///
+    /// assert_eq!(true, sc.has_next_u128().unwrap());
+    /// assert_eq!(Some(12), sc.next_u128().unwrap());
+    /// assert_eq!(false, sc.has_next_u128().unwrap());
+    /// ```
+    #[inline]
+    pub fn has_next_u128(&mut self) -> Result<bool, ScannerError> {
+        self.has_next_integer::<u128>()
+    }
+
+    /// Check whether the next token separated by delimiters can be parsed to a `usize` value without consuming it.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("12 x".as_bytes());
+    ///
+    /// assert_eq!(true, sc.has_next_usize().unwrap());
+    /// assert_eq!(Some(12), sc.next_usize().unwrap());
+    /// assert_eq!(false, sc.has_next_usize().unwrap());
+    /// ```
+    #[inline]
+    pub fn has_next_usize(&mut self) -> Result<bool, ScannerError> {
+        self.has_next_integer::<usize>()
+    }
+
+    /// Check whether the next token separated by delimiters can be parsed to a `i8` value without consuming it.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("12 x".as_bytes());
+    ///
+    /// assert_eq!(true, sc.has_next_i8().unwrap());
+    /// assert_eq!(Some(12), sc.next_i8().unwrap());
+    /// assert_eq!(false, sc.has_next_i8().unwrap());
+    /// ```
+    #[inline]
+    pub fn has_next_i8(&mut self) -> Result<bool, ScannerError> {
+        self.has_next_integer::<i8>()
+    }
+
+    /// Check whether the next token separated by delimiters can be parsed to a `i16` value without consuming it.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("12 x".as_bytes());
+    ///
+    /// assert_eq!(true, sc.has_next_i16().unwrap());
+    /// assert_eq!(Some(12), sc.next_i16().unwrap());
+    /// assert_eq!(false, sc.has_next_i16().unwrap());
+    /// ```
+    #[inline]
+    pub fn has_next_i16(&mut self) -> Result<bool, ScannerError> {
+        self.has_next_integer::<i16>()
+    }
+
+    /// Check whether the next token separated by delimiters can be parsed to a `i32` value without consuming it.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("12 x".as_bytes());
+    ///
+    /// assert_eq!(true, sc.has_next_i32().unwrap());
+    /// assert_eq!(Some(12), sc.next_i32().unwrap());
+    /// assert_eq!(false, sc.has_next_i32().unwrap());
+    /// ```
+    #[inline]
+    pub fn has_next_i32(&mut self) -> Result<bool, ScannerError> {
+        self.has_next_integer::<i32>()
+    }
+
+    /// Check whether the next token separated by delimiters can be parsed to a `i64` value without consuming it.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("12 x".as_bytes());
+    ///
+    /// assert_eq!(true, sc.has_next_i64().unwrap());
+    /// assert_eq!(Some(12), sc.next_i64().unwrap());
+    /// assert_eq!(false, sc.has_next_i64().unwrap());
+    /// ```
+    #[inline]
+    pub fn has_next_i64(&mut self) -> Result<bool, ScannerError> {
+        self.has_next_integer::<i64>()
+    }
+
+    /// Check whether the next token separated by delimiters can be parsed to a `i128` value without consuming it.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("12 x".as_bytes());
+    ///
+    /// assert_eq!(true, sc.has_next_i128().unwrap());
+    /// assert_eq!(Some(12), sc.next_i128().unwrap());
+    /// assert_eq!(false, sc.has_next_i128().unwrap());
+    /// ```
+    #[inline]
+    pub fn has_next_i128(&mut self) -> Result<bool, ScannerError> {
+        self.has_next_integer::<i128>()
+    }
+
+    /// Check whether the next token separated by delimiters can be parsed to a `isize` value without consuming it.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("12 x".as_bytes());
+    ///
+    /// assert_eq!(true, sc.has_next_isize().unwrap());
+    /// assert_eq!(Some(12), sc.next_isize().unwrap());
+    /// assert_eq!(false, sc.has_next_isize().unwrap());
+    /// ```
+    #[inline]
+    pub fn has_next_isize(&mut self) -> Result<bool, ScannerError> {
+        self.has_next_integer::<isize>()
+    }
+
+    /// Check whether the next token separated by delimiters can be parsed to a `f32` value without consuming it.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("1.5 x".as_bytes());
+    ///
+    /// assert_eq!(true, sc.has_next_f32().unwrap());
+    /// assert_eq!(Some(1.5), sc.next_f32().unwrap());
+    /// assert_eq!(false, sc.has_next_f32().unwrap());
+    /// ```
+    #[inline]
+    pub fn has_next_f32(&mut self) -> Result<bool, ScannerError> {
+        self.has_next_number::<f32>()
+    }
+
+    /// Check whether the next token separated by delimiters can be parsed to a `f64` value without consuming it.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("1.5 x".as_bytes());
+    ///
+    /// assert_eq!(true, sc.has_next_f64().unwrap());
+    /// assert_eq!(Some(1.5), sc.next_f64().unwrap());
+    /// assert_eq!(false, sc.has_next_f64().unwrap());
+    /// ```
+    #[inline]
+    pub fn has_next_f64(&mut self) -> Result<bool, ScannerError> {
+        self.has_next_number::<f64>()
+    }
+}
+
+impl<'a> ScannerU8SliceAscii<'a> {
+    /// Read the next text until it reaches a specific boundary and parse it to a number in the number format.
+    #[inline]
+    fn next_number_until<T: FromStr, D: ?Sized + AsRef<[u8]>>(
+        &mut self,
         boundary: &D,
-    ) -> Result<Option<u32>, ScannerError> {
+    ) -> Result<Option<T>, ScannerError>
+    where
+        <T as FromStr>::Err: Error + Send + Sync + 'static, {
+        let position = self.tracker.position();
+
+        let result = self.next_until(boundary)?;
+
+        match result {
+            Some(s) => {
+                Ok(Some(parse_number(String::from_utf8_lossy(s), &self.number_format, position)?))
+            },
+            None => Ok(None),
+        }
+    }
+
+    /// Read the next text until it reaches a specific boundary and parse it to a value of any type which implements `FromStr`. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use std::net::IpAddr;
+    ///
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("true,127.0.0.1".as_bytes());
+    ///
+    /// assert_eq!(Some(true), sc.next_parse_until(",").unwrap());
+    /// assert_eq!(
+    ///     Some(IpAddr::from([127, 0, 0, 1])),
+    ///     sc.next_parse_until(",").unwrap()
+    /// );
+    /// ```
+    #[inline]
+    pub fn next_parse_until<T: FromStr, D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+    ) -> Result<Option<T>, ScannerError>
+    where
+        <T as FromStr>::Err: Error + Send + Sync + 'static, {
+        let position = self.tracker.position();
+
         let result = self.next_until(boundary)?;
 
         match result {
-            Some(s) => Ok(Some(unsafe { from_utf8_unchecked(s) }.parse()?)),
+            Some(s) => Ok(Some(parse_token(String::from_utf8_lossy(s), position)?)),
             None => Ok(None),
         }
     }
 
+    /// Read the next text until it reaches a specific boundary and parse it to a `u8` value. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("1 2".as_bytes());
+    ///
+    /// assert_eq!(Some(1), sc.next_u8_until(" ").unwrap());
+    /// assert_eq!(Some(2), sc.next_u8_until(" ").unwrap());
+    /// ```
+    #[inline]
+    pub fn next_u8_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+    ) -> Result<Option<u8>, ScannerError> {
+        self.next_parse_radix_until(boundary, 10)
+    }
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `u16` value. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("1 2".as_bytes());
+    ///
+    /// assert_eq!(Some(1), sc.next_u16_until(" ").unwrap());
+    /// assert_eq!(Some(2), sc.next_u16_until(" ").unwrap());
+    /// ```
+    #[inline]
+    pub fn next_u16_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+    ) -> Result<Option<u16>, ScannerError> {
+        self.next_parse_radix_until(boundary, 10)
+    }
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `u32` value. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("1 2".as_bytes());
+    ///
+    /// assert_eq!(Some(1), sc.next_u32_until(" ").unwrap());
+    /// assert_eq!(Some(2), sc.next_u32_until(" ").unwrap());
+    /// ```
+    #[inline]
+    pub fn next_u32_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+    ) -> Result<Option<u32>, ScannerError> {
+        self.next_parse_radix_until(boundary, 10)
+    }
+
     /// Read the next text until it reaches a specific boundary and parse it to a `u64` value. If there is nothing to read, it will return `Ok(None)`.
     ///
     /// ```rust
@@ -638,12 +1383,7 @@ impl<'a> ScannerU8SliceAscii<'a> {
         &mut self,
         boundary: &D,
     ) -> Result<Option<u64>, ScannerError> {
-        let result = self.next_until(boundary)?;
-
-        match result {
-            Some(s) => Ok(Some(unsafe { from_utf8_unchecked(s) }.parse()?)),
-            None => Ok(None),
-        }
+        self.next_parse_radix_until(boundary, 10)
     }
 
     /// Read the next text until it reaches a specific boundary and parse it to a `u128` value. If there is nothing to read, it will return `Ok(None)`.
@@ -661,12 +1401,7 @@ impl<'a> ScannerU8SliceAscii<'a> {
         &mut self,
         boundary: &D,
     ) -> Result<Option<u128>, ScannerError> {
-        let result = self.next_until(boundary)?;
-
-        match result {
-            Some(s) => Ok(Some(unsafe { from_utf8_unchecked(s) }.parse()?)),
-            None => Ok(None),
-        }
+        self.next_parse_radix_until(boundary, 10)
     }
 
     /// Read the next text until it reaches a specific boundary and parse it to a `usize` value. If there is nothing to read, it will return `Ok(None)`.
@@ -684,12 +1419,7 @@ impl<'a> ScannerU8SliceAscii<'a> {
         &mut self,
         boundary: &D,
     ) -> Result<Option<usize>, ScannerError> {
-        let result = self.next_until(boundary)?;
-
-        match result {
-            Some(s) => Ok(Some(unsafe { from_utf8_unchecked(s) }.parse()?)),
-            None => Ok(None),
-        }
+        self.next_parse_radix_until(boundary, 10)
     }
 
     /// Read the next text until it reaches a specific boundary and parse it to a `i8` value. If there is nothing to read, it will return `Ok(None)`.
@@ -707,12 +1437,7 @@ impl<'a> ScannerU8SliceAscii<'a> {
         &mut self,
         boundary: &D,
     ) -> Result<Option<i8>, ScannerError> {
-        let result = self.next_until(boundary)?;
-
-        match result {
-            Some(s) => Ok(Some(unsafe { from_utf8_unchecked(s) }.parse()?)),
-            None => Ok(None),
-        }
+        self.next_parse_radix_until(boundary, 10)
     }
 
     /// Read the next text until it reaches a specific boundary and parse it to a `i16` value. If there is nothing to read, it will return `Ok(None)`.
@@ -730,12 +1455,7 @@ impl<'a> ScannerU8SliceAscii<'a> {
         &mut self,
         boundary: &D,
     ) -> Result<Option<i16>, ScannerError> {
-        let result = self.next_until(boundary)?;
-
-        match result {
-            Some(s) => Ok(Some(unsafe { from_utf8_unchecked(s) }.parse()?)),
-            None => Ok(None),
-        }
+        self.next_parse_radix_until(boundary, 10)
     }
 
     /// Read the next text until it reaches a specific boundary and parse it to a `i32` value. If there is nothing to read, it will return `Ok(None)`.
@@ -753,12 +1473,7 @@ impl<'a> ScannerU8SliceAscii<'a> {
         &mut self,
         boundary: &D,
     ) -> Result<Option<i32>, ScannerError> {
-        let result = self.next_until(boundary)?;
-
-        match result {
-            Some(s) => Ok(Some(unsafe { from_utf8_unchecked(s) }.parse()?)),
-            None => Ok(None),
-        }
+        self.next_parse_radix_until(boundary, 10)
     }
 
     /// Read the next text until it reaches a specific boundary and parse it to a `i64` value. If there is nothing to read, it will return `Ok(None)`.
@@ -776,12 +1491,7 @@ impl<'a> ScannerU8SliceAscii<'a> {
         &mut self,
         boundary: &D,
     ) -> Result<Option<i64>, ScannerError> {
-        let result = self.next_until(boundary)?;
-
-        match result {
-            Some(s) => Ok(Some(unsafe { from_utf8_unchecked(s) }.parse()?)),
-            None => Ok(None),
-        }
+        self.next_parse_radix_until(boundary, 10)
     }
 
     /// Read the next text until it reaches a specific boundary and parse it to a `i128` value. If there is nothing to read, it will return `Ok(None)`.
@@ -799,13 +1509,8 @@ impl<'a> ScannerU8SliceAscii<'a> {
         &mut self,
         boundary: &D,
     ) -> Result<Option<i128>, ScannerError> {
-        let result = self.next_until(boundary)?;
-
-        match result {
-            Some(s) => Ok(Some(unsafe { from_utf8_unchecked(s) }.parse()?)),
-            None => Ok(None),
-        }
-    }
+        self.next_parse_radix_until(boundary, 10)
+    }
 
     /// Read the next text until it reaches a specific boundary and parse it to a `isize` value. If there is nothing to read, it will return `Ok(None)`.
     ///
@@ -822,12 +1527,7 @@ impl<'a> ScannerU8SliceAscii<'a> {
         &mut self,
         boundary: &D,
     ) -> Result<Option<isize>, ScannerError> {
-        let result = self.next_until(boundary)?;
-
-        match result {
-            Some(s) => Ok(Some(unsafe { from_utf8_unchecked(s) }.parse()?)),
-            None => Ok(None),
-        }
+        self.next_parse_radix_until(boundary, 10)
     }
 
     /// Read the next text until it reaches a specific boundary and parse it to a `f32` value. If there is nothing to read, it will return `Ok(None)`.
@@ -845,12 +1545,7 @@ impl<'a> ScannerU8SliceAscii<'a> {
         &mut self,
         boundary: &D,
     ) -> Result<Option<f32>, ScannerError> {
-        let result = self.next_until(boundary)?;
-
-        match result {
-            Some(s) => Ok(Some(unsafe { from_utf8_unchecked(s) }.parse()?)),
-            None => Ok(None),
-        }
+        self.next_number_until(boundary)
     }
 
     /// Read the next text until it reaches a specific boundary and parse it to a `f64` value. If there is nothing to read, it will return `Ok(None)`.
@@ -868,13 +1563,860 @@ impl<'a> ScannerU8SliceAscii<'a> {
         &mut self,
         boundary: &D,
     ) -> Result<Option<f64>, ScannerError> {
+        self.next_number_until(boundary)
+    }
+}
+
+impl<'a> ScannerU8SliceAscii<'a> {
+    #[inline]
+    fn next_parse_radix<T: Integer>(&mut self, radix: u32) -> Result<Option<T>, ScannerError> {
+        check_radix(radix)?;
+
+        self.skip_whitespaces()?;
+
+        let position = self.tracker.position();
+
+        let result = self.next()?;
+
+        match result {
+            Some(s) => Ok(Some(parse_integer(
+                String::from_utf8_lossy(s),
+                radix,
+                &self.number_format,
+                position,
+            )?)),
+            None => Ok(None),
+        }
+    }
+
+    #[inline]
+    fn next_parse_radix_until<T: Integer, D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+        radix: u32,
+    ) -> Result<Option<T>, ScannerError> {
+        check_radix(radix)?;
+
+        let position = self.tracker.position();
+
         let result = self.next_until(boundary)?;
 
         match result {
-            Some(s) => Ok(Some(unsafe { from_utf8_unchecked(s) }.parse()?)),
+            Some(s) => Ok(Some(parse_integer(
+                String::from_utf8_lossy(s),
+                radix,
+                &self.number_format,
+                position,
+            )?)),
             None => Ok(None),
         }
     }
+
+    /// Read the next token separated by the delimiter and parse it to a `u8` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("7f 0b101".as_bytes());
+    ///
+    /// assert_eq!(Some(127), sc.next_u8_radix(16).unwrap());
+    /// assert_eq!(Some(5), sc.next_u8_radix(0).unwrap());
+    /// ```
+    #[inline]
+    pub fn next_u8_radix(&mut self, radix: u32) -> Result<Option<u8>, ScannerError> {
+        self.next_parse_radix(radix)
+    }
+
+    /// Read the next token separated by the delimiter and parse it to a `u16` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("7f 0b101".as_bytes());
+    ///
+    /// assert_eq!(Some(127), sc.next_u16_radix(16).unwrap());
+    /// assert_eq!(Some(5), sc.next_u16_radix(0).unwrap());
+    /// ```
+    #[inline]
+    pub fn next_u16_radix(&mut self, radix: u32) -> Result<Option<u16>, ScannerError> {
+        self.next_parse_radix(radix)
+    }
+
+    /// Read the next token separated by the delimiter and parse it to a `u32` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("7f 0b101".as_bytes());
+    ///
+    /// assert_eq!(Some(127), sc.next_u32_radix(16).unwrap());
+    /// assert_eq!(Some(5), sc.next_u32_radix(0).unwrap());
+    /// ```
+    #[inline]
+    pub fn next_u32_radix(&mut self, radix: u32) -> Result<Option<u32>, ScannerError> {
+        self.next_parse_radix(radix)
+    }
+
+    /// Read the next token separated by the delimiter and parse it to a `u64` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("7f 0b101".as_bytes());
+    ///
+    /// assert_eq!(Some(127), sc.next_u64_radix(16).unwrap());
+    /// assert_eq!(Some(5), sc.next_u64_radix(0).unwrap());
+    /// ```
+    #[inline]
+    pub fn next_u64_radix(&mut self, radix: u32) -> Result<Option<u64>, ScannerError> {
+        self.next_parse_radix(radix)
+    }
+
+    /// Read the next token separated by the delimiter and parse it to a `u128` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("7f 0b101".as_bytes());
+    ///
+    /// assert_eq!(Some(127), sc.next_u128_radix(16).unwrap());
+    /// assert_eq!(Some(5), sc.next_u128_radix(0).unwrap());
+    /// ```
+    #[inline]
+    pub fn next_u128_radix(&mut self, radix: u32) -> Result<Option<u128>, ScannerError> {
+        self.next_parse_radix(radix)
+    }
+
+    /// Read the next token separated by the delimiter and parse it to a `usize` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("7f 0b101".as_bytes());
+    ///
+    /// assert_eq!(Some(127), sc.next_usize_radix(16).unwrap());
+    /// assert_eq!(Some(5), sc.next_usize_radix(0).unwrap());
+    /// ```
+    #[inline]
+    pub fn next_usize_radix(&mut self, radix: u32) -> Result<Option<usize>, ScannerError> {
+        self.next_parse_radix(radix)
+    }
+
+    /// Read the next token separated by the delimiter and parse it to a `i8` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("-7f 0b101".as_bytes());
+    ///
+    /// assert_eq!(Some(-127), sc.next_i8_radix(16).unwrap());
+    /// assert_eq!(Some(5), sc.next_i8_radix(0).unwrap());
+    /// ```
+    #[inline]
+    pub fn next_i8_radix(&mut self, radix: u32) -> Result<Option<i8>, ScannerError> {
+        self.next_parse_radix(radix)
+    }
+
+    /// Read the next token separated by the delimiter and parse it to a `i16` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("-7f 0b101".as_bytes());
+    ///
+    /// assert_eq!(Some(-127), sc.next_i16_radix(16).unwrap());
+    /// assert_eq!(Some(5), sc.next_i16_radix(0).unwrap());
+    /// ```
+    #[inline]
+    pub fn next_i16_radix(&mut self, radix: u32) -> Result<Option<i16>, ScannerError> {
+        self.next_parse_radix(radix)
+    }
+
+    /// Read the next token separated by the delimiter and parse it to a `i32` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("-7f 0b101".as_bytes());
+    ///
+    /// assert_eq!(Some(-127), sc.next_i32_radix(16).unwrap());
+    /// assert_eq!(Some(5), sc.next_i32_radix(0).unwrap());
+    /// ```
+    #[inline]
+    pub fn next_i32_radix(&mut self, radix: u32) -> Result<Option<i32>, ScannerError> {
+        self.next_parse_radix(radix)
+    }
+
+    /// Read the next token separated by the delimiter and parse it to a `i64` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("-7f 0b101".as_bytes());
+    ///
+    /// assert_eq!(Some(-127), sc.next_i64_radix(16).unwrap());
+    /// assert_eq!(Some(5), sc.next_i64_radix(0).unwrap());
+    /// ```
+    #[inline]
+    pub fn next_i64_radix(&mut self, radix: u32) -> Result<Option<i64>, ScannerError> {
+        self.next_parse_radix(radix)
+    }
+
+    /// Read the next token separated by the delimiter and parse it to a `i128` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("-7f 0b101".as_bytes());
+    ///
+    /// assert_eq!(Some(-127), sc.next_i128_radix(16).unwrap());
+    /// assert_eq!(Some(5), sc.next_i128_radix(0).unwrap());
+    /// ```
+    #[inline]
+    pub fn next_i128_radix(&mut self, radix: u32) -> Result<Option<i128>, ScannerError> {
+        self.next_parse_radix(radix)
+    }
+
+    /// Read the next token separated by the delimiter and parse it to a `isize` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("-7f 0b101".as_bytes());
+    ///
+    /// assert_eq!(Some(-127), sc.next_isize_radix(16).unwrap());
+    /// assert_eq!(Some(5), sc.next_isize_radix(0).unwrap());
+    /// ```
+    #[inline]
+    pub fn next_isize_radix(&mut self, radix: u32) -> Result<Option<isize>, ScannerError> {
+        self.next_parse_radix(radix)
+    }
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `u8` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("7f,0b101".as_bytes());
+    ///
+    /// assert_eq!(Some(127), sc.next_u8_radix_until(",", 16).unwrap());
+    /// assert_eq!(Some(5), sc.next_u8_radix_until(",", 0).unwrap());
+    /// ```
+    #[inline]
+    pub fn next_u8_radix_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+        radix: u32,
+    ) -> Result<Option<u8>, ScannerError> {
+        self.next_parse_radix_until(boundary, radix)
+    }
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `u16` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("7f,0b101".as_bytes());
+    ///
+    /// assert_eq!(Some(127), sc.next_u16_radix_until(",", 16).unwrap());
+    /// assert_eq!(Some(5), sc.next_u16_radix_until(",", 0).unwrap());
+    /// ```
+    #[inline]
+    pub fn next_u16_radix_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+        radix: u32,
+    ) -> Result<Option<u16>, ScannerError> {
+        self.next_parse_radix_until(boundary, radix)
+    }
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `u32` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("7f,0b101".as_bytes());
+    ///
+    /// assert_eq!(Some(127), sc.next_u32_radix_until(",", 16).unwrap());
+    /// assert_eq!(Some(5), sc.next_u32_radix_until(",", 0).unwrap());
+    /// ```
+    #[inline]
+    pub fn next_u32_radix_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+        radix: u32,
+    ) -> Result<Option<u32>, ScannerError> {
+        self.next_parse_radix_until(boundary, radix)
+    }
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `u64` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("7f,0b101".as_bytes());
+    ///
+    /// assert_eq!(Some(127), sc.next_u64_radix_until(",", 16).unwrap());
+    /// assert_eq!(Some(5), sc.next_u64_radix_until(",", 0).unwrap());
+    /// ```
+    #[inline]
+    pub fn next_u64_radix_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+        radix: u32,
+    ) -> Result<Option<u64>, ScannerError> {
+        self.next_parse_radix_until(boundary, radix)
+    }
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `u128` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("7f,0b101".as_bytes());
+    ///
+    /// assert_eq!(Some(127), sc.next_u128_radix_until(",", 16).unwrap());
+    /// assert_eq!(Some(5), sc.next_u128_radix_until(",", 0).unwrap());
+    /// ```
+    #[inline]
+    pub fn next_u128_radix_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+        radix: u32,
+    ) -> Result<Option<u128>, ScannerError> {
+        self.next_parse_radix_until(boundary, radix)
+    }
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `usize` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("7f,0b101".as_bytes());
+    ///
+    /// assert_eq!(Some(127), sc.next_usize_radix_until(",", 16).unwrap());
+    /// assert_eq!(Some(5), sc.next_usize_radix_until(",", 0).unwrap());
+    /// ```
+    #[inline]
+    pub fn next_usize_radix_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+        radix: u32,
+    ) -> Result<Option<usize>, ScannerError> {
+        self.next_parse_radix_until(boundary, radix)
+    }
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `i8` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("-7f,0b101".as_bytes());
+    ///
+    /// assert_eq!(Some(-127), sc.next_i8_radix_until(",", 16).unwrap());
+    /// assert_eq!(Some(5), sc.next_i8_radix_until(",", 0).unwrap());
+    /// ```
+    #[inline]
+    pub fn next_i8_radix_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+        radix: u32,
+    ) -> Result<Option<i8>, ScannerError> {
+        self.next_parse_radix_until(boundary, radix)
+    }
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `i16` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("-7f,0b101".as_bytes());
+    ///
+    /// assert_eq!(Some(-127), sc.next_i16_radix_until(",", 16).unwrap());
+    /// assert_eq!(Some(5), sc.next_i16_radix_until(",", 0).unwrap());
+    /// ```
+    #[inline]
+    pub fn next_i16_radix_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+        radix: u32,
+    ) -> Result<Option<i16>, ScannerError> {
+        self.next_parse_radix_until(boundary, radix)
+    }
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `i32` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("-7f,0b101".as_bytes());
+    ///
+    /// assert_eq!(Some(-127), sc.next_i32_radix_until(",", 16).unwrap());
+    /// assert_eq!(Some(5), sc.next_i32_radix_until(",", 0).unwrap());
+    /// ```
+    #[inline]
+    pub fn next_i32_radix_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+        radix: u32,
+    ) -> Result<Option<i32>, ScannerError> {
+        self.next_parse_radix_until(boundary, radix)
+    }
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `i64` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("-7f,0b101".as_bytes());
+    ///
+    /// assert_eq!(Some(-127), sc.next_i64_radix_until(",", 16).unwrap());
+    /// assert_eq!(Some(5), sc.next_i64_radix_until(",", 0).unwrap());
+    /// ```
+    #[inline]
+    pub fn next_i64_radix_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+        radix: u32,
+    ) -> Result<Option<i64>, ScannerError> {
+        self.next_parse_radix_until(boundary, radix)
+    }
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `i128` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("-7f,0b101".as_bytes());
+    ///
+    /// assert_eq!(Some(-127), sc.next_i128_radix_until(",", 16).unwrap());
+    /// assert_eq!(Some(5), sc.next_i128_radix_until(",", 0).unwrap());
+    /// ```
+    #[inline]
+    pub fn next_i128_radix_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+        radix: u32,
+    ) -> Result<Option<i128>, ScannerError> {
+        self.next_parse_radix_until(boundary, radix)
+    }
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `isize` value in the given radix. The digits may be preceded by a sign and by the `0x`, `0o` or `0b` prefix of the radix. If `radix` is `0`, the radix is detected from the prefix, or it is `10` without one. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// If `radix` is neither `0` nor in the range from 2 to 36, it will return an `InvalidRadix` error without consuming anything.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("-7f,0b101".as_bytes());
+    ///
+    /// assert_eq!(Some(-127), sc.next_isize_radix_until(",", 16).unwrap());
+    /// assert_eq!(Some(5), sc.next_isize_radix_until(",", 0).unwrap());
+    /// ```
+    #[inline]
+    pub fn next_isize_radix_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+        radix: u32,
+    ) -> Result<Option<isize>, ScannerError> {
+        self.next_parse_radix_until(boundary, radix)
+    }
+}
+
+impl<'a> ScannerU8SliceAscii<'a> {
+    /// Read the next token separated by the delimiter and parse it to a `bool` value with the boolean vocabulary, which only has `true` and `false` by default. If the token is not in the vocabulary, it will return a `ParseBoolError` error. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::{BoolVocabulary, ScannerU8SliceAscii};
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("true Off".as_bytes());
+    ///
+    /// assert_eq!(Some(true), sc.next_bool().unwrap());
+    ///
+    /// sc.set_bool_vocabulary(BoolVocabulary::lenient());
+    ///
+    /// assert_eq!(Some(false), sc.next_bool().unwrap());
+    /// ```
+    #[inline]
+    pub fn next_bool(&mut self) -> Result<Option<bool>, ScannerError> {
+        self.skip_whitespaces()?;
+
+        let position = self.tracker.position();
+
+        let result = self.next()?;
+
+        match result {
+            Some(s) => {
+                Ok(Some(parse_bool(String::from_utf8_lossy(s), &self.bool_vocabulary, position)?))
+            },
+            None => Ok(None),
+        }
+    }
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `bool` value with the boolean vocabulary, which only has `true` and `false` by default. If the text is not in the vocabulary, it will return a `ParseBoolError` error. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::{BoolVocabulary, ScannerU8SliceAscii};
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("false,y".as_bytes());
+    ///
+    /// assert_eq!(Some(false), sc.next_bool_until(",").unwrap());
+    ///
+    /// sc.set_bool_vocabulary(BoolVocabulary::lenient());
+    ///
+    /// assert_eq!(Some(true), sc.next_bool_until(",").unwrap());
+    /// ```
+    #[inline]
+    pub fn next_bool_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+    ) -> Result<Option<bool>, ScannerError> {
+        let position = self.tracker.position();
+
+        let result = self.next_until(boundary)?;
+
+        match result {
+            Some(s) => {
+                Ok(Some(parse_bool(String::from_utf8_lossy(s), &self.bool_vocabulary, position)?))
+            },
+            None => Ok(None),
+        }
+    }
+}
+
+#[cfg(feature = "num-bigint")]
+impl<'a> ScannerU8SliceAscii<'a> {
+    /// Read the next token separated by the delimiter and parse it to a `BigInt` value without losing precision. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use num_bigint::BigInt;
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new(
+    ///     "-340282366920938463463374607431768211456 2".as_bytes(),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     Some(-(BigInt::from(u128::MAX) + 1u8)),
+    ///     sc.next_bigint().unwrap()
+    /// );
+    /// assert_eq!(Some(BigInt::from(2)), sc.next_bigint().unwrap());
+    /// ```
+    #[inline]
+    pub fn next_bigint(&mut self) -> Result<Option<BigInt>, ScannerError> {
+        self.next_number()
+    }
+
+    /// Read the next token separated by the delimiter and parse it to a `BigUint` value without losing precision. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use num_bigint::BigUint;
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new(
+    ///     "340282366920938463463374607431768211456 2".as_bytes(),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     Some(BigUint::from(u128::MAX) + 1u8),
+    ///     sc.next_biguint().unwrap()
+    /// );
+    /// assert_eq!(Some(BigUint::from(2u8)), sc.next_biguint().unwrap());
+    /// ```
+    #[inline]
+    pub fn next_biguint(&mut self) -> Result<Option<BigUint>, ScannerError> {
+        self.next_number()
+    }
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `BigInt` value without losing precision. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use num_bigint::BigInt;
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new(
+    ///     "-340282366920938463463374607431768211456,2".as_bytes(),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     Some(-(BigInt::from(u128::MAX) + 1u8)),
+    ///     sc.next_bigint_until(",").unwrap()
+    /// );
+    /// assert_eq!(Some(BigInt::from(2)), sc.next_bigint_until(",").unwrap());
+    /// ```
+    #[inline]
+    pub fn next_bigint_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+    ) -> Result<Option<BigInt>, ScannerError> {
+        self.next_number_until(boundary)
+    }
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `BigUint` value without losing precision. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use num_bigint::BigUint;
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new(
+    ///     "340282366920938463463374607431768211456,2".as_bytes(),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     Some(BigUint::from(u128::MAX) + 1u8),
+    ///     sc.next_biguint_until(",").unwrap()
+    /// );
+    /// assert_eq!(Some(BigUint::from(2u8)), sc.next_biguint_until(",").unwrap());
+    /// ```
+    #[inline]
+    pub fn next_biguint_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+    ) -> Result<Option<BigUint>, ScannerError> {
+        self.next_number_until(boundary)
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl<'a> ScannerU8SliceAscii<'a> {
+    /// Read the next token separated by the delimiter and parse it to a `Decimal` value without going through a floating-point number, so that no precision is lost. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use rust_decimal::Decimal;
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc =
+    ///     ScannerU8SliceAscii::new("12345678901234567890.12 0.1".as_bytes());
+    ///
+    /// assert_eq!(
+    ///     Some(Decimal::from_i128_with_scale(1234567890123456789012, 2)),
+    ///     sc.next_decimal().unwrap()
+    /// );
+    /// assert_eq!(Some(Decimal::new(1, 1)), sc.next_decimal().unwrap());
+    /// ```
+    #[inline]
+    pub fn next_decimal(&mut self) -> Result<Option<Decimal>, ScannerError> {
+        self.next_number()
+    }
+
+    /// Read the next text until it reaches a specific boundary and parse it to a `Decimal` value without going through a floating-point number, so that no precision is lost. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use rust_decimal::Decimal;
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc =
+    ///     ScannerU8SliceAscii::new("12345678901234567890.12,0.1".as_bytes());
+    ///
+    /// assert_eq!(
+    ///     Some(Decimal::from_i128_with_scale(1234567890123456789012, 2)),
+    ///     sc.next_decimal_until(",").unwrap()
+    /// );
+    /// assert_eq!(Some(Decimal::new(1, 1)), sc.next_decimal_until(",").unwrap());
+    /// ```
+    #[inline]
+    pub fn next_decimal_until<D: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        boundary: &D,
+    ) -> Result<Option<Decimal>, ScannerError> {
+        self.next_number_until(boundary)
+    }
+}
+
+impl<'a> ScannerU8SliceAscii<'a> {
+    /// Read the next token separated by delimiters, or a quoted string which can contain delimiters. A quoted string starts and ends with the same `"` or `'`, and its value is returned without the quotes. Inside it, a doubled quote stands for the quote itself, and the escape sequences `\n`, `\t`, `\r`, `\0`, `\\`, `\'`, `\"` and `\u{...}` are supported. If the data ends before the closing quote, it will return an `UnterminatedQuote` error. If there is nothing to read, it will return `Ok(None)`.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new(
+    ///     r#"name "John Smith" 42 'it''s' "a\tb\u{41}""#.as_bytes(),
+    /// );
+    ///
+    /// assert_eq!(Some("name".into()), sc.next_quoted().unwrap());
+    /// assert_eq!(Some("John Smith".into()), sc.next_quoted().unwrap());
+    /// assert_eq!(Some(42), sc.next_u8().unwrap());
+    /// assert_eq!(Some("it's".into()), sc.next_quoted().unwrap());
+    /// assert_eq!(Some("a\tbA".into()), sc.next_quoted().unwrap());
+    /// assert_eq!(None, sc.next_quoted().unwrap());
+    /// ```
+    #[inline]
+    pub fn next_quoted(&mut self) -> Result<Option<String>, ScannerError> {
+        next_quoted(self)
+    }
+}
+
+impl<'a> ScannerU8SliceAscii<'a> {
+    /// Get an iterator over the next tokens separated by delimiters. Unlike iterating the scanner itself, an error is yielded instead of ending the iteration silently.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("123 456\n789".as_bytes());
+    ///
+    /// let tokens = sc.tokens().collect::<Result<Vec<_>, _>>().unwrap();
+    ///
+    /// assert_eq!(
+    ///     vec!["123".as_bytes(), "456".as_bytes(), "789".as_bytes()],
+    ///     tokens
+    /// );
+    /// ```
+    #[inline]
+    pub fn tokens(&mut self) -> Tokens<'_, Self> {
+        Tokens::new(self)
+    }
+
+    /// Get an iterator over the next tokens or quoted strings read by `next_quoted`.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new(r#"a "b c" d"#.as_bytes());
+    ///
+    /// let tokens = sc.quoted_tokens().collect::<Result<Vec<_>, _>>().unwrap();
+    ///
+    /// assert_eq!(vec!["a", "b c", "d"], tokens);
+    /// ```
+    #[inline]
+    pub fn quoted_tokens(&mut self) -> QuotedTokens<'_, Self> {
+        QuotedTokens::new(self)
+    }
+
+    /// Get an iterator over the next lines.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("123 456\r\n\n789".as_bytes());
+    ///
+    /// let lines = sc.lines().collect::<Result<Vec<_>, _>>().unwrap();
+    ///
+    /// assert_eq!(
+    ///     vec!["123 456".as_bytes(), "".as_bytes(), "789".as_bytes()],
+    ///     lines
+    /// );
+    /// ```
+    #[inline]
+    pub fn lines(&mut self) -> Lines<'_, Self> {
+        Lines::new(self)
+    }
+
+    /// Get an iterator over the next chars.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("ab cd".as_bytes());
+    ///
+    /// assert_eq!("ab cd", sc.chars().collect::<Result<String, _>>().unwrap());
+    /// ```
+    #[inline]
+    pub fn chars(&mut self) -> Chars<'_, Self> {
+        Chars::new(self)
+    }
+
+    /// Get an iterator which parses the next tokens separated by delimiters to values of any type which implements `FromStr`. A token which cannot be parsed is yielded as an error and skipped.
+    ///
+    /// ```rust
+    /// use scanner_rust::ScannerU8SliceAscii;
+    ///
+    /// let mut sc = ScannerU8SliceAscii::new("1 2 x 4".as_bytes());
+    ///
+    /// let numbers =
+    ///     sc.parse_iter::<u32>().take(2).collect::<Result<Vec<_>, _>>().unwrap();
+    ///
+    /// assert_eq!(vec![1, 2], numbers);
+    ///
+    /// let mut numbers = sc.parse_iter::<u32>();
+    ///
+    /// assert!(numbers.next().unwrap().is_err());
+    /// assert_eq!(4, numbers.next().unwrap().unwrap());
+    /// assert!(numbers.next().is_none());
+    /// ```
+    #[inline]
+    pub fn parse_iter<T: FromStr>(&mut self) -> ParseIter<'_, Self, T>
+    where
+        <T as FromStr>::Err: Error + Send + Sync + 'static, {
+        ParseIter::new(self)
+    }
+}
+
+impl<'a> Scan for ScannerU8SliceAscii<'a> {
+    type Line = &'a [u8];
+    type Token = &'a [u8];
+
+    impl_scan_delegate!();
+
+    #[inline]
+    fn next_char(&mut self) -> Result<Option<char>, ScannerError> {
+        Self::next_char(self)
+    }
+
+    #[inline]
+    fn next_line(&mut self) -> Result<Option<&'a [u8]>, ScannerError> {
+        Self::next_line(self)
+    }
+
+    #[inline]
+    fn skip_whitespaces(&mut self) -> Result<bool, ScannerError> {
+        Self::skip_whitespaces(self)
+    }
+
+    #[inline]
+    fn next(&mut self) -> Result<Option<&'a [u8]>, ScannerError> {
+        Self::next(self)
+    }
+
+    #[inline]
+    fn next_until<S: AsRef<str>>(&mut self, boundary: S) -> Result<Option<&'a [u8]>, ScannerError> {
+        Self::next_until(self, boundary.as_ref())
+    }
 }
 
 impl<'a> Iterator for ScannerU8SliceAscii<'a> {
@@ -0,0 +1,30 @@
+//! Async scanners reading data from `tokio::io::AsyncRead`. This module is available with the `tokio` feature.
+
+use std::{
+    error::Error,
+    future::poll_fn,
+    io::{self, ErrorKind},
+    pin::Pin,
+    str::FromStr,
+};
+
+use ::tokio::io::{AsyncRead, ReadBuf};
+
+use crate::{
+    bool_vocabulary::parse_bool,
+    integer::{check_radix, parse_integer, Integer},
+    number_format::parse_number,
+    scan_buffer::ScanBuffer,
+    scanner_error::parse_token,
+    BoolVocabulary, Delimiter, NumberFormat, Position, ScannerError,
+};
+
+async fn read<R: AsyncRead + Unpin>(reader: &mut R, buf: &mut [u8]) -> io::Result<usize> {
+    let mut buf = ReadBuf::new(buf);
+
+    poll_fn(|cx| Pin::new(&mut *reader).poll_read(cx, &mut buf)).await?;
+
+    Ok(buf.filled().len())
+}
+
+async_scanners!("tokio", "tokio::io::AsyncRead");
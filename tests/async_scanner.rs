@@ -0,0 +1,388 @@
+#![cfg(feature = "tokio")]
+
+use std::{
+    fmt::Debug,
+    future::Future,
+    sync::Arc,
+    task::{Context, Poll, Wake},
+    thread::{self, Thread},
+};
+
+use scanner_rust::{
+    tokio::*, BoolVocabulary, IntegerOverflow, NumberFormat, Scanner, ScannerAscii, ScannerError,
+};
+use tokio::io::{duplex, AsyncWriteExt, DuplexStream};
+
+const DATA: &[u8] = b"12 3.5 x123 456\r\n789 \xE4\xB8\xAD\xE6\x96\x87\n\n\r -0 abc\xE3\x80\x80def \
+                      \xFF18446744073709551616 \n\rtail\r";
+
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Run a future on the current thread, which is enough for the duplex streams.
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = Box::pin(future);
+
+    let waker = Arc::new(ThreadWaker(thread::current())).into();
+    let mut cx = Context::from_waker(&waker);
+
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+/// Write the data to a duplex stream a few bytes at a time from another thread.
+fn slow_stream(data: &'static [u8]) -> DuplexStream {
+    let (reader, mut writer) = duplex(3);
+
+    thread::spawn(move || {
+        block_on(async move {
+            for chunk in data.chunks(2) {
+                writer.write_all(chunk).await.unwrap();
+            }
+        })
+    });
+
+    reader
+}
+
+fn show<T: Debug>(result: Result<T, ScannerError>) -> String {
+    match result {
+        Ok(value) => format!("{value:?}"),
+        Err(err) => err.to_string(),
+    }
+}
+
+/// Call the same methods of a sync scanner or an async scanner.
+macro_rules! read_all {
+    ($sc:ident $($await:tt)*) => {{
+        let mut results = vec![
+            show($sc.next_u8() $($await)*),
+            show($sc.skip_whitespaces() $($await)*),
+            show($sc.next_f64_until(" ") $($await)*),
+            show($sc.next_char() $($await)*),
+            show($sc.next() $($await)*),
+            show($sc.next_line() $($await)*),
+            show($sc.next_raw() $($await)*),
+            show($sc.next_char() $($await)*),
+            show($sc.next_line_raw() $($await)*),
+            show($sc.next_line() $($await)*),
+            show($sc.skip_whitespaces() $($await)*),
+            show($sc.next_i32() $($await)*),
+            show($sc.next() $($await)*),
+            show($sc.next_u64() $($await)*),
+            show($sc.next_bytes(3) $($await)*),
+            show($sc.next_until("l") $($await)*),
+            show($sc.next_until_raw("kk") $($await)*),
+            format!("{:?}", $sc.position()),
+        ];
+
+        for _ in 0..2 {
+            results.push(show($sc.next_line() $($await)*));
+            results.push(show($sc.next() $($await)*));
+            results.push(show($sc.skip_whitespaces() $($await)*));
+        }
+
+        results
+    }};
+}
+
+#[test]
+fn tokio_duplex() {
+    let expected = {
+        let mut sc = Scanner::new(DATA);
+
+        read_all!(sc)
+    };
+
+    assert_eq!(
+        expected,
+        block_on(async {
+            let mut sc = AsyncScanner::with_capacity(slow_stream(DATA), 4);
+
+            read_all!(sc.await)
+        })
+    );
+
+    let expected = {
+        let mut sc = ScannerAscii::new(DATA);
+
+        read_all!(sc)
+    };
+
+    assert_eq!(
+        expected,
+        block_on(async {
+            let mut sc = AsyncScannerAscii::new(slow_stream(DATA));
+
+            read_all!(sc.await)
+        })
+    );
+}
+
+/// Parse numbers and boolean values with a sync scanner or an async scanner.
+macro_rules! read_numbers {
+    ($sc:ident $($await:tt)*) => {{
+        let mut results = vec![
+            show($sc.next_u8() $($await)*),
+            show($sc.next_u8() $($await)*),
+            show($sc.next_u32() $($await)*),
+            show($sc.next_u16_radix(0) $($await)*),
+            show($sc.skip_whitespaces() $($await)*),
+            show($sc.next_i8_radix_until(",", 16) $($await)*),
+            show($sc.next_u8_radix(1) $($await)*),
+            show($sc.next_bool() $($await)*),
+        ];
+
+        $sc.set_number_format(NumberFormat {
+            digit_separators: vec!['_'],
+            decimal_point: ',',
+            integer_overflow: IntegerOverflow::Saturating,
+            ..NumberFormat::default()
+        });
+        $sc.set_bool_vocabulary(BoolVocabulary::lenient());
+
+        results.push(show($sc.next_u32() $($await)*));
+        results.push(show($sc.next_f64() $($await)*));
+        results.push(show($sc.next_i8() $($await)*));
+        results.push(show($sc.skip_whitespaces() $($await)*));
+        results.push(show($sc.next_bool_until(",") $($await)*));
+        results.push(show($sc.next_bool() $($await)*));
+        results.push(show($sc.next_f32() $($await)*));
+
+        results
+    }};
+}
+
+#[test]
+fn tokio_numbers() {
+    const DATA: &[u8] = b"-0 300x 99999999999abc 0x1f -7f,true 1_000 3,5 -300 No,yes\n";
+
+    let expected = {
+        let mut sc = Scanner::new(DATA);
+
+        read_numbers!(sc)
+    };
+
+    assert_eq!("Some(0)", expected[0]);
+    assert_eq!(r#"invalid digit in "99999999999abc" at 1:9"#, expected[2]);
+    assert_eq!("Some(-127)", expected[5]);
+
+    assert_eq!(
+        expected,
+        block_on(async {
+            let mut sc = AsyncScanner::with_capacity(slow_stream(DATA), 4);
+
+            read_numbers!(sc.await)
+        })
+    );
+
+    let expected = {
+        let mut sc = ScannerAscii::new(DATA);
+
+        read_numbers!(sc)
+    };
+
+    assert_eq!(
+        expected,
+        block_on(async {
+            let mut sc = AsyncScannerAscii::new(slow_stream(DATA));
+
+            read_numbers!(sc.await)
+        })
+    );
+}
+
+#[test]
+fn tokio_long_data() {
+    let data: &'static [u8] =
+        format!("{0}\r\n{0}中 {0}<=>{0}", "a".repeat(1000)).into_bytes().leak();
+
+    let expected = {
+        let mut sc = Scanner::new(data);
+
+        read_all!(sc)
+    };
+
+    assert_eq!(
+        expected,
+        block_on(async {
+            let mut sc = AsyncScanner::with_capacity(slow_stream(data), 4);
+
+            read_all!(sc.await)
+        })
+    );
+
+    block_on(async {
+        let mut sc = AsyncScanner::with_capacity(slow_stream(data), 4);
+
+        assert_eq!(Some(1000), sc.next_line().await.unwrap().map(|line| line.len()));
+        assert_eq!(Some(format!("{}中", "a".repeat(1000))), sc.next().await.unwrap());
+        assert!(sc.skip_whitespaces().await.unwrap());
+        assert_eq!(Some("a".repeat(1000)), sc.next_until("<=>").await.unwrap());
+        assert_eq!(Some("a".repeat(1000)), sc.next_until("<=>").await.unwrap());
+        assert_eq!(None, sc.next_until("<=>").await.unwrap());
+    });
+}
+
+#[test]
+fn tokio_interactive() {
+    block_on(async {
+        let (reader, mut writer) = duplex(64);
+
+        let mut sc = AsyncScanner::new(reader);
+
+        // a line is returned without waiting for the other byte of `\r\n`
+        writer.write_all(b"ping 1\r").await.unwrap();
+        assert_eq!(Some("ping".into()), sc.next().await.unwrap());
+        assert_eq!(Some(1), sc.next_u8().await.unwrap());
+        assert_eq!(Some("".into()), sc.next_line().await.unwrap());
+
+        writer.write_all(b"\npong\n").await.unwrap();
+        assert_eq!(Some("pong".into()), sc.next_line().await.unwrap());
+
+        writer.write_all("中文".as_bytes()).await.unwrap();
+        drop(writer);
+        assert_eq!(Some('中'), sc.next_char().await.unwrap());
+        assert_eq!(Some("文".into()), sc.next_line().await.unwrap());
+        assert_eq!(None, sc.next_line().await.unwrap());
+        assert!(!sc.skip_whitespaces().await.unwrap());
+    });
+}
+
+#[test]
+fn tokio_strict_utf8() {
+    block_on(async {
+        let mut sc = AsyncScanner::new(slow_stream(b"ab\xFFcd 12"));
+
+        sc.set_strict_utf8(true);
+
+        assert_eq!(
+            "invalid UTF-8 sequence 0xFF at byte 2",
+            sc.next().await.unwrap_err().to_string()
+        );
+        assert_eq!(Some(b"\xFFcd".to_vec()), sc.next_raw().await.unwrap());
+        assert_eq!(Some(12), sc.next_u8().await.unwrap());
+    });
+}
+
+/// Poll a future once and drop it, like a `tokio::select!` branch which is not taken.
+fn poll_once_and_drop<F: Future>(future: F) -> bool {
+    let mut future = Box::pin(future);
+
+    let waker = Arc::new(ThreadWaker(thread::current())).into();
+    let mut cx = Context::from_waker(&waker);
+
+    future.as_mut().poll(&mut cx).is_pending()
+}
+
+#[test]
+fn tokio_cancel_safety() {
+    let (reader, mut writer) = duplex(64);
+
+    let mut sc = AsyncScanner::new(reader);
+
+    assert!(poll_once_and_drop(sc.next_line()));
+
+    block_on(writer.write_all(b"hel")).unwrap();
+    assert!(poll_once_and_drop(sc.next_line()));
+
+    block_on(writer.write_all(b"lo\n12")).unwrap();
+    assert_eq!(Some("hello".into()), block_on(sc.next_line()).unwrap());
+    assert!(poll_once_and_drop(sc.next_u8()));
+
+    block_on(writer.write_all(b" 34")).unwrap();
+    drop(writer);
+    assert_eq!(Some(12), block_on(sc.next_u8()).unwrap());
+    assert_eq!(Some(34), block_on(sc.next_u8()).unwrap());
+    assert_eq!(6, sc.position().column);
+
+    let (reader, mut writer) = duplex(64);
+
+    let mut sc = AsyncScannerAscii::with_capacity(reader, 4);
+
+    assert!(poll_once_and_drop(sc.next()));
+
+    block_on(writer.write_all(b"abc")).unwrap();
+    assert!(poll_once_and_drop(sc.next()));
+
+    block_on(writer.write_all(b"def ")).unwrap();
+    assert_eq!(Some("abcdef".into()), block_on(sc.next()).unwrap());
+}
+
+#[cfg(feature = "futures-io")]
+mod futures_io {
+    use std::{
+        io,
+        pin::Pin,
+        task::{Context, Poll},
+    };
+
+    use scanner_rust::{
+        futures_io::{AsyncScanner, AsyncScannerAscii},
+        Scanner, ScannerAscii,
+    };
+    use tokio::io::{DuplexStream, ReadBuf};
+
+    use super::{block_on, show, slow_stream, DATA};
+
+    /// Make a tokio stream readable as a `futures_io::AsyncRead`.
+    struct Compat(DuplexStream);
+
+    impl ::futures_io::AsyncRead for Compat {
+        fn poll_read(
+            mut self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &mut [u8],
+        ) -> Poll<io::Result<usize>> {
+            let mut buf = ReadBuf::new(buf);
+
+            match tokio::io::AsyncRead::poll_read(Pin::new(&mut self.0), cx, &mut buf) {
+                Poll::Ready(Ok(())) => Poll::Ready(Ok(buf.filled().len())),
+                Poll::Ready(Err(err)) => Poll::Ready(Err(err)),
+                Poll::Pending => Poll::Pending,
+            }
+        }
+    }
+
+    #[test]
+    fn futures_io_duplex() {
+        let expected = {
+            let mut sc = Scanner::new(DATA);
+
+            read_all!(sc)
+        };
+
+        assert_eq!(
+            expected,
+            block_on(async {
+                let mut sc = AsyncScanner::with_capacity(Compat(slow_stream(DATA)), 4);
+
+                read_all!(sc.await)
+            })
+        );
+
+        let expected = {
+            let mut sc = ScannerAscii::new(DATA);
+
+            read_all!(sc)
+        };
+
+        assert_eq!(
+            expected,
+            block_on(async {
+                let mut sc = AsyncScannerAscii::new(Compat(slow_stream(DATA)));
+
+                read_all!(sc.await)
+            })
+        );
+    }
+}
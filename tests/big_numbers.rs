@@ -0,0 +1,91 @@
+#![cfg(any(feature = "num-bigint", feature = "rust_decimal"))]
+
+use scanner_rust::{
+    generic_array::typenum::U8, NumberFormat, Scan, Scanner, ScannerAscii, ScannerStr,
+    ScannerU8Slice, ScannerU8SliceAscii,
+};
+
+#[cfg(feature = "num-bigint")]
+fn read_bigints<S: Scan>(sc: &mut S) -> Vec<String> {
+    let mut results = vec![
+        format!("{:?}", sc.next_bigint().unwrap()),
+        format!("{:?}", sc.next_biguint().unwrap()),
+        sc.next_biguint().unwrap_err().to_string(),
+    ];
+
+    assert!(sc.skip_whitespaces().unwrap());
+    results.push(format!("{:?}", sc.next_bigint_until(",").unwrap()));
+
+    sc.set_number_format(NumberFormat {
+        grouping: Some(','),
+        ..NumberFormat::default()
+    });
+
+    results.push(format!("{:?}", sc.next_biguint().unwrap()));
+    results.push(format!("{:?}", sc.next_bigint().unwrap()));
+
+    results
+}
+
+#[cfg(feature = "num-bigint")]
+#[test]
+fn bigint() {
+    let data = "-115792089237316195423570985008687907853269984665640564039457584007913129639936 \
+                340282366920938463463374607431768211456 -1 +42,1,000,000,000,000,000,000,000";
+
+    let expected = vec![
+        "Some(-115792089237316195423570985008687907853269984665640564039457584007913129639936)",
+        "Some(340282366920938463463374607431768211456)",
+        "invalid digit found in string in \"-1\" at 1:121",
+        "Some(42)",
+        "Some(1000000000000000000000)",
+        "None",
+    ];
+
+    assert_eq!(expected, read_bigints(&mut ScannerStr::new(data)));
+    assert_eq!(expected, read_bigints(&mut ScannerU8Slice::new(data)));
+    assert_eq!(expected, read_bigints(&mut ScannerU8SliceAscii::new(data)));
+    assert_eq!(expected, read_bigints(&mut Scanner::new(data.as_bytes())));
+    assert_eq!(expected, read_bigints(&mut ScannerAscii::<_, U8>::new2(data.as_bytes())));
+}
+
+#[cfg(feature = "rust_decimal")]
+fn read_decimals<S: Scan>(sc: &mut S) -> Vec<String> {
+    let mut results = vec![
+        sc.next_decimal().unwrap().unwrap().to_string(),
+        sc.next_decimal().unwrap().unwrap().to_string(),
+        sc.next_decimal().unwrap_err().to_string(),
+    ];
+
+    sc.set_number_format(NumberFormat {
+        grouping: Some('.'),
+        decimal_point: ',',
+        ..NumberFormat::default()
+    });
+
+    assert!(sc.skip_whitespaces().unwrap());
+    results.push(sc.next_decimal_until(";").unwrap().unwrap().to_string());
+    results.push(sc.next_decimal().unwrap().unwrap().to_string());
+
+    results
+}
+
+#[cfg(feature = "rust_decimal")]
+#[test]
+fn decimal() {
+    let data = "0.1 12345678901234567890.123456789 1.2.3 -1.234.567,89;+0,30";
+
+    let expected = vec![
+        "0.1",
+        "12345678901234567890.123456789",
+        "Invalid decimal: two decimal points in \"1.2.3\" at 1:36",
+        "-1234567.89",
+        "0.30",
+    ];
+
+    assert_eq!(expected, read_decimals(&mut ScannerStr::new(data)));
+    assert_eq!(expected, read_decimals(&mut ScannerU8Slice::new(data)));
+    assert_eq!(expected, read_decimals(&mut ScannerU8SliceAscii::new(data)));
+    assert_eq!(expected, read_decimals(&mut Scanner::new(data.as_bytes())));
+    assert_eq!(expected, read_decimals(&mut ScannerAscii::<_, U8>::new2(data.as_bytes())));
+}
@@ -0,0 +1,108 @@
+#![cfg(feature = "regex")]
+
+use regex::{bytes, Regex};
+use scanner_rust::{generic_array::typenum::U8, Scanner, ScannerStr, ScannerU8Slice};
+
+const DATA: &str = "PI 3.14 中文\r\nXYZ 2.71,ABCD\n";
+
+#[test]
+fn next_matching() {
+    let decimal = r"\d+\.\d+";
+    let code = r"[A-Z]{3}";
+
+    let mut sc = ScannerStr::new(DATA);
+    let (decimal_str, code_str) = (Regex::new(decimal).unwrap(), Regex::new(code).unwrap());
+
+    assert_eq!(None, sc.next_matching(&decimal_str).unwrap());
+    assert_eq!(Some("PI"), sc.next().unwrap());
+    assert_eq!(Some("3.14"), sc.next_matching(&decimal_str).unwrap());
+    assert_eq!(None, sc.next_matching(&code_str).unwrap());
+    assert_eq!(Some("中文"), sc.next().unwrap());
+    assert_eq!(Some("XYZ"), sc.next_matching(&code_str).unwrap());
+    assert_eq!(Some("2.71"), sc.next_matching(&decimal_str).unwrap());
+    assert_eq!(Some(","), sc.next_until("A").unwrap());
+    assert_eq!(Some("BCD"), sc.next_matching(&code_str).unwrap());
+    assert_eq!(None, sc.next_matching(&code_str).unwrap());
+
+    let (decimal_bytes, code_bytes) =
+        (bytes::Regex::new(decimal).unwrap(), bytes::Regex::new(code).unwrap());
+
+    let mut sc = ScannerU8Slice::new(DATA);
+
+    assert_eq!(None, sc.next_matching(&decimal_bytes).unwrap());
+    assert_eq!(Some("PI".as_bytes()), sc.next().unwrap());
+    assert_eq!(Some("3.14".as_bytes()), sc.next_matching(&decimal_bytes).unwrap());
+    assert_eq!(None, sc.next_matching(&code_bytes).unwrap());
+    assert_eq!(Some("中文".as_bytes()), sc.next().unwrap());
+    assert_eq!(Some("XYZ".as_bytes()), sc.next_matching(&code_bytes).unwrap());
+    assert_eq!(Some("2.71".as_bytes()), sc.next_matching(&decimal_bytes).unwrap());
+    assert_eq!(Some(",".as_bytes()), sc.next_until("A").unwrap());
+    assert_eq!(Some("BCD".as_bytes()), sc.next_matching(&code_bytes).unwrap());
+    assert_eq!(None, sc.next_matching(&code_bytes).unwrap());
+
+    let mut sc: Scanner<_, U8> = Scanner::new2(DATA.as_bytes());
+
+    assert_eq!(None, sc.next_matching(&decimal_bytes).unwrap());
+    assert_eq!(Some("PI".into()), sc.next().unwrap());
+    assert_eq!(Some("3.14".into()), sc.next_matching(&decimal_bytes).unwrap());
+    assert_eq!(None, sc.next_matching(&code_bytes).unwrap());
+    assert_eq!(Some("中文".into()), sc.next().unwrap());
+    assert_eq!(Some("XYZ".into()), sc.next_matching(&code_bytes).unwrap());
+    assert_eq!(Some("2.71".into()), sc.next_matching(&decimal_bytes).unwrap());
+    assert_eq!(Some(",".into()), sc.next_until("A").unwrap());
+    assert_eq!(Some("BCD".into()), sc.next_matching(&code_bytes).unwrap());
+    assert_eq!(None, sc.next_matching(&code_bytes).unwrap());
+}
+
+#[test]
+fn find_and_skip() {
+    let number = r"\d";
+    let space = r"\s+";
+
+    let (number_str, space_str) = (Regex::new(number).unwrap(), Regex::new(space).unwrap());
+
+    let mut sc = ScannerStr::new(DATA);
+
+    assert!(!sc.skip_pattern(&space_str).unwrap());
+    assert_eq!(Some("3"), sc.find_in_line(&number_str).unwrap());
+    assert_eq!(Some("1"), sc.find_in_line(&number_str).unwrap());
+    assert_eq!(Some("4"), sc.find_in_line(&number_str).unwrap());
+    assert_eq!(None, sc.find_in_line(&number_str).unwrap());
+    assert!(sc.skip_pattern(&space_str).unwrap());
+    assert_eq!(Some('中'), sc.next_char().unwrap());
+    assert_eq!(None, sc.find_within_horizon(&number_str, 6).unwrap());
+    assert_eq!(Some("2"), sc.find_within_horizon(&number_str, 0).unwrap());
+    assert_eq!(Some(".71,ABCD"), sc.next_line().unwrap());
+
+    let (number_bytes, space_bytes) =
+        (bytes::Regex::new(number).unwrap(), bytes::Regex::new(space).unwrap());
+
+    let mut sc = ScannerU8Slice::new(DATA);
+
+    assert!(!sc.skip_pattern(&space_bytes).unwrap());
+    assert_eq!(Some("3".as_bytes()), sc.find_in_line(&number_bytes).unwrap());
+    assert_eq!(Some("1".as_bytes()), sc.find_in_line(&number_bytes).unwrap());
+    assert_eq!(Some("4".as_bytes()), sc.find_in_line(&number_bytes).unwrap());
+    assert_eq!(None, sc.find_in_line(&number_bytes).unwrap());
+    assert!(sc.skip_pattern(&space_bytes).unwrap());
+    assert_eq!(Some('中'), sc.next_char().unwrap());
+    assert_eq!(None, sc.find_within_horizon(&number_bytes, 6).unwrap());
+    assert_eq!(Some("2".as_bytes()), sc.find_within_horizon(&number_bytes, 0).unwrap());
+    assert_eq!(Some(".71,ABCD".as_bytes()), sc.next_line().unwrap());
+
+    let mut sc: Scanner<_, U8> = Scanner::new2(DATA.as_bytes());
+
+    assert!(!sc.skip_pattern(&space_bytes).unwrap());
+    assert_eq!(Some("3".into()), sc.find_in_line(&number_bytes).unwrap());
+    assert_eq!(Some("1".into()), sc.find_in_line(&number_bytes).unwrap());
+    assert_eq!(Some("4".into()), sc.find_in_line(&number_bytes).unwrap());
+    assert_eq!(None, sc.find_in_line(&number_bytes).unwrap());
+    assert!(sc.skip_pattern(&space_bytes).unwrap());
+    assert_eq!(Some('中'), sc.next_char().unwrap());
+    assert_eq!(None, sc.find_within_horizon(&number_bytes, 6).unwrap());
+    // the horizon cannot be larger than the buffer
+    assert_eq!(None, sc.find_within_horizon(&number_bytes, 0).unwrap());
+    assert_eq!(Some("文".into()), sc.next_line().unwrap());
+    assert_eq!(Some("2".into()), sc.find_within_horizon(&number_bytes, 0).unwrap());
+    assert_eq!(Some(".71,ABCD".into()), sc.next_line().unwrap());
+}
@@ -0,0 +1,940 @@
+use std::num::IntErrorKind;
+
+use scanner_rust::{
+    generic_array::typenum::{U16, U4, U8},
+    Delimiter, Position, Scan, Scanner, ScannerAscii, ScannerError, ScannerErrorKind, ScannerStr,
+    ScannerU8Slice, ScannerU8SliceAscii,
+};
+
+fn read_all<S: Scan>(sc: &mut S) -> (Option<u8>, Option<f64>, Option<char>, Vec<Vec<u8>>) {
+    let a = sc.next_u8().unwrap();
+    assert!(sc.skip_whitespaces().unwrap());
+    let b = sc.next_f64_until(" ").unwrap();
+    let c = sc.next_char().unwrap();
+
+    let mut tokens = Vec::new();
+
+    while let Some(token) = sc.next().unwrap() {
+        tokens.push(token.as_ref().to_vec());
+    }
+
+    (a, b, c, tokens)
+}
+
+#[test]
+fn generic_tokens() {
+    let data = "12 3.5 x123 456\r\n789 ";
+
+    let expected =
+        (Some(12), Some(3.5), Some('x'), vec![b"123".to_vec(), b"456".to_vec(), b"789".to_vec()]);
+
+    assert_eq!(expected, read_all(&mut Scanner::new(data.as_bytes())));
+    assert_eq!(expected, read_all(&mut ScannerAscii::new(data.as_bytes())));
+    assert_eq!(expected, read_all(&mut ScannerStr::new(data)));
+    assert_eq!(expected, read_all(&mut ScannerU8Slice::new(data)));
+    assert_eq!(expected, read_all(&mut ScannerU8SliceAscii::new(data)));
+}
+
+fn read_lines<S: Scan>(sc: &mut S) -> Vec<Vec<u8>> {
+    let mut lines = Vec::new();
+
+    assert!(sc.skip_whitespaces().unwrap());
+
+    while let Some(line) = sc.next_line().unwrap() {
+        lines.push(line.as_ref().to_vec());
+    }
+
+    lines
+}
+
+#[test]
+fn generic_lines() {
+    let data = "  abc\r\n\ndef\rghi";
+
+    let expected = vec![b"abc".to_vec(), b"".to_vec(), b"def".to_vec(), b"ghi".to_vec()];
+
+    assert_eq!(expected, read_lines(&mut Scanner::new(data.as_bytes())));
+    assert_eq!(expected, read_lines(&mut ScannerAscii::new(data.as_bytes())));
+    assert_eq!(expected, read_lines(&mut ScannerStr::new(data)));
+    assert_eq!(expected, read_lines(&mut ScannerU8Slice::new(data)));
+    assert_eq!(expected, read_lines(&mut ScannerU8SliceAscii::new(data)));
+}
+
+fn read_parsed<S: Scan>(sc: &mut S) -> (Option<bool>, Option<char>, Option<String>) {
+    let a = sc.next_parse().unwrap();
+    assert!(sc.skip_whitespaces().unwrap());
+    let b = sc.next_parse_until(",").unwrap();
+    let c = sc.next_parse().unwrap();
+
+    assert!(sc.next_parse::<bool>().is_err());
+
+    (a, b, c)
+}
+
+#[test]
+fn generic_parse() {
+    let data = "true x,中文 maybe";
+
+    let expected = (Some(true), Some('x'), Some("中文".to_string()));
+
+    assert_eq!(expected, read_parsed(&mut Scanner::new(data.as_bytes())));
+    assert_eq!(expected, read_parsed(&mut ScannerStr::new(data)));
+    assert_eq!(expected, read_parsed(&mut ScannerU8Slice::new(data)));
+}
+
+fn show<T: AsRef<[u8]>>(data: T) -> String {
+    String::from_utf8_lossy(data.as_ref()).into_owned()
+}
+
+fn iterate<S: Scan>(sc: &mut S) -> (Vec<String>, Vec<String>, String, Vec<String>) {
+    let tokens = sc.tokens().take(2).map(|token| show(token.unwrap())).collect::<Vec<_>>();
+
+    let numbers = sc
+        .parse_iter::<u8>()
+        .take(4)
+        .map(|result| match result {
+            Ok(n) => n.to_string(),
+            Err(err) => err.to_string(),
+        })
+        .collect::<Vec<_>>();
+
+    let chars = sc.chars().take(3).collect::<Result<String, _>>().unwrap();
+
+    let lines = sc.lines().map(|line| show(line.unwrap())).collect::<Vec<_>>();
+
+    (tokens, numbers, chars, lines)
+}
+
+#[test]
+fn iterators() {
+    let data = "ab cd\t1 256 x 2 中文\r\n\nef";
+
+    let expected = (
+        vec!["ab".to_string(), "cd".to_string()],
+        vec![
+            "1".to_string(),
+            "number too large in \"256\" at 1:9".to_string(),
+            "invalid digit in \"x\" at 1:13".to_string(),
+            "2".to_string(),
+        ],
+        " 中文".to_string(),
+        vec!["".to_string(), "".to_string(), "ef".to_string()],
+    );
+
+    assert_eq!(expected, iterate(&mut Scanner::new(data.as_bytes())));
+    assert_eq!(expected, iterate(&mut ScannerStr::new(data)));
+    assert_eq!(expected, iterate(&mut ScannerU8Slice::new(data)));
+
+    let collected =
+        Scanner::new("1 2 3".as_bytes()).parse_iter::<u32>().collect::<Result<Vec<_>, _>>();
+
+    assert_eq!(vec![1, 2, 3], collected.unwrap());
+
+    // the streaming scanners can be iterated directly too, just like the in-memory ones
+    assert_eq!(vec!["ab", "cd"], Scanner::new("ab cd".as_bytes()).collect::<Vec<_>>());
+    assert_eq!(vec!["ab", "cd"], ScannerAscii::new("ab cd".as_bytes()).collect::<Vec<_>>());
+}
+
+#[test]
+fn iterators_stop_on_unrecoverable_errors() {
+    let mut sc: Scanner<_, U8> = Scanner::new2(b"1 \xFF 2".as_ref());
+
+    sc.set_strict_utf8(true);
+
+    let mut tokens = sc.tokens();
+
+    assert_eq!("1", tokens.next().unwrap().unwrap());
+    assert!(matches!(tokens.next(), Some(Err(ScannerError::InvalidUtf8 { .. }))));
+    assert!(tokens.next().is_none());
+
+    // the invalid data is still there after the iterator ends
+    let mut sc = Scanner::new(b"a\xFFb".as_ref());
+
+    sc.set_strict_utf8(true);
+
+    let mut chars = sc.chars();
+
+    assert_eq!('a', chars.next().unwrap().unwrap());
+    assert!(matches!(chars.next(), Some(Err(ScannerError::InvalidUtf8 { .. }))));
+    assert!(chars.next().is_none());
+    assert!(matches!(sc.next_char(), Err(ScannerError::InvalidUtf8 { .. })));
+
+    struct Broken;
+
+    impl std::io::Read for Broken {
+        fn read(&mut self, _buf: &mut [u8]) -> std::io::Result<usize> {
+            Err(std::io::ErrorKind::BrokenPipe.into())
+        }
+    }
+
+    let mut sc = ScannerAscii::new(Broken);
+
+    let mut numbers = sc.parse_iter::<u8>();
+
+    assert!(matches!(numbers.next(), Some(Err(ScannerError::IOError(_)))));
+    assert!(numbers.next().is_none());
+}
+
+fn parse_past_errors<S: Scan>(sc: &mut S) -> Vec<Result<u8, ScannerErrorKind>> {
+    sc.parse_iter::<u8>().map(|result| result.map_err(|err| err.kind())).collect()
+}
+
+#[test]
+fn iterators_continue_after_recoverable_errors() {
+    let data = "1 x 2 300 3";
+
+    let expected = vec![
+        Ok(1),
+        Err(ScannerErrorKind::InvalidDigit),
+        Ok(2),
+        Err(ScannerErrorKind::Overflow),
+        Ok(3),
+    ];
+
+    assert_eq!(expected, parse_past_errors(&mut Scanner::new(data.as_bytes())));
+    assert_eq!(expected, parse_past_errors(&mut ScannerAscii::new(data.as_bytes())));
+    assert_eq!(expected, parse_past_errors(&mut ScannerStr::new(data)));
+    assert_eq!(expected, parse_past_errors(&mut ScannerU8Slice::new(data)));
+    assert_eq!(expected, parse_past_errors(&mut ScannerU8SliceAscii::new(data)));
+}
+
+/// Peek everything before reading it, and check that the peeked data is what is read.
+fn peek_all<S: Scan>(sc: &mut S) -> Vec<String> {
+    let mut results = Vec::new();
+
+    for i in 0.. {
+        let (peeked, read) = match i % 3 {
+            0 => {
+                let peeked = sc.peek_token().unwrap().map(show);
+
+                (peeked, sc.next().unwrap().map(show))
+            },
+            1 => {
+                let peeked = sc.peek_char().unwrap().map(String::from);
+
+                (peeked, sc.next_char().unwrap().map(String::from))
+            },
+            _ => {
+                let peeked = sc.peek_line().unwrap().map(show);
+
+                (peeked, sc.next_line().unwrap().map(show))
+            },
+        };
+
+        assert_eq!(peeked, read);
+
+        match read {
+            Some(read) => results.push(read),
+            None => break,
+        }
+    }
+
+    results
+}
+
+#[test]
+fn peeks() {
+    let data = "12 中文 0123456789abc\r\n\rxy z\n\n  long_token_longer_than_buffers\r";
+
+    let expected = peek_all(&mut ScannerStr::new(data));
+
+    assert_eq!(expected, peek_all(&mut ScannerU8Slice::new(data)));
+    assert_eq!(expected, peek_all(&mut Scanner::new(data.as_bytes())));
+    assert_eq!(expected, peek_all(&mut Scanner::<_, U4>::new2(data.as_bytes())));
+    assert_eq!(expected, peek_all(&mut Scanner::<_, U16>::new2(data.as_bytes())));
+
+    let data = "12 ab 0123456789abc\r\n\rxy z\n\n  long_token_longer_than_buffers\r";
+
+    let expected = peek_all(&mut ScannerU8SliceAscii::new(data));
+
+    assert_eq!(expected, peek_all(&mut ScannerAscii::new(data.as_bytes())));
+    assert_eq!(expected, peek_all(&mut ScannerAscii::<_, U8>::new2(data.as_bytes())));
+
+    // the peeked data is not counted in the position, nor in the mark
+    let mut sc = Scanner::<_, U8>::new2(data.as_bytes());
+
+    sc.mark(4);
+
+    assert_eq!(Some(data.as_bytes()[..32].to_vec()), sc.peek_bytes(32).unwrap());
+    assert_eq!(0, sc.position().offset);
+    assert_eq!(Some("12".into()), sc.next().unwrap());
+    assert!(sc.reset());
+    assert_eq!(Some(12), sc.next_u8().unwrap());
+}
+
+fn read_quoted<S: Scan>(sc: &mut S) -> Vec<String> {
+    let mut results = Vec::new();
+
+    loop {
+        match sc.next_quoted() {
+            Ok(Some(token)) => results.push(token),
+            Ok(None) => break,
+            Err(err) => {
+                results.push(err.to_string());
+
+                if let ScannerError::UnterminatedQuote {
+                    ..
+                } = err
+                {
+                    break;
+                }
+            },
+        }
+    }
+
+    results
+}
+
+#[test]
+fn quoted() {
+    let data = r#"a "b c"d '' "it's" 'say ""hi""' "\\\"\'\n\r\t\0\u{4E2D}\u{1F600}"
+        "say ""hi""" "中文 'x" "unterminated"#;
+
+    let expected = vec![
+        "a",
+        "b c",
+        "d",
+        "",
+        "it's",
+        "say \"\"hi\"\"",
+        "\\\"'\n\r\t\0中😀",
+        "say \"hi\"",
+        "中文 'x",
+        "unterminated quote at 2:30",
+    ];
+
+    assert_eq!(expected, read_quoted(&mut ScannerStr::new(data)));
+    assert_eq!(expected, read_quoted(&mut ScannerU8Slice::new(data)));
+    assert_eq!(expected, read_quoted(&mut Scanner::new(data.as_bytes())));
+    assert_eq!(expected, read_quoted(&mut Scanner::<_, U4>::new2(data.as_bytes())));
+
+    let data = "'a''b' \"x";
+
+    let expected = vec!["a'b".to_string(), "unterminated quote at 1:8".to_string()];
+
+    assert_eq!(expected, read_quoted(&mut ScannerAscii::<_, U8>::new2(data.as_bytes())));
+    assert_eq!(expected, read_quoted(&mut ScannerU8SliceAscii::new(data)));
+    assert_eq!(
+        vec!["a'b".to_string()],
+        ScannerStr::new("'a''b'").quoted_tokens().collect::<Result<Vec<_>, _>>().unwrap()
+    );
+}
+
+#[test]
+fn invalid_escapes() {
+    for (data, sequence) in [
+        (r#"x "\q""#, r"\q"),
+        (r#"x "\u{110000}""#, r"\u{110000}"),
+        (r#"x "\u{1234567}""#, r"\u{1234567"),
+        (r#"x "\u{}""#, r"\u{}"),
+        (r#"x "\u41""#, r"\u"),
+        (r#"x "\"#, r"\"),
+    ] {
+        let expected =
+            vec!["x".to_string(), format!("invalid escape sequence {sequence:?} at 1:4")];
+
+        let first_two = |mut results: Vec<String>| {
+            results.truncate(2);
+
+            results
+        };
+
+        assert_eq!(expected, first_two(read_quoted(&mut ScannerStr::new(data))));
+        assert_eq!(expected, first_two(read_quoted(&mut ScannerU8SliceAscii::new(data))));
+        assert_eq!(expected, first_two(read_quoted(&mut Scanner::new(data.as_bytes()))));
+    }
+}
+
+fn read_with_delimiter<S: Scan, D: Into<Delimiter>>(
+    sc: &mut S,
+    delimiter: D,
+) -> (Vec<Vec<u8>>, Option<Vec<u8>>) {
+    sc.set_delimiter(delimiter);
+
+    let mut tokens = Vec::new();
+
+    while let Some(token) = sc.next().unwrap() {
+        tokens.push(token.as_ref().to_vec());
+    }
+
+    (tokens, sc.next_line().unwrap().map(|line| line.as_ref().to_vec()))
+}
+
+#[test]
+fn delimiters() {
+    fn check<D: Clone + Into<Delimiter>>(data: &str, delimiter: D, expected: &[&str]) {
+        let ascii = data.is_ascii();
+
+        let expected = (expected.iter().map(|s| s.as_bytes().to_vec()).collect::<Vec<_>>(), None);
+
+        assert_eq!(
+            expected,
+            read_with_delimiter(&mut Scanner::new(data.as_bytes()), delimiter.clone())
+        );
+        assert_eq!(
+            expected,
+            read_with_delimiter(&mut Scanner::<_, U4>::new2(data.as_bytes()), delimiter.clone())
+        );
+        if ascii {
+            assert_eq!(
+                expected,
+                read_with_delimiter(&mut ScannerAscii::new(data.as_bytes()), delimiter.clone())
+            );
+            assert_eq!(
+                expected,
+                read_with_delimiter(
+                    &mut ScannerAscii::<_, U4>::new2(data.as_bytes()),
+                    delimiter.clone()
+                )
+            );
+        }
+        assert_eq!(expected, read_with_delimiter(&mut ScannerStr::new(data), delimiter.clone()));
+        assert_eq!(
+            expected,
+            read_with_delimiter(&mut ScannerU8Slice::new(data), delimiter.clone())
+        );
+
+        if ascii {
+            assert_eq!(
+                expected,
+                read_with_delimiter(&mut ScannerU8SliceAscii::new(data), delimiter)
+            );
+        }
+    }
+
+    check("a b,c,,d ,", b',', &["a b", "c", "d "]);
+    check("1;2|3; |4", [';', '|'], &["1", "2", "3", " ", "4"]);
+    check("abc<>de<><>f<g><", "<>", &["abc", "de", "f<g><"]);
+    check("中文，中文,，x", ['，', ','], &["中文", "中文", "x"]);
+}
+
+#[test]
+fn delimiters_typed() {
+    let data = "12,-3,4.5,";
+
+    let mut sc = Scanner::new(data.as_bytes());
+    sc.set_delimiter(',');
+
+    assert_eq!(Some(12), sc.next_u8().unwrap());
+    assert!(sc.skip_whitespaces().unwrap());
+    assert_eq!(Some(-3), sc.next_i32().unwrap());
+    assert_eq!(Some(4.5), sc.next_f64().unwrap());
+    assert_eq!(None, sc.next_f64().unwrap());
+    assert!(!sc.skip_whitespaces().unwrap());
+
+    let mut sc = ScannerStr::new(" 1 ,2");
+    sc.set_delimiter(',');
+
+    assert_eq!(Some(" 1 "), sc.next().unwrap());
+    assert_eq!(Some("2"), sc.next().unwrap());
+}
+
+fn check_has_next<S: Scan>(sc: &mut S) {
+    assert!(sc.has_next_line().unwrap());
+    assert!(sc.has_next().unwrap());
+    assert!(sc.has_next_i32().unwrap());
+    assert!(!sc.has_next_u8().unwrap());
+    assert!(sc.has_next_f64().unwrap());
+    assert_eq!(b"", sc.next_line().unwrap().unwrap().as_ref());
+    assert!(sc.has_next_i32().unwrap());
+    assert_eq!(Some(-300), sc.next_i32().unwrap());
+
+    assert!(!sc.has_next_i32().unwrap());
+    assert!(sc.has_next_f64().unwrap());
+    assert!(sc.has_next_parse::<String>().unwrap());
+    assert_eq!(Some(2.5), sc.next_f64().unwrap());
+
+    assert!(!sc.has_next_f64().unwrap());
+    assert_eq!(Some('\n'), sc.next_char().unwrap());
+    assert!(sc.has_next().unwrap());
+    assert!(!sc.has_next_parse::<f64>().unwrap());
+    assert_eq!(b"x", sc.next().unwrap().unwrap().as_ref());
+
+    assert!(sc.has_next_line().unwrap());
+    assert!(!sc.has_next().unwrap());
+    assert!(!sc.has_next_i32().unwrap());
+    assert_eq!(b"  ", sc.next_line().unwrap().unwrap().as_ref());
+    assert!(!sc.has_next_line().unwrap());
+    assert!(!sc.has_next().unwrap());
+}
+
+#[test]
+fn has_next() {
+    let data = "\n   -300   \t\t  2.5\nx  ";
+
+    check_has_next(&mut Scanner::new(data.as_bytes()));
+    check_has_next(&mut Scanner::<_, U16>::new2(data.as_bytes()));
+    check_has_next(&mut ScannerAscii::new(data.as_bytes()));
+    check_has_next(&mut ScannerAscii::<_, U16>::new2(data.as_bytes()));
+    check_has_next(&mut ScannerStr::new(data));
+    check_has_next(&mut ScannerU8Slice::new(data));
+    check_has_next(&mut ScannerU8SliceAscii::new(data));
+}
+
+#[test]
+fn has_next_long_token() {
+    let mut sc = Scanner::<_, U8>::new2("  1234567 123456789 9".as_bytes());
+
+    assert!(sc.has_next_u32().unwrap());
+    assert_eq!(Some(1234567), sc.next_u32().unwrap());
+    assert!(sc.has_next().unwrap());
+    assert!(sc.has_next_u32().unwrap());
+    assert_eq!(Some(123456789), sc.next_u32().unwrap());
+    assert!(sc.has_next_u32().unwrap());
+    assert_eq!(Some(9), sc.next_u32().unwrap());
+    assert!(!sc.has_next().unwrap());
+}
+
+fn check_has_next_beyond_buffer<S: Scan>(sc: &mut S) -> Vec<String> {
+    let mut results =
+        vec![String::from_utf8_lossy(sc.next_line().unwrap().unwrap().as_ref()).into_owned()];
+
+    assert!(sc.has_next().unwrap());
+    assert!(sc.has_next_parse::<String>().unwrap());
+    assert!(sc.has_next_f64().unwrap());
+    assert!(!sc.has_next_u128().unwrap());
+
+    results.push(String::from_utf8_lossy(sc.next_line().unwrap().unwrap().as_ref()).into_owned());
+
+    assert!(!sc.has_next().unwrap());
+
+    results
+}
+
+#[test]
+fn has_next_beyond_buffer() {
+    let data = format!("x\n{}{}", " ".repeat(300), "7".repeat(300));
+
+    let expected = vec!["x".to_string(), data[2..].to_string()];
+
+    assert_eq!(expected, check_has_next_beyond_buffer(&mut Scanner::new(data.as_bytes())));
+    assert_eq!(expected, check_has_next_beyond_buffer(&mut ScannerAscii::new(data.as_bytes())));
+    assert_eq!(expected, check_has_next_beyond_buffer(&mut ScannerStr::new(&data)));
+
+    let data = format!("{}  ", "7".repeat(300));
+
+    let mut sc = Scanner::new(data.as_bytes());
+
+    assert!(sc.has_next().unwrap());
+    assert!(sc.has_next_parse::<String>().unwrap());
+    assert!(!sc.has_next_u64().unwrap());
+    assert_eq!(Some(data.trim_end().to_string()), sc.next_parse().unwrap());
+    assert!(!sc.has_next().unwrap());
+}
+
+fn track_positions<S: Scan>(sc: &mut S) -> Vec<(usize, usize, usize)> {
+    let mut positions = Vec::new();
+
+    let mut record = |sc: &S| {
+        let position = sc.position();
+
+        positions.push((position.offset, position.line, position.column));
+    };
+
+    record(sc);
+    sc.next_line().unwrap();
+    record(sc);
+    sc.next().unwrap();
+    record(sc);
+    sc.skip_whitespaces().unwrap();
+    record(sc);
+    sc.next_char().unwrap();
+    record(sc);
+    sc.next_until("\r").unwrap();
+    record(sc);
+    sc.next_char().unwrap();
+    record(sc);
+    sc.next_i32().unwrap();
+    record(sc);
+    sc.next_line().unwrap();
+    record(sc);
+    sc.next_line().unwrap();
+    record(sc);
+
+    positions
+}
+
+#[test]
+fn positions() {
+    let data = "abc\r\nxyz \t中文,de\r\n-12\n\n";
+
+    let expected = vec![
+        (0, 1, 1),
+        (5, 2, 1),
+        (8, 2, 4),
+        (10, 2, 6),
+        (13, 2, 7),
+        (20, 3, 1),
+        (21, 3, 1),
+        (24, 3, 4),
+        (25, 4, 1),
+        (26, 5, 1),
+    ];
+
+    assert_eq!(expected, track_positions(&mut Scanner::new(data.as_bytes())));
+    assert_eq!(expected, track_positions(&mut ScannerStr::new(data)));
+    assert_eq!(expected, track_positions(&mut ScannerU8Slice::new(data)));
+
+    assert_eq!(expected, track_positions(&mut Scanner::<_, U4>::new2(data.as_bytes())));
+
+    // the ASCII scanners count columns in bytes
+    let expected_ascii = vec![
+        (0, 1, 1),
+        (5, 2, 1),
+        (8, 2, 4),
+        (10, 2, 6),
+        (11, 2, 7),
+        (20, 3, 1),
+        (21, 3, 1),
+        (24, 3, 4),
+        (25, 4, 1),
+        (26, 5, 1),
+    ];
+
+    assert_eq!(expected_ascii, track_positions(&mut ScannerAscii::new(data.as_bytes())));
+    assert_eq!(expected_ascii, track_positions(&mut ScannerU8SliceAscii::new(data)));
+    assert_eq!(expected_ascii, track_positions(&mut ScannerAscii::<_, U4>::new2(data.as_bytes())));
+}
+
+#[test]
+fn positions_of_drops() {
+    let data = "12 34\n\r5678\r\n";
+
+    let mut sc = Scanner::<_, U4>::new2(data.as_bytes());
+
+    assert_eq!(Some(2), sc.drop_next().unwrap());
+    assert_eq!(
+        Position {
+            offset: 2, line: 1, column: 3
+        },
+        sc.position()
+    );
+    assert_eq!(Some(3), sc.drop_next_line().unwrap());
+    assert_eq!(
+        Position {
+            offset: 7, line: 2, column: 1
+        },
+        sc.position()
+    );
+    assert_eq!(Some(2), sc.drop_next_bytes(2).unwrap());
+    assert_eq!(
+        Position {
+            offset: 9, line: 2, column: 3
+        },
+        sc.position()
+    );
+    assert_eq!(Some(3), sc.drop_next_until("\n").unwrap());
+    assert_eq!(
+        Position {
+            offset: 13, line: 3, column: 1
+        },
+        sc.position()
+    );
+
+    let mut sc = ScannerU8Slice::new(data);
+
+    assert_eq!(Some(2), sc.drop_next_bytes(2).unwrap());
+    assert_eq!(
+        Position {
+            offset: 2, line: 1, column: 3
+        },
+        sc.position()
+    );
+    assert_eq!(Some(" 34".as_bytes()), sc.next_line().unwrap());
+    assert_eq!(
+        Position {
+            offset: 7, line: 2, column: 1
+        },
+        sc.position()
+    );
+}
+
+fn parse_errors<S: Scan>(sc: &mut S) -> Vec<String> {
+    let mut errors = Vec::new();
+
+    assert_eq!(Some(1), sc.next_u8().unwrap());
+    errors.push(sc.next_u8().unwrap_err().to_string());
+    errors.push(sc.next_i8().unwrap_err().to_string());
+    errors.push(sc.next_f64().unwrap_err().to_string());
+    errors.push(sc.next_parse::<bool>().unwrap_err().to_string());
+    errors.push(sc.next_u8_until(",").unwrap_err().to_string());
+    errors.push(sc.next_f32_until(",").unwrap_err().to_string());
+
+    errors
+}
+
+#[test]
+fn parse_error_context() {
+    let data = "1\n\n  256 -129\t1.2.3\nmaybe ,x,";
+
+    let expected = vec![
+        r#"number too large in "256" at 3:3"#.to_string(),
+        r#"number too small in "-129" at 3:7"#.to_string(),
+        r#"invalid float literal in "1.2.3" at 3:12"#.to_string(),
+        r#"provided string was not `true` or `false` in "maybe" at 4:1"#.to_string(),
+        r#"invalid digit in " " at 4:6"#.to_string(),
+        r#"invalid float literal in "x" at 4:8"#.to_string(),
+    ];
+
+    assert_eq!(expected, parse_errors(&mut Scanner::new(data.as_bytes())));
+    assert_eq!(expected, parse_errors(&mut ScannerAscii::new(data.as_bytes())));
+    assert_eq!(expected, parse_errors(&mut ScannerStr::new(data)));
+    assert_eq!(expected, parse_errors(&mut ScannerU8Slice::new(data)));
+    assert_eq!(expected, parse_errors(&mut ScannerU8SliceAscii::new(data)));
+
+    let mut sc = ScannerStr::new("\n12x");
+
+    match sc.next_i32().unwrap_err() {
+        ScannerError::ParseIntError {
+            source,
+            token,
+            position,
+        } => {
+            assert_eq!(&IntErrorKind::InvalidDigit, source.kind());
+            assert_eq!("12x", token);
+            assert_eq!(
+                Position {
+                    offset: 1, line: 2, column: 1
+                },
+                position
+            );
+        },
+        err => panic!("{err:?}"),
+    }
+}
+
+fn invalid_utf8<T: std::fmt::Debug>(result: Result<T, ScannerError>) -> (usize, Vec<u8>) {
+    match result {
+        Err(ScannerError::InvalidUtf8 {
+            offset,
+            bytes,
+        }) => (offset, bytes),
+        other => panic!("{other:?}"),
+    }
+}
+
+#[test]
+fn strict_utf8() {
+    let data = b"ab\xFFc\n\xE4\xB8\xAD\xE4\xB8 x,1\xC0,\xFF2,";
+
+    let mut sc: Scanner<_, U8> = Scanner::new2(data.as_ref());
+
+    assert_eq!(Some("ab\u{FFFD}c".into()), sc.next_line().unwrap());
+
+    sc.set_strict_utf8(true);
+
+    assert_eq!((8, vec![0xE4, 0xB8]), invalid_utf8(sc.next()));
+    assert_eq!(Some(2), sc.drop_next_bytes(2).unwrap());
+    assert_eq!(Some(" x".into()), sc.next_until(",").unwrap());
+    assert_eq!((14, vec![0xC0]), invalid_utf8(sc.next_until(",")));
+    assert_eq!(Some(2), sc.drop_next_bytes(2).unwrap());
+    assert_eq!((16, vec![0xFF]), invalid_utf8(sc.next_u8_until(",")));
+
+    let mut sc: Scanner<_, U8> = Scanner::new2(data.as_ref());
+
+    sc.set_strict_utf8(true);
+
+    let err = sc.next_line().unwrap_err();
+
+    assert_eq!("invalid UTF-8 sequence 0xFF at byte 2", err.to_string());
+    assert_eq!((2, vec![0xFF]), invalid_utf8(sc.next_char()));
+    assert_eq!(Some(1), sc.drop_next_bytes(1).unwrap());
+    assert_eq!(Some("c".into()), sc.next_line().unwrap());
+
+    let mut sc = ScannerU8Slice::new(data);
+
+    sc.set_strict_utf8(true);
+
+    assert_eq!(Some('a'), sc.next_char().unwrap());
+    assert_eq!(Some('b'), sc.next_char().unwrap());
+    assert_eq!((2, vec![0xFF]), invalid_utf8(sc.next_char()));
+    assert_eq!(Some(b"\xFFc".as_ref()), sc.next_line().unwrap());
+    assert_eq!(Some('中'), sc.next_char().unwrap());
+    assert_eq!((8, vec![0xE4, 0xB8]), invalid_utf8(sc.next_char()));
+    assert_eq!(Some(b"\xE4\xB8 x".as_ref()), sc.next_until(",").unwrap());
+    assert_eq!((14, vec![0xC0]), invalid_utf8(sc.next_u8_until(",")));
+    assert_eq!((16, vec![0xFF]), invalid_utf8(sc.next_parse::<u8>()));
+}
+
+fn read_integers<S: Scan>(sc: &mut S) -> Vec<String> {
+    fn show<T: std::fmt::Debug>(result: Result<Option<T>, ScannerError>) -> String {
+        match result {
+            Ok(value) => format!("{value:?}"),
+            Err(err) => err.to_string(),
+        }
+    }
+
+    vec![
+        show(sc.next_u8()),
+        show(sc.next_u8()),
+        show(sc.next_u8()),
+        show(sc.next_u8()),
+        show(sc.next_u8()),
+        show(sc.next_i8()),
+        show(sc.next_i8()),
+        show(sc.next_u128()),
+        show(sc.next_u64()),
+        show(sc.next_i64()),
+        show(sc.next_isize()),
+        show(sc.next_u32()),
+    ]
+}
+
+#[test]
+fn integers_from_buffer() {
+    let data = "+12 -0 007\n255 256  -128 -129\t123456789012345678901234567890 \
+                18446744073709551615 -9223372036854775808 1x";
+
+    let expected = read_integers(&mut ScannerU8Slice::new(data));
+
+    assert_eq!("Some(12)", expected[0]);
+    assert_eq!("Some(0)", expected[1]);
+    assert_eq!("Some(7)", expected[2]);
+    assert_eq!(r#"number too large in "256" at 2:5"#, expected[4]);
+    assert_eq!(r#"number too small in "-129" at 2:15"#, expected[6]);
+    assert_eq!("Some(123456789012345678901234567890)", expected[7]);
+    assert_eq!("Some(18446744073709551615)", expected[8]);
+    assert_eq!("Some(-9223372036854775808)", expected[9]);
+    assert_eq!(r#"invalid digit in "1x" at 2:93"#, expected[10]);
+    assert_eq!("None", expected[11]);
+
+    assert_eq!(expected, read_integers(&mut Scanner::new(data.as_bytes())));
+    assert_eq!(expected, read_integers(&mut Scanner::<_, U4>::new2(data.as_bytes())));
+    assert_eq!(expected, read_integers(&mut Scanner::<_, U16>::new2(data.as_bytes())));
+    assert_eq!(expected, read_integers(&mut ScannerAscii::new(data.as_bytes())));
+    assert_eq!(expected, read_integers(&mut ScannerAscii::<_, U4>::new2(data.as_bytes())));
+    assert_eq!(expected, read_integers(&mut ScannerAscii::<_, U16>::new2(data.as_bytes())));
+
+    // a sign can also be a delimiter
+    let mut sc = Scanner::new("-1--2-".as_bytes());
+
+    sc.set_delimiter('-');
+
+    assert_eq!(Some(1), sc.next_i32().unwrap());
+    assert_eq!(Some(2), sc.next_i32().unwrap());
+    assert_eq!(None, sc.next_i32().unwrap());
+}
+
+/// A xorshift generator, which is enough to make arbitrary bytes for the tests below.
+struct XorShift(u64);
+
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+
+        self.0
+    }
+
+    /// Mostly numeric characters and separators, mixed with arbitrary bytes.
+    fn bytes(&mut self, length: usize) -> Vec<u8> {
+        const ALPHABET: &[u8] = b"0123456789-+.eE ,\n";
+
+        (0..length)
+            .map(|_| {
+                let r = self.next();
+
+                if r % 3 == 0 {
+                    (r >> 8) as u8
+                } else {
+                    ALPHABET[(r >> 8) as usize % ALPHABET.len()]
+                }
+            })
+            .collect()
+    }
+}
+
+fn parse_random<S: Scan>(sc: &mut S, mode: u8) -> Vec<Option<u64>> {
+    let mut values = Vec::new();
+
+    loop {
+        let result = match mode {
+            0 => sc.next_i32().map(|v| v.map(|v| v as u64)),
+            1 => sc.next_f64().map(|v| v.map(f64::to_bits)),
+            2 => sc.next_i32_until(",").map(|v| v.map(|v| v as u64)),
+            _ => sc.next_f64_until(",").map(|v| v.map(f64::to_bits)),
+        };
+
+        match result {
+            Ok(Some(v)) => values.push(Some(v)),
+            Ok(None) => break,
+            Err(err) => {
+                assert!(!err.to_string().is_empty());
+
+                values.push(None);
+            },
+        }
+    }
+
+    values
+}
+
+#[test]
+fn random_bytes() {
+    let mut rng = XorShift(0x2545_F491_4F6C_DD1D);
+    let mut parsed = [0; 4];
+
+    for _ in 0..64 {
+        let data = rng.bytes(256);
+
+        for mode in 0..4 {
+            let expected = parse_random(&mut ScannerU8Slice::new(&data), mode);
+
+            parsed[mode as usize] += expected.iter().flatten().count();
+
+            assert_eq!(expected, parse_random(&mut Scanner::new(data.as_slice()), mode));
+            assert_eq!(expected, parse_random(&mut Scanner::<_, U16>::new2(data.as_slice()), mode));
+            assert_eq!(
+                expected,
+                parse_random(&mut Scanner::with_capacity(data.as_slice(), 5), mode)
+            );
+
+            let expected = parse_random(&mut ScannerU8SliceAscii::new(&data), mode);
+
+            assert_eq!(expected, parse_random(&mut ScannerAscii::new(data.as_slice()), mode));
+            assert_eq!(
+                expected,
+                parse_random(&mut ScannerAscii::<_, U16>::new2(data.as_slice()), mode)
+            );
+            assert_eq!(
+                expected,
+                parse_random(&mut ScannerAscii::with_capacity(data.as_slice(), 5), mode)
+            );
+        }
+    }
+
+    assert!(parsed.iter().all(|&n| n > 0), "{parsed:?}");
+}
+
+#[test]
+fn heap_buffers() {
+    let data = "12 3.5 x123 456\r\n789 abcdefghijklmnopqrstuvwxyz\n\n中文 -0 18446744073709551615";
+
+    for capacity in [0, 4, 5, 16, 65536] {
+        assert_eq!(
+            read_all(&mut Scanner::<_, U4>::new2(data.as_bytes())),
+            read_all(&mut Scanner::with_capacity(data.as_bytes(), capacity))
+        );
+        assert_eq!(
+            read_all(&mut ScannerAscii::<_, U4>::new2(data.as_bytes())),
+            read_all(&mut ScannerAscii::with_capacity(data.as_bytes(), capacity))
+        );
+        assert_eq!(
+            read_lines(&mut ScannerStr::new(data)),
+            read_lines(&mut Scanner::with_capacity(data.as_bytes(), capacity))
+        );
+        assert_eq!(
+            read_lines(&mut ScannerAscii::<_, U4>::new2(data.as_bytes())),
+            read_lines(&mut ScannerAscii::with_capacity(data.as_bytes(), capacity))
+        );
+        assert_eq!(
+            read_integers(&mut ScannerU8Slice::new(data)),
+            read_integers(&mut Scanner::with_capacity(data.as_bytes(), capacity))
+        );
+        assert_eq!(
+            read_integers(&mut ScannerU8SliceAscii::new(data)),
+            read_integers(&mut ScannerAscii::with_capacity(data.as_bytes(), capacity))
+        );
+    }
+}
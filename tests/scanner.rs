@@ -1,4 +1,12 @@
-use scanner_rust::Scanner;
+use std::io::Read;
+
+use scanner_rust::{
+    generic_array::{
+        typenum::{IsGreaterOrEqual, True, U16, U256, U4, U5, U8},
+        ArrayLength,
+    },
+    BoolVocabulary, IntegerOverflow, NumberFormat, Position, Scanner, ScannerErrorKind, ScannerStr,
+};
 
 #[test]
 fn read_chars() {
@@ -365,3 +373,598 @@ fn next_f64() {
     assert_eq!(Some(2.0), sc.next_f64().unwrap());
     assert_eq!(Some(-123456.987654), sc.next_f64().unwrap());
 }
+
+fn read_refs<N: ArrayLength + IsGreaterOrEqual<U4, Output = True>>(
+    data: &[u8],
+    by_ref: bool,
+) -> Vec<Option<Vec<u8>>> {
+    let mut sc: Scanner<_, N> = Scanner::new2(data);
+
+    let mut result = Vec::new();
+
+    for i in 0.. {
+        let (item, raw_item) = if by_ref {
+            if i % 2 == 0 {
+                (
+                    sc.next_ref().unwrap().map(|s| s.as_bytes().to_vec()),
+                    sc.next_raw_ref().unwrap().map(|s| s.to_vec()),
+                )
+            } else {
+                (
+                    sc.next_line_ref().unwrap().map(|s| s.as_bytes().to_vec()),
+                    sc.next_line_raw_ref().unwrap().map(|s| s.to_vec()),
+                )
+            }
+        } else if i % 2 == 0 {
+            (sc.next().unwrap().map(String::into_bytes), sc.next_raw().unwrap())
+        } else {
+            (sc.next_line().unwrap().map(String::into_bytes), sc.next_line_raw().unwrap())
+        };
+
+        let end = item.is_none() && raw_item.is_none();
+
+        result.push(item);
+        result.push(raw_item);
+
+        if end {
+            break;
+        }
+    }
+
+    assert_eq!(data.len(), sc.position().offset);
+
+    result
+}
+
+#[test]
+fn next_refs() {
+    let data = "123 456\r\n789 \n\n 中文好難。寝る\n\r\rabcdefghijklmnopqrstuvwxyz 0123456789\n  \
+                x\r\n\n\r\r";
+
+    let mut data = data.as_bytes().to_vec();
+
+    for _ in 0..2 {
+        assert_eq!(read_refs::<U4>(&data, false), read_refs::<U4>(&data, true));
+        assert_eq!(read_refs::<U5>(&data, false), read_refs::<U5>(&data, true));
+        assert_eq!(read_refs::<U8>(&data, false), read_refs::<U8>(&data, true));
+        assert_eq!(read_refs::<U16>(&data, false), read_refs::<U16>(&data, true));
+        assert_eq!(read_refs::<U256>(&data, false), read_refs::<U256>(&data, true));
+
+        // invalid UTF-8
+        data.extend_from_slice(b" \xE4\xB8 \xFF\n\xFF\xFF \xC0x");
+    }
+
+    let mut sc = Scanner::new("中文 字".as_bytes());
+
+    assert_eq!(Some("中文"), sc.next_ref().unwrap());
+    assert_eq!(Some("字".as_bytes()), sc.next_raw_ref().unwrap());
+    assert_eq!(None, sc.next_ref().unwrap());
+}
+
+#[test]
+fn next_into() {
+    let data = "123 456\r\n789 \n\n 中文 \n\r\rabc 0123456789\n  x\r\n";
+
+    let mut sc: Scanner<_, U8> = Scanner::new2(data.as_bytes());
+    let mut expected: Scanner<_, U8> = Scanner::new2(data.as_bytes());
+
+    let mut s = String::new();
+    let mut v = Vec::new();
+
+    let capacity = {
+        s.reserve(64);
+        v.reserve(64);
+
+        (s.capacity(), v.capacity())
+    };
+
+    for i in 0.. {
+        let (a, b) = match i % 6 {
+            0 => (sc.next_into(&mut s).unwrap(), expected.next().unwrap().map(String::into_bytes)),
+            1 => (sc.next_raw_into(&mut v).unwrap(), expected.next_raw().unwrap()),
+            2 => (
+                sc.next_line_into(&mut s).unwrap(),
+                expected.next_line().unwrap().map(String::into_bytes),
+            ),
+            3 => (sc.next_line_raw_into(&mut v).unwrap(), expected.next_line_raw().unwrap()),
+            4 => (
+                sc.next_until_into("5", &mut s).unwrap(),
+                expected.next_until("5").unwrap().map(String::into_bytes),
+            ),
+            _ => (sc.next_bytes_into(5, &mut v).unwrap(), expected.next_bytes(5).unwrap()),
+        };
+
+        let output = if matches!(i % 6, 0 | 2 | 4) { s.as_bytes() } else { v.as_slice() };
+
+        match b {
+            Some(b) => {
+                assert_eq!(Some(b.len()), a);
+                assert_eq!(b, output);
+            },
+            None => {
+                assert_eq!(None, a);
+                assert!(output.is_empty());
+
+                break;
+            },
+        }
+    }
+
+    assert_eq!(capacity, (s.capacity(), v.capacity()));
+}
+
+fn mark_and_reset<N: ArrayLength + IsGreaterOrEqual<U4, Output = True>>() {
+    let data = "12 中文 34\r\n56 abc\r\n\r\nxyz";
+
+    let mut sc: Scanner<_, N> = Scanner::new2(data.as_bytes());
+
+    assert_eq!(Some(12), sc.next_u8().unwrap());
+
+    sc.mark(64);
+
+    let position = sc.position();
+
+    assert!(sc.next_u8().is_err());
+    assert_eq!(Some(" 34".into()), sc.next_line().unwrap());
+    assert!(sc.reset());
+    assert_eq!(position, sc.position());
+    assert_eq!(Some("中文".into()), sc.next().unwrap());
+    assert!(sc.reset());
+    assert_eq!(Some(" 中文 34".into()), sc.next_line().unwrap());
+
+    sc.mark(64);
+
+    assert_eq!(Some("56 abc".into()), sc.next_line().unwrap());
+    assert!(sc.reset());
+    assert_eq!(Some("56 abc".into()), sc.next_line().unwrap());
+    assert_eq!(Some("".into()), sc.next_line().unwrap());
+    assert_eq!(Some("xyz".into()), sc.next_line().unwrap());
+    assert_eq!(None, sc.next_line().unwrap());
+    assert!(sc.reset());
+    assert_eq!(Some("56".into()), sc.next().unwrap());
+
+    sc.mark(2);
+
+    assert_eq!(Some("abc".into()), sc.next().unwrap());
+    assert!(!sc.reset());
+    assert_eq!(Some("".into()), sc.next_line().unwrap());
+    assert_eq!(Some("".into()), sc.next_line().unwrap());
+    assert_eq!(Some("xyz".into()), sc.next_line().unwrap());
+}
+
+#[test]
+fn mark_reset() {
+    mark_and_reset::<U4>();
+    mark_and_reset::<U8>();
+    mark_and_reset::<U256>();
+}
+
+#[test]
+fn unread() {
+    // `\r` and `\n` are read separately
+    let data = "a\r".as_bytes().chain("\n[b]\r\nc\n".as_bytes());
+
+    let mut sc = Scanner::new(data);
+
+    assert_eq!(Some("a".into()), sc.next_line().unwrap());
+
+    sc.unread_line("x");
+
+    assert_eq!(Some("x".into()), sc.next_line().unwrap());
+    assert_eq!(Some("[b]".into()), sc.next_line().unwrap());
+
+    sc.unread_line("[b]");
+    sc.unread_char('中');
+
+    assert_eq!(Some("中[b]".into()), sc.next_line().unwrap());
+    assert_eq!(Some("c".into()), sc.next().unwrap());
+
+    sc.unread(b"d e");
+
+    assert_eq!(Some("d".into()), sc.next().unwrap());
+
+    sc.mark(16);
+
+    let token = sc.next().unwrap().unwrap();
+
+    sc.unread(&token);
+
+    assert!(sc.reset());
+    assert_eq!(Some("e".into()), sc.next().unwrap());
+    assert_eq!(None, sc.next().unwrap());
+
+    sc.unread("f");
+
+    assert_eq!(Some('f'), sc.next_char().unwrap());
+    assert_eq!(None, sc.next_char().unwrap());
+}
+
+fn unread_and_track<N: ArrayLength + IsGreaterOrEqual<U4, Output = True>>() {
+    let mut sc: Scanner<_, N> = Scanner::new2("abc def\r\n\r12\nx 34\n\nlast".as_bytes());
+
+    assert_eq!(Some("abc".into()), sc.next().unwrap());
+
+    sc.unread("abc");
+
+    assert_eq!(0, sc.position().offset);
+    assert_eq!(Some("abc".into()), sc.next().unwrap());
+    assert_eq!(3, sc.position().offset);
+
+    let line = sc.next_line().unwrap().unwrap();
+
+    assert_eq!(" def", line);
+
+    sc.unread_line(&line);
+
+    assert_eq!(3, sc.position().offset);
+
+    // the original `\r\n` is put back, so the `\r` after it is still an empty line
+    assert_eq!(Some(" def".into()), sc.next_line().unwrap());
+    assert_eq!(Some("".into()), sc.next_line().unwrap());
+    assert_eq!(Some("12".into()), sc.next_line().unwrap());
+
+    let position = sc.position();
+    let line = sc.next_line().unwrap().unwrap();
+
+    sc.unread_line(line);
+
+    assert_eq!(position, sc.position());
+    assert_eq!("invalid digit in \"x\" at 4:1", sc.next_u8().unwrap_err().to_string());
+    assert_eq!(Some(34), sc.next_u8().unwrap());
+
+    // unrelated data does not move the position
+    sc.unread("56 ");
+
+    assert_eq!(position.offset + 4, sc.position().offset);
+    assert_eq!(Some(56), sc.next_u8().unwrap());
+    assert_eq!(Some(" ".into()), sc.next_line().unwrap());
+    assert_eq!(Some("".into()), sc.next_line().unwrap());
+
+    sc.unread_line("made up");
+
+    assert_eq!(Some("made up".into()), sc.next_line().unwrap());
+    assert_eq!(Some("last".into()), sc.next_line().unwrap());
+    assert_eq!(None, sc.next_line().unwrap());
+}
+
+#[test]
+fn unread_positions() {
+    unread_and_track::<U4>();
+    unread_and_track::<U8>();
+    unread_and_track::<U256>();
+
+    let mut sc = Scanner::new("a\r\n\rb".as_bytes());
+
+    assert_eq!(Some("a".into()), sc.next_line().unwrap());
+
+    sc.unread_line("a");
+
+    assert_eq!(Some("a".into()), sc.next_line().unwrap());
+    assert_eq!(Some("".into()), sc.next_line().unwrap());
+    assert_eq!(Some("b".into()), sc.next_line().unwrap());
+    assert_eq!(
+        Position {
+            offset: 5, line: 3, column: 2
+        },
+        sc.position()
+    );
+}
+
+#[test]
+fn unread_last_line() {
+    let mut sc = Scanner::new("a\nlast".as_bytes());
+
+    assert_eq!(Some("a".into()), sc.next_line().unwrap());
+    assert_eq!(Some("last".into()), sc.next_line().unwrap());
+
+    sc.unread_line("last");
+
+    assert_eq!(
+        Position {
+            offset: 2, line: 2, column: 1
+        },
+        sc.position()
+    );
+    assert_eq!(Some("last".into()), sc.next_until("x").unwrap());
+    assert_eq!(
+        Position {
+            offset: 6, line: 2, column: 5
+        },
+        sc.position()
+    );
+    assert_eq!(None, sc.next_line().unwrap());
+}
+
+fn peek_before_every_read<N: ArrayLength + IsGreaterOrEqual<U4, Output = True>>(
+    data: &str,
+) -> Vec<String> {
+    let mut sc: Scanner<_, N> = Scanner::new2(data.as_bytes());
+    let mut results = Vec::new();
+
+    loop {
+        let position = sc.position();
+        let token = sc.peek_token().unwrap();
+
+        assert_eq!(position, sc.position());
+        assert_eq!(token, sc.next().unwrap());
+
+        let line = sc.peek_line().unwrap();
+
+        assert_eq!(line, sc.next_line().unwrap());
+
+        match (token, line) {
+            (Some(token), Some(line)) => {
+                results.push(token);
+                results.push(line);
+            },
+            _ => break,
+        }
+    }
+
+    results
+}
+
+#[test]
+fn peek_in_buffer() {
+    let data = (0..300)
+        .map(|i| format!("{i}{}", if i % 7 == 0 { "\r\n" } else { " " }))
+        .collect::<String>();
+
+    let mut expected = Vec::new();
+    let mut sc = ScannerStr::new(&data);
+
+    while let (Some(token), Some(line)) = (sc.next().unwrap(), sc.next_line().unwrap()) {
+        expected.push(token.to_string());
+        expected.push(line.to_string());
+    }
+
+    assert_eq!(expected, peek_before_every_read::<U4>(&data));
+    assert_eq!(expected, peek_before_every_read::<U5>(&data));
+    assert_eq!(expected, peek_before_every_read::<U16>(&data));
+    assert_eq!(expected, peek_before_every_read::<U256>(&data));
+}
+
+#[test]
+fn peek_after_split_line_break() {
+    let mut sc = Scanner::new("abc\r".as_bytes().chain("\ndef\n".as_bytes()));
+
+    assert_eq!(Some("abc".into()), sc.next_line().unwrap());
+    assert_eq!(Some('d'), sc.peek_char().unwrap());
+    assert_eq!(Some("def".into()), sc.next_line().unwrap());
+
+    let mut sc = Scanner::new("abc\r".as_bytes().chain("\ndef\n".as_bytes()));
+
+    assert_eq!(Some("abc".into()), sc.next_line().unwrap());
+    assert_eq!(Some("def".into()), sc.peek_token().unwrap());
+    assert_eq!(Some("def".into()), sc.peek_line().unwrap());
+    assert_eq!(Some("def".into()), sc.next_line().unwrap());
+    assert_eq!(None, sc.next_line().unwrap());
+}
+
+#[test]
+fn has_next_after_split_line_break() {
+    let mut sc = Scanner::new("abc\r".as_bytes().chain("\n12\n".as_bytes()));
+
+    assert_eq!(Some("abc".into()), sc.next_line().unwrap());
+    assert!(sc.has_next().unwrap());
+    assert!(sc.has_next_line().unwrap());
+    assert!(sc.has_next_u32().unwrap());
+    assert!(sc.has_next_parse::<u8>().unwrap());
+    assert_eq!(Some("12".into()), sc.next_line().unwrap());
+    assert!(!sc.has_next_line().unwrap());
+}
+
+#[test]
+fn positions_of_split_data() {
+    let data = "ab\r\ncd\r\n\ref\n\n g\rh\r";
+
+    for i in 0..=data.len() {
+        let mut sc = Scanner::new(data.as_bytes()[..i].chain(&data.as_bytes()[i..]));
+        let mut ss = ScannerStr::new(data);
+
+        assert_eq!(Some("ab".into()), sc.next().unwrap());
+        assert_eq!(Some("ab"), ss.next().unwrap());
+
+        assert_eq!(Some('\r'), sc.next_char().unwrap());
+        assert_eq!(Some('\r'), ss.next_char().unwrap());
+        assert_eq!(ss.position(), sc.position(), "split at {}", i);
+
+        assert_eq!(Some("".into()), sc.next_line().unwrap());
+        assert_eq!(Some(""), ss.next_line().unwrap());
+        assert_eq!(ss.position(), sc.position(), "split at {}", i);
+
+        sc.mark(64);
+        let checkpoint = ss.checkpoint();
+
+        assert_eq!(Some("cd".into()), sc.next_line().unwrap());
+        assert_eq!(Some("cd"), ss.next_line().unwrap());
+        assert_eq!(ss.position(), sc.position(), "split at {}", i);
+        assert_eq!(Some("ef".into()), sc.peek_token().unwrap());
+        assert_eq!(Some("ef"), ss.peek_token().unwrap());
+        assert_eq!(ss.position(), sc.position(), "split at {}", i);
+        assert_eq!(Some("".into()), sc.next_line().unwrap());
+        assert_eq!(Some(""), ss.next_line().unwrap());
+        assert_eq!(ss.position(), sc.position(), "split at {}", i);
+
+        assert!(sc.reset());
+        ss.restore(checkpoint);
+
+        assert_eq!(ss.position(), sc.position(), "split at {}", i);
+
+        while let Some(line) = ss.next_line().unwrap() {
+            assert_eq!(Some(line.into()), sc.next_line().unwrap());
+            assert_eq!(ss.position(), sc.position(), "split at {}", i);
+        }
+
+        assert_eq!(None, sc.next_line().unwrap());
+    }
+}
+
+#[test]
+fn next_radix() {
+    let data = "DeadBeef -0x1f +0O17 0b1010 42 0o17 0b12 0b1 0xff,-80, -0x1 0x ff";
+
+    let mut sc = Scanner::<_, U4>::new2(data.as_bytes());
+
+    assert_eq!(
+        "invalid radix 1, which should be 0 or from 2 to 36",
+        sc.next_i64_radix(1).unwrap_err().to_string()
+    );
+    assert_eq!(
+        ScannerErrorKind::InvalidRadix,
+        sc.next_i64_radix_until(" ", 37).unwrap_err().kind()
+    );
+    assert_eq!(
+        "number too large in \"DeadBeef\" at 1:1",
+        sc.next_i32_radix(16).unwrap_err().to_string()
+    );
+    assert_eq!(Some(-31), sc.next_i32_radix(0).unwrap());
+    assert_eq!(Some(15), sc.next_i32_radix(0).unwrap());
+    assert_eq!(Some(10), sc.next_i32_radix(0).unwrap());
+    assert_eq!(Some(42), sc.next_i32_radix(0).unwrap());
+    assert_eq!(Some(15), sc.next_i32_radix(8).unwrap());
+    assert_eq!("invalid digit in \"0b12\" at 1:37", sc.next_i32_radix(2).unwrap_err().to_string());
+    assert_eq!(Some(177), sc.next_i32_radix(16).unwrap());
+    assert!(sc.skip_whitespaces().unwrap());
+    assert_eq!(Some(255), sc.next_u8_radix_until(",", 0).unwrap());
+    assert_eq!(Some(-128), sc.next_i8_radix_until(",", 16).unwrap());
+    assert_eq!(
+        "number too small in \"-0x1\" at 1:56",
+        sc.next_u16_radix(0).unwrap_err().to_string()
+    );
+    assert_eq!("invalid digit in \"0x\" at 1:61", sc.next_u16_radix(16).unwrap_err().to_string());
+    assert_eq!(Some(255), sc.next_u64_radix(16).unwrap());
+}
+
+#[test]
+fn number_format() {
+    let data = "1_000_000 1,234,567 +42 12,34 1__0 3.5 0xdead_beef 1,234.5 1.234,5;1.000; 1.5 2.5";
+
+    let mut sc = Scanner::<_, U4>::new2(data.as_bytes());
+
+    sc.set_number_format(NumberFormat {
+        digit_separators: vec!['_'],
+        grouping: Some(','),
+        ..NumberFormat::default()
+    });
+
+    assert_eq!(Some("1_000_000".into()), sc.peek_token().unwrap());
+    assert!(sc.has_next_u32().unwrap());
+    assert_eq!(Some(1000000), sc.next_u32().unwrap());
+    assert_eq!(Some(1234567), sc.next_i64().unwrap());
+    assert_eq!(Some(42), sc.next_i8().unwrap());
+    assert_eq!("invalid digit in \"12,34\" at 1:25", sc.next_u32().unwrap_err().to_string());
+    assert_eq!("invalid digit in \"1__0\" at 1:31", sc.next_u32().unwrap_err().to_string());
+    assert_eq!(Some(3.5), sc.next_f64().unwrap());
+    assert_eq!(Some(3735928559), sc.next_u32_radix(0).unwrap());
+    assert_eq!(Some(1234.5), sc.next_f32().unwrap());
+
+    sc.set_number_format(NumberFormat {
+        grouping: Some('.'),
+        decimal_point: ',',
+        ..NumberFormat::default()
+    });
+
+    assert!(sc.skip_whitespaces().unwrap());
+    assert_eq!(Some(1234.5), sc.next_f64_until(";").unwrap());
+    assert_eq!(Some(1000), sc.next_u32_until(";").unwrap());
+    assert_eq!("invalid float literal in \"1.5\" at 1:75", sc.next_f64().unwrap_err().to_string());
+    assert_eq!(Some(2.5), sc.next_parse::<f64>().unwrap());
+}
+
+#[test]
+fn number_format_conflicts() {
+    let data = "1.234 1.234 1,234 12,34";
+
+    let mut sc = Scanner::<_, U4>::new2(data.as_bytes());
+
+    sc.set_number_format(NumberFormat {
+        digit_separators: vec!['.'],
+        grouping: Some('.'),
+        ..NumberFormat::default()
+    });
+
+    assert_eq!(Some(1.234), sc.next_f64().unwrap());
+    assert_eq!("invalid digit in \"1.234\" at 1:7", sc.next_u32().unwrap_err().to_string());
+
+    sc.set_number_format(NumberFormat {
+        digit_separators: vec![','],
+        grouping: Some(','),
+        ..NumberFormat::default()
+    });
+
+    assert_eq!(Some(1234), sc.next_u32().unwrap());
+    assert_eq!("invalid digit in \"12,34\" at 1:19", sc.next_u32().unwrap_err().to_string());
+}
+
+#[test]
+fn next_bool() {
+    let data = "true True YES n 1 maybe SÍ,yes,";
+
+    let mut sc = Scanner::<_, U4>::new2(data.as_bytes());
+
+    assert_eq!(Some(true), sc.next_bool().unwrap());
+    assert_eq!("invalid boolean in \"True\" at 1:6", sc.next_bool().unwrap_err().to_string());
+
+    sc.set_bool_vocabulary(BoolVocabulary::lenient());
+
+    assert_eq!(Some(true), sc.next_bool().unwrap());
+    assert_eq!(Some(false), sc.next_bool().unwrap());
+    assert_eq!(Some(true), sc.next_bool().unwrap());
+    assert_eq!("invalid boolean in \"maybe\" at 1:19", sc.next_bool().unwrap_err().to_string());
+
+    sc.set_bool_vocabulary(BoolVocabulary {
+        true_words:       vec!["sí".into()],
+        false_words:      vec!["no".into()],
+        case_insensitive: true,
+    });
+
+    assert!(sc.skip_whitespaces().unwrap());
+    assert_eq!(Some(true), sc.next_bool_until(",").unwrap());
+    assert_eq!(
+        "invalid boolean in \"yes\" at 1:28",
+        sc.next_bool_until(",").unwrap_err().to_string()
+    );
+    assert_eq!(None, sc.next_bool().unwrap());
+}
+
+#[test]
+fn integer_overflow() {
+    let data =
+        "300 -300 -300 3x0 1.2.3 ,maybe 300 -300 -5 0x1ffff 300x 257,-81,9223372036854775808 -1 -0";
+
+    let mut sc = Scanner::<_, U4>::new2(data.as_bytes());
+
+    assert_eq!(ScannerErrorKind::Overflow, sc.next_u8().unwrap_err().kind());
+    assert_eq!(ScannerErrorKind::Underflow, sc.next_i8().unwrap_err().kind());
+    assert_eq!(ScannerErrorKind::Underflow, sc.next_u8().unwrap_err().kind());
+    assert_eq!(ScannerErrorKind::InvalidDigit, sc.next_u8().unwrap_err().kind());
+    assert_eq!(ScannerErrorKind::InvalidFloat, sc.next_f32().unwrap_err().kind());
+    assert!(sc.skip_whitespaces().unwrap());
+    assert_eq!(ScannerErrorKind::Empty, sc.next_u8_until(",").unwrap_err().kind());
+    assert_eq!(ScannerErrorKind::InvalidBool, sc.next_bool().unwrap_err().kind());
+
+    sc.set_number_format(NumberFormat {
+        integer_overflow: IntegerOverflow::Saturating,
+        ..NumberFormat::default()
+    });
+
+    assert!(sc.has_next_u8().unwrap());
+    assert_eq!(Some(255), sc.next_u8().unwrap());
+    assert_eq!(Some(-128), sc.next_i8().unwrap());
+    assert_eq!(Some(0), sc.next_u8().unwrap());
+    assert_eq!(Some(65535), sc.next_u16_radix(0).unwrap());
+    assert_eq!("invalid digit in \"300x\" at 1:52", sc.next_u8().unwrap_err().to_string());
+
+    sc.set_number_format(NumberFormat {
+        integer_overflow: IntegerOverflow::Wrapping,
+        ..NumberFormat::default()
+    });
+
+    assert!(sc.skip_whitespaces().unwrap());
+    assert_eq!(Some(1), sc.next_u8_until(",").unwrap());
+    assert_eq!(Some(127), sc.next_i8_radix_until(",", 16).unwrap());
+    assert_eq!(Some(-9223372036854775808), sc.next_i64().unwrap());
+    assert_eq!(Some(255), sc.next_u8().unwrap());
+    assert_eq!(Some(0), sc.next_u8().unwrap());
+}
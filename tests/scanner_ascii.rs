@@ -1,4 +1,9 @@
-use scanner_rust::ScannerAscii;
+use std::io::Read;
+
+use scanner_rust::{
+    generic_array::typenum::U8, BoolVocabulary, IntegerOverflow, NumberFormat, Position,
+    ScannerAscii, ScannerErrorKind, ScannerStr,
+};
 
 #[test]
 fn read_chars() {
@@ -345,3 +350,401 @@ fn next_f64() {
     assert_eq!(Some(2.0), sc.next_f64().unwrap());
     assert_eq!(Some(-123456.987654), sc.next_f64().unwrap());
 }
+
+#[test]
+fn next_into() {
+    let data = "123 456\r\n789 \n\n 中文 \n\r\rabc 0123456789\n  x\r\n";
+
+    let mut sc: ScannerAscii<_, U8> = ScannerAscii::new2(data.as_bytes());
+    let mut expected: ScannerAscii<_, U8> = ScannerAscii::new2(data.as_bytes());
+
+    let mut s = String::new();
+    let mut v = Vec::new();
+
+    let capacity = {
+        s.reserve(64);
+        v.reserve(64);
+
+        (s.capacity(), v.capacity())
+    };
+
+    for i in 0.. {
+        let (a, b) = match i % 6 {
+            0 => (sc.next_into(&mut s).unwrap(), expected.next().unwrap().map(String::into_bytes)),
+            1 => (sc.next_raw_into(&mut v).unwrap(), expected.next_raw().unwrap()),
+            2 => (
+                sc.next_line_into(&mut s).unwrap(),
+                expected.next_line().unwrap().map(String::into_bytes),
+            ),
+            3 => (sc.next_line_raw_into(&mut v).unwrap(), expected.next_line_raw().unwrap()),
+            4 => (
+                sc.next_until_into("5", &mut s).unwrap(),
+                expected.next_until("5").unwrap().map(String::into_bytes),
+            ),
+            _ => (sc.next_bytes_into(5, &mut v).unwrap(), expected.next_bytes(5).unwrap()),
+        };
+
+        let output = if matches!(i % 6, 0 | 2 | 4) { s.as_bytes() } else { v.as_slice() };
+
+        match b {
+            Some(b) => {
+                assert_eq!(Some(b.len()), a);
+                assert_eq!(b, output);
+            },
+            None => {
+                assert_eq!(None, a);
+                assert!(output.is_empty());
+
+                break;
+            },
+        }
+    }
+
+    assert_eq!(capacity, (s.capacity(), v.capacity()));
+}
+
+#[test]
+fn mark_reset() {
+    let data = "0123456789 abc,def\nghi";
+
+    let mut sc: ScannerAscii<_, U8> = ScannerAscii::new2(data.as_bytes());
+
+    sc.mark(32);
+
+    assert_eq!(Some(b"0123".to_vec()), sc.next_bytes(4).unwrap());
+    assert_eq!(Some("456789".into()), sc.next().unwrap());
+    assert!(sc.reset());
+    assert_eq!(0, sc.position().offset);
+    assert_eq!(Some(123456789), sc.next_u32().unwrap());
+    assert_eq!(Some(" abc".into()), sc.next_until(",").unwrap());
+
+    sc.mark(3);
+
+    assert_eq!(Some("def".into()), sc.next_line().unwrap());
+    assert!(!sc.reset());
+    assert_eq!(Some("ghi".into()), sc.next_line().unwrap());
+}
+
+#[test]
+fn unread() {
+    let data = "key\n".as_bytes().chain("\r1 2".as_bytes());
+
+    let mut sc = ScannerAscii::new(data);
+
+    assert_eq!(Some("key".into()), sc.next_line().unwrap());
+
+    sc.unread_line("key");
+
+    assert_eq!(Some("key".into()), sc.next_line().unwrap());
+    assert_eq!(Some(1), sc.next_u8().unwrap());
+
+    sc.unread_char('3');
+
+    assert_eq!(Some(3), sc.next_u8().unwrap());
+    assert_eq!(Some(2), sc.next_u8().unwrap());
+    assert_eq!(None, sc.next_u8().unwrap());
+}
+
+#[test]
+fn unread_positions() {
+    // `\r` and `\n` are read separately
+    let data = "a\r".as_bytes().chain("\n\rb 1".as_bytes());
+
+    let mut sc = ScannerAscii::new(data);
+
+    assert_eq!(Some("a".into()), sc.next_line().unwrap());
+
+    sc.unread_line("a");
+
+    assert_eq!(0, sc.position().offset);
+    assert_eq!(Some("a".into()), sc.next_line().unwrap());
+    assert_eq!(Some("".into()), sc.next_line().unwrap());
+    assert_eq!(Some("b".into()), sc.next().unwrap());
+
+    sc.unread_char('b');
+
+    assert_eq!(
+        Position {
+            offset: 4, line: 3, column: 1
+        },
+        sc.position()
+    );
+    assert_eq!("invalid digit in \"b\" at 3:1", sc.next_u8().unwrap_err().to_string());
+    assert_eq!(Some(1), sc.next_u8().unwrap());
+    assert_eq!(
+        Position {
+            offset: 7, line: 3, column: 4
+        },
+        sc.position()
+    );
+}
+
+#[test]
+fn unread_last_line() {
+    let mut sc = ScannerAscii::new("a\nlast".as_bytes());
+
+    assert_eq!(Some("a".into()), sc.next_line().unwrap());
+    assert_eq!(Some("last".into()), sc.next_line().unwrap());
+
+    sc.unread_line("last");
+
+    assert_eq!(
+        Position {
+            offset: 2, line: 2, column: 1
+        },
+        sc.position()
+    );
+    assert_eq!(Some("last".into()), sc.next_until("x").unwrap());
+    assert_eq!(
+        Position {
+            offset: 6, line: 2, column: 5
+        },
+        sc.position()
+    );
+    assert_eq!(None, sc.next_line().unwrap());
+}
+
+#[test]
+fn peek_after_split_line_break() {
+    let mut sc = ScannerAscii::new("abc\r".as_bytes().chain("\ndef\n".as_bytes()));
+
+    assert_eq!(Some("abc".into()), sc.next_line().unwrap());
+    assert_eq!(Some('d'), sc.peek_char().unwrap());
+    assert_eq!(Some("def".into()), sc.next_line().unwrap());
+
+    let mut sc = ScannerAscii::new("abc\r".as_bytes().chain("\ndef\n".as_bytes()));
+
+    assert_eq!(Some("abc".into()), sc.next_line().unwrap());
+    assert_eq!(Some("def".into()), sc.peek_token().unwrap());
+    assert_eq!(Some("def".into()), sc.peek_line().unwrap());
+    assert_eq!(Some("def".into()), sc.next_line().unwrap());
+    assert_eq!(None, sc.next_line().unwrap());
+}
+
+#[test]
+fn has_next_after_split_line_break() {
+    let mut sc = ScannerAscii::new("abc\r".as_bytes().chain("\n12\n".as_bytes()));
+
+    assert_eq!(Some("abc".into()), sc.next_line().unwrap());
+    assert!(sc.has_next().unwrap());
+    assert!(sc.has_next_line().unwrap());
+    assert!(sc.has_next_u32().unwrap());
+    assert!(sc.has_next_parse::<u8>().unwrap());
+    assert_eq!(Some("12".into()), sc.next_line().unwrap());
+    assert!(!sc.has_next_line().unwrap());
+}
+
+#[test]
+fn positions_of_split_data() {
+    let data = "ab\r\ncd\r\n\ref\n\n g\rh\r";
+
+    for i in 0..=data.len() {
+        let mut sc = ScannerAscii::new(data.as_bytes()[..i].chain(&data.as_bytes()[i..]));
+        let mut ss = ScannerStr::new(data);
+
+        assert_eq!(Some("ab".into()), sc.next().unwrap());
+        assert_eq!(Some("ab"), ss.next().unwrap());
+
+        assert_eq!(Some('\r'), sc.next_char().unwrap());
+        assert_eq!(Some('\r'), ss.next_char().unwrap());
+        assert_eq!(ss.position(), sc.position(), "split at {}", i);
+
+        assert_eq!(Some("".into()), sc.next_line().unwrap());
+        assert_eq!(Some(""), ss.next_line().unwrap());
+        assert_eq!(ss.position(), sc.position(), "split at {}", i);
+
+        sc.mark(64);
+        let checkpoint = ss.checkpoint();
+
+        assert_eq!(Some("cd".into()), sc.next_line().unwrap());
+        assert_eq!(Some("cd"), ss.next_line().unwrap());
+        assert_eq!(ss.position(), sc.position(), "split at {}", i);
+        assert_eq!(Some("ef".into()), sc.peek_token().unwrap());
+        assert_eq!(Some("ef"), ss.peek_token().unwrap());
+        assert_eq!(ss.position(), sc.position(), "split at {}", i);
+        assert_eq!(Some("".into()), sc.next_line().unwrap());
+        assert_eq!(Some(""), ss.next_line().unwrap());
+        assert_eq!(ss.position(), sc.position(), "split at {}", i);
+
+        assert!(sc.reset());
+        ss.restore(checkpoint);
+
+        assert_eq!(ss.position(), sc.position(), "split at {}", i);
+
+        while let Some(line) = ss.next_line().unwrap() {
+            assert_eq!(Some(line.into()), sc.next_line().unwrap());
+            assert_eq!(ss.position(), sc.position(), "split at {}", i);
+        }
+
+        assert_eq!(None, sc.next_line().unwrap());
+    }
+}
+
+#[test]
+fn next_radix() {
+    let data = "DeadBeef -0x1f +0O17 0b1010 42 0o17 0b12 0b1 0xff,-80, -0x1 0x ff";
+
+    let mut sc = ScannerAscii::new(data.as_bytes());
+
+    assert_eq!(
+        "invalid radix 1, which should be 0 or from 2 to 36",
+        sc.next_i64_radix(1).unwrap_err().to_string()
+    );
+    assert_eq!(
+        ScannerErrorKind::InvalidRadix,
+        sc.next_i64_radix_until(" ", 37).unwrap_err().kind()
+    );
+    assert_eq!(
+        "number too large in \"DeadBeef\" at 1:1",
+        sc.next_i32_radix(16).unwrap_err().to_string()
+    );
+    assert_eq!(Some(-31), sc.next_i32_radix(0).unwrap());
+    assert_eq!(Some(15), sc.next_i32_radix(0).unwrap());
+    assert_eq!(Some(10), sc.next_i32_radix(0).unwrap());
+    assert_eq!(Some(42), sc.next_i32_radix(0).unwrap());
+    assert_eq!(Some(15), sc.next_i32_radix(8).unwrap());
+    assert_eq!("invalid digit in \"0b12\" at 1:37", sc.next_i32_radix(2).unwrap_err().to_string());
+    assert_eq!(Some(177), sc.next_i32_radix(16).unwrap());
+    assert!(sc.skip_whitespaces().unwrap());
+    assert_eq!(Some(255), sc.next_u8_radix_until(",", 0).unwrap());
+    assert_eq!(Some(-128), sc.next_i8_radix_until(",", 16).unwrap());
+    assert_eq!(
+        "number too small in \"-0x1\" at 1:56",
+        sc.next_u16_radix(0).unwrap_err().to_string()
+    );
+    assert_eq!("invalid digit in \"0x\" at 1:61", sc.next_u16_radix(16).unwrap_err().to_string());
+    assert_eq!(Some(255), sc.next_u64_radix(16).unwrap());
+}
+
+#[test]
+fn number_format() {
+    let data = "1_000_000 1,234,567 +42 12,34 1__0 3.5 0xdead_beef 1,234.5 1.234,5;1.000; 1.5 2.5";
+
+    let mut sc = ScannerAscii::new(data.as_bytes());
+
+    sc.set_number_format(NumberFormat {
+        digit_separators: vec!['_'],
+        grouping: Some(','),
+        ..NumberFormat::default()
+    });
+
+    assert_eq!(Some("1_000_000".into()), sc.peek_token().unwrap());
+    assert!(sc.has_next_u32().unwrap());
+    assert_eq!(Some(1000000), sc.next_u32().unwrap());
+    assert_eq!(Some(1234567), sc.next_i64().unwrap());
+    assert_eq!(Some(42), sc.next_i8().unwrap());
+    assert_eq!("invalid digit in \"12,34\" at 1:25", sc.next_u32().unwrap_err().to_string());
+    assert_eq!("invalid digit in \"1__0\" at 1:31", sc.next_u32().unwrap_err().to_string());
+    assert_eq!(Some(3.5), sc.next_f64().unwrap());
+    assert_eq!(Some(3735928559), sc.next_u32_radix(0).unwrap());
+    assert_eq!(Some(1234.5), sc.next_f32().unwrap());
+
+    sc.set_number_format(NumberFormat {
+        grouping: Some('.'),
+        decimal_point: ',',
+        ..NumberFormat::default()
+    });
+
+    assert!(sc.skip_whitespaces().unwrap());
+    assert_eq!(Some(1234.5), sc.next_f64_until(";").unwrap());
+    assert_eq!(Some(1000), sc.next_u32_until(";").unwrap());
+    assert_eq!("invalid float literal in \"1.5\" at 1:75", sc.next_f64().unwrap_err().to_string());
+    assert_eq!(Some(2.5), sc.next_parse::<f64>().unwrap());
+}
+
+#[test]
+fn number_format_conflicts() {
+    let data = "1.234 1.234 1,234 12,34";
+
+    let mut sc = ScannerAscii::new(data.as_bytes());
+
+    sc.set_number_format(NumberFormat {
+        digit_separators: vec!['.'],
+        grouping: Some('.'),
+        ..NumberFormat::default()
+    });
+
+    assert_eq!(Some(1.234), sc.next_f64().unwrap());
+    assert_eq!("invalid digit in \"1.234\" at 1:7", sc.next_u32().unwrap_err().to_string());
+
+    sc.set_number_format(NumberFormat {
+        digit_separators: vec![','],
+        grouping: Some(','),
+        ..NumberFormat::default()
+    });
+
+    assert_eq!(Some(1234), sc.next_u32().unwrap());
+    assert_eq!("invalid digit in \"12,34\" at 1:19", sc.next_u32().unwrap_err().to_string());
+}
+
+#[test]
+fn next_bool() {
+    let data = "true True YES n 1 maybe NO,yes,";
+
+    let mut sc = ScannerAscii::new(data.as_bytes());
+
+    assert_eq!(Some(true), sc.next_bool().unwrap());
+    assert_eq!("invalid boolean in \"True\" at 1:6", sc.next_bool().unwrap_err().to_string());
+
+    sc.set_bool_vocabulary(BoolVocabulary::lenient());
+
+    assert_eq!(Some(true), sc.next_bool().unwrap());
+    assert_eq!(Some(false), sc.next_bool().unwrap());
+    assert_eq!(Some(true), sc.next_bool().unwrap());
+    assert_eq!("invalid boolean in \"maybe\" at 1:19", sc.next_bool().unwrap_err().to_string());
+
+    sc.set_bool_vocabulary(BoolVocabulary {
+        true_words:       vec!["sí".into()],
+        false_words:      vec!["no".into()],
+        case_insensitive: true,
+    });
+
+    assert!(sc.skip_whitespaces().unwrap());
+    assert_eq!(Some(false), sc.next_bool_until(",").unwrap());
+    assert_eq!(
+        "invalid boolean in \"yes\" at 1:28",
+        sc.next_bool_until(",").unwrap_err().to_string()
+    );
+    assert_eq!(None, sc.next_bool().unwrap());
+}
+
+#[test]
+fn integer_overflow() {
+    let data =
+        "300 -300 -300 3x0 1.2.3 ,maybe 300 -300 -5 0x1ffff 300x 257,-81,9223372036854775808 -1 -0";
+
+    let mut sc = ScannerAscii::new(data.as_bytes());
+
+    assert_eq!(ScannerErrorKind::Overflow, sc.next_u8().unwrap_err().kind());
+    assert_eq!(ScannerErrorKind::Underflow, sc.next_i8().unwrap_err().kind());
+    assert_eq!(ScannerErrorKind::Underflow, sc.next_u8().unwrap_err().kind());
+    assert_eq!(ScannerErrorKind::InvalidDigit, sc.next_u8().unwrap_err().kind());
+    assert_eq!(ScannerErrorKind::InvalidFloat, sc.next_f32().unwrap_err().kind());
+    assert!(sc.skip_whitespaces().unwrap());
+    assert_eq!(ScannerErrorKind::Empty, sc.next_u8_until(",").unwrap_err().kind());
+    assert_eq!(ScannerErrorKind::InvalidBool, sc.next_bool().unwrap_err().kind());
+
+    sc.set_number_format(NumberFormat {
+        integer_overflow: IntegerOverflow::Saturating,
+        ..NumberFormat::default()
+    });
+
+    assert!(sc.has_next_u8().unwrap());
+    assert_eq!(Some(255), sc.next_u8().unwrap());
+    assert_eq!(Some(-128), sc.next_i8().unwrap());
+    assert_eq!(Some(0), sc.next_u8().unwrap());
+    assert_eq!(Some(65535), sc.next_u16_radix(0).unwrap());
+    assert_eq!("invalid digit in \"300x\" at 1:52", sc.next_u8().unwrap_err().to_string());
+
+    sc.set_number_format(NumberFormat {
+        integer_overflow: IntegerOverflow::Wrapping,
+        ..NumberFormat::default()
+    });
+
+    assert!(sc.skip_whitespaces().unwrap());
+    assert_eq!(Some(1), sc.next_u8_until(",").unwrap());
+    assert_eq!(Some(127), sc.next_i8_radix_until(",", 16).unwrap());
+    assert_eq!(Some(-9223372036854775808), sc.next_i64().unwrap());
+    assert_eq!(Some(255), sc.next_u8().unwrap());
+    assert_eq!(Some(0), sc.next_u8().unwrap());
+}
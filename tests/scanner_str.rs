@@ -1,4 +1,7 @@
-use scanner_rust::ScannerStr;
+use scanner_rust::{
+    BoolVocabulary, IntegerOverflow, NumberFormat, Position, ScannerError, ScannerErrorKind,
+    ScannerStr,
+};
 
 #[test]
 fn read_chars() {
@@ -256,6 +259,18 @@ fn next_u32() {
     assert_eq!(Some(2147483648), sc.next_u32().unwrap());
 }
 
+#[test]
+fn next_integer_errors() {
+    let data = "300x 99999999999abc -0 -1";
+
+    let mut sc = ScannerStr::new(data);
+
+    assert_eq!(ScannerErrorKind::InvalidDigit, sc.next_u8().unwrap_err().kind());
+    assert_eq!(ScannerErrorKind::InvalidDigit, sc.next_u32().unwrap_err().kind());
+    assert_eq!(Some(0), sc.next_u32().unwrap());
+    assert_eq!(ScannerErrorKind::Underflow, sc.next_u32().unwrap_err().kind());
+}
+
 #[test]
 fn next_u64() {
     let data = "4611686018427387904 9223372036854775808";
@@ -365,3 +380,231 @@ fn next_f64() {
     assert_eq!(Some(2.0), sc.next_f64().unwrap());
     assert_eq!(Some(-123456.987654), sc.next_f64().unwrap());
 }
+
+#[test]
+fn checkpoints() {
+    let data = "12 中文 34\n56 x";
+
+    let mut sc = ScannerStr::new(data);
+
+    assert_eq!(Some(12), sc.next_u8().unwrap());
+
+    let checkpoint = sc.checkpoint();
+
+    assert_eq!(Some("中文"), sc.next().unwrap());
+    assert_eq!(Some(34), sc.next_u8().unwrap());
+    assert_eq!("\n56 x", sc.remaining());
+
+    sc.restore(checkpoint);
+
+    assert_eq!(checkpoint.position(), sc.position());
+    assert_eq!(" 中文 34\n56 x", sc.remaining());
+
+    // a failed attempt does not consume anything
+    let numbers = |sc: &mut ScannerStr| -> Result<Vec<u8>, ScannerError> {
+        let mut numbers = Vec::new();
+
+        while let Some(n) = sc.next_u8()? {
+            numbers.push(n);
+        }
+
+        Ok(numbers)
+    };
+
+    assert!(sc.try_scan(numbers).is_err());
+    assert_eq!(checkpoint.position(), sc.position());
+
+    sc.set_position(13);
+
+    assert_eq!(
+        Position {
+            offset: 13, line: 2, column: 1
+        },
+        sc.position()
+    );
+    assert_eq!(Some(56), sc.try_scan(|sc| sc.next_u8()).unwrap());
+    assert!(sc.try_scan(numbers).is_err());
+    assert_eq!(Some("x"), sc.next().unwrap());
+
+    sc.set_position(data.len());
+
+    assert_eq!("", sc.remaining());
+    assert_eq!(None, sc.next().unwrap());
+}
+
+#[test]
+#[should_panic]
+fn set_position_inside_char() {
+    let mut sc = ScannerStr::new("中文");
+
+    sc.set_position(1);
+}
+
+#[test]
+fn next_radix() {
+    let data = "DeadBeef -0x1f +0O17 0b1010 42 0o17 0b12 0b1 0xff,-80, -0x1 0x ff";
+
+    let mut sc = ScannerStr::new(data);
+
+    assert_eq!(
+        "invalid radix 1, which should be 0 or from 2 to 36",
+        sc.next_i64_radix(1).unwrap_err().to_string()
+    );
+    assert_eq!(
+        ScannerErrorKind::InvalidRadix,
+        sc.next_i64_radix_until(" ", 37).unwrap_err().kind()
+    );
+    assert_eq!(
+        "number too large in \"DeadBeef\" at 1:1",
+        sc.next_i32_radix(16).unwrap_err().to_string()
+    );
+    assert_eq!(Some(-31), sc.next_i32_radix(0).unwrap());
+    assert_eq!(Some(15), sc.next_i32_radix(0).unwrap());
+    assert_eq!(Some(10), sc.next_i32_radix(0).unwrap());
+    assert_eq!(Some(42), sc.next_i32_radix(0).unwrap());
+    assert_eq!(Some(15), sc.next_i32_radix(8).unwrap());
+    assert_eq!("invalid digit in \"0b12\" at 1:37", sc.next_i32_radix(2).unwrap_err().to_string());
+    assert_eq!(Some(177), sc.next_i32_radix(16).unwrap());
+    assert!(sc.skip_whitespaces().unwrap());
+    assert_eq!(Some(255), sc.next_u8_radix_until(",", 0).unwrap());
+    assert_eq!(Some(-128), sc.next_i8_radix_until(",", 16).unwrap());
+    assert_eq!(
+        "number too small in \"-0x1\" at 1:56",
+        sc.next_u16_radix(0).unwrap_err().to_string()
+    );
+    assert_eq!("invalid digit in \"0x\" at 1:61", sc.next_u16_radix(16).unwrap_err().to_string());
+    assert_eq!(Some(255), sc.next_u64_radix(16).unwrap());
+}
+
+#[test]
+fn number_format() {
+    let data = "1_000_000 1,234,567 +42 12,34 1__0 3.5 0xdead_beef 1,234.5 1.234,5;1.000; 1.5 2.5";
+
+    let mut sc = ScannerStr::new(data);
+
+    sc.set_number_format(NumberFormat {
+        digit_separators: vec!['_'],
+        grouping: Some(','),
+        ..NumberFormat::default()
+    });
+
+    assert_eq!(Some("1_000_000"), sc.peek_token().unwrap());
+    assert!(sc.has_next_u32().unwrap());
+    assert_eq!(Some(1000000), sc.next_u32().unwrap());
+    assert_eq!(Some(1234567), sc.next_i64().unwrap());
+    assert_eq!(Some(42), sc.next_i8().unwrap());
+    assert_eq!("invalid digit in \"12,34\" at 1:25", sc.next_u32().unwrap_err().to_string());
+    assert_eq!("invalid digit in \"1__0\" at 1:31", sc.next_u32().unwrap_err().to_string());
+    assert_eq!(Some(3.5), sc.next_f64().unwrap());
+    assert_eq!(Some(3735928559), sc.next_u32_radix(0).unwrap());
+    assert_eq!(Some(1234.5), sc.next_f32().unwrap());
+
+    sc.set_number_format(NumberFormat {
+        grouping: Some('.'),
+        decimal_point: ',',
+        ..NumberFormat::default()
+    });
+
+    assert!(sc.skip_whitespaces().unwrap());
+    assert_eq!(Some(1234.5), sc.next_f64_until(";").unwrap());
+    assert_eq!(Some(1000), sc.next_u32_until(";").unwrap());
+    assert_eq!("invalid float literal in \"1.5\" at 1:75", sc.next_f64().unwrap_err().to_string());
+    assert_eq!(Some(2.5), sc.next_parse::<f64>().unwrap());
+}
+
+#[test]
+fn number_format_conflicts() {
+    let data = "1.234 1.234 1,234 12,34";
+
+    let mut sc = ScannerStr::new(data);
+
+    sc.set_number_format(NumberFormat {
+        digit_separators: vec!['.'],
+        grouping: Some('.'),
+        ..NumberFormat::default()
+    });
+
+    assert_eq!(Some(1.234), sc.next_f64().unwrap());
+    assert_eq!("invalid digit in \"1.234\" at 1:7", sc.next_u32().unwrap_err().to_string());
+
+    sc.set_number_format(NumberFormat {
+        digit_separators: vec![','],
+        grouping: Some(','),
+        ..NumberFormat::default()
+    });
+
+    assert_eq!(Some(1234), sc.next_u32().unwrap());
+    assert_eq!("invalid digit in \"12,34\" at 1:19", sc.next_u32().unwrap_err().to_string());
+}
+
+#[test]
+fn next_bool() {
+    let data = "true True YES n 1 maybe SÍ,yes,";
+
+    let mut sc = ScannerStr::new(data);
+
+    assert_eq!(Some(true), sc.next_bool().unwrap());
+    assert_eq!("invalid boolean in \"True\" at 1:6", sc.next_bool().unwrap_err().to_string());
+
+    sc.set_bool_vocabulary(BoolVocabulary::lenient());
+
+    assert_eq!(Some(true), sc.next_bool().unwrap());
+    assert_eq!(Some(false), sc.next_bool().unwrap());
+    assert_eq!(Some(true), sc.next_bool().unwrap());
+    assert_eq!("invalid boolean in \"maybe\" at 1:19", sc.next_bool().unwrap_err().to_string());
+
+    sc.set_bool_vocabulary(BoolVocabulary {
+        true_words:       vec!["sí".into()],
+        false_words:      vec!["no".into()],
+        case_insensitive: true,
+    });
+
+    assert!(sc.skip_whitespaces().unwrap());
+    assert_eq!(Some(true), sc.next_bool_until(",").unwrap());
+    assert_eq!(
+        "invalid boolean in \"yes\" at 1:28",
+        sc.next_bool_until(",").unwrap_err().to_string()
+    );
+    assert_eq!(None, sc.next_bool().unwrap());
+}
+
+#[test]
+fn integer_overflow() {
+    let data =
+        "300 -300 -300 3x0 1.2.3 ,maybe 300 -300 -5 0x1ffff 300x 257,-81,9223372036854775808 -1 -0";
+
+    let mut sc = ScannerStr::new(data);
+
+    assert_eq!(ScannerErrorKind::Overflow, sc.next_u8().unwrap_err().kind());
+    assert_eq!(ScannerErrorKind::Underflow, sc.next_i8().unwrap_err().kind());
+    assert_eq!(ScannerErrorKind::Underflow, sc.next_u8().unwrap_err().kind());
+    assert_eq!(ScannerErrorKind::InvalidDigit, sc.next_u8().unwrap_err().kind());
+    assert_eq!(ScannerErrorKind::InvalidFloat, sc.next_f32().unwrap_err().kind());
+    assert!(sc.skip_whitespaces().unwrap());
+    assert_eq!(ScannerErrorKind::Empty, sc.next_u8_until(",").unwrap_err().kind());
+    assert_eq!(ScannerErrorKind::InvalidBool, sc.next_bool().unwrap_err().kind());
+
+    sc.set_number_format(NumberFormat {
+        integer_overflow: IntegerOverflow::Saturating,
+        ..NumberFormat::default()
+    });
+
+    assert!(sc.has_next_u8().unwrap());
+    assert_eq!(Some(255), sc.next_u8().unwrap());
+    assert_eq!(Some(-128), sc.next_i8().unwrap());
+    assert_eq!(Some(0), sc.next_u8().unwrap());
+    assert_eq!(Some(65535), sc.next_u16_radix(0).unwrap());
+    assert_eq!("invalid digit in \"300x\" at 1:52", sc.next_u8().unwrap_err().to_string());
+
+    sc.set_number_format(NumberFormat {
+        integer_overflow: IntegerOverflow::Wrapping,
+        ..NumberFormat::default()
+    });
+
+    assert!(sc.skip_whitespaces().unwrap());
+    assert_eq!(Some(1), sc.next_u8_until(",").unwrap());
+    assert_eq!(Some(127), sc.next_i8_radix_until(",", 16).unwrap());
+    assert_eq!(Some(-9223372036854775808), sc.next_i64().unwrap());
+    assert_eq!(Some(255), sc.next_u8().unwrap());
+    assert_eq!(Some(0), sc.next_u8().unwrap());
+}